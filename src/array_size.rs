@@ -4,4 +4,11 @@ impl Default for ArraySize {
     fn default() -> Self {
         Self::No
     }
-}
\ No newline at end of file
+}
+
+impl ArraySize {
+    // Length of dynamic array is known only from its dimension member, buffer or terminator.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Dyn | Self::Bounded(_) | Self::Rest | Self::Until(_))
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+impl BitField {
+    // Bytes of the container are accounted to the member closing the group.
+    pub fn bytes(&self) -> usize {
+        if self.closes_group {
+            self.container.size() as usize
+        } else {
+            0
+        }
+    }
+
+    pub fn mask(&self) -> u64 {
+        ((1u64 << self.bits) - 1) << self.shift
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self.native.as_ref(), NativeType::I8 | NativeType::I16 | NativeType::I32 | NativeType::I64)
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+impl ChecksumAlgorithm {
+    pub fn from_name(name: &str) -> Option<ChecksumAlgorithm> {
+        match name {
+            "crc8" => Some(Self::Crc8),
+            "crc16_ccitt" => Some(Self::Crc16Ccitt),
+            "crc32" => Some(Self::Crc32),
+            "sum" => Some(Self::Sum),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Crc8 => "crc8",
+            Self::Crc16Ccitt => "crc16_ccitt",
+            Self::Crc32 => "crc32",
+            Self::Sum => "sum",
+        }
+    }
+
+    // CRCs have fixed width, additive sum is truncated to width of its member.
+    pub fn bits(&self) -> Option<u8> {
+        match self {
+            Self::Crc8 => Some(8),
+            Self::Crc16Ccitt => Some(16),
+            Self::Crc32 => Some(32),
+            Self::Sum => None,
+        }
+    }
+}
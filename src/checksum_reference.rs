@@ -0,0 +1,7 @@
+use super::*;
+
+impl ChecksumReference {
+    pub fn covers(&self, other: &ChecksumReference) -> bool {
+        (self.first.index..=self.last.index).contains(&other.origin.index)
+    }
+}
@@ -4,6 +4,7 @@ impl CodeView {
     pub fn offset(&self, offset: usize) -> CodeView {
         Self {
             origin: self.origin.clone(),
+            file: self.file.clone(),
             from: self.to,
             to: self.to + offset,
         }
@@ -12,6 +13,7 @@ impl CodeView {
     pub fn trim(&self, to: usize) -> CodeView {
         Self {
             origin: self.origin.clone(),
+            file: self.file.clone(),
             from: self.from,
             to: self.from + to,
         }
@@ -25,20 +27,57 @@ impl CodeView {
         &self.origin.as_str()[self.to..]
     }
 
-    pub fn pos(&self) -> String {
-        format!("on line {} at column {}", self.line(), self.column())
+    pub fn location(&self) -> String {
+        let file = if self.file.is_empty() { "<input>" } else { self.file.as_str() };
+        format!("{}:{}:{}", file, self.line(), self.column())
     }
 
-    fn line(&self) -> usize {
-        self.origin.as_str()[..self.from].lines().count()
+    // Leading white chars of parsed tokens are not part of what they point at.
+    pub fn trim_start(&self) -> CodeView {
+        let white = self.view().len() - self.view().trim_start().len();
+        if white == self.view().len() {
+            return self.clone();
+        }
+        Self {
+            origin: self.origin.clone(),
+            file: self.file.clone(),
+            from: self.from + white,
+            to: self.to,
+        }
+    }
+
+    // Declarations of a file are generated into module named by file stem.
+    pub fn module(&self) -> String {
+        std::path::Path::new(self.file.as_str())
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn from_file(file: &str, content: String) -> CodeView {
+        Self {
+            origin: Rc::new(content),
+            file: Rc::new(String::from(file)),
+            from: 0,
+            to: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.origin.as_str()[..self.from].matches('\n').count() + 1
+    }
+
+    pub fn column(&self) -> usize {
+        self.origin.as_str()[self.line_start()..self.from].chars().count() + 1
+    }
+
+    pub fn source_line(&self) -> &str {
+        let rest = &self.origin.as_str()[self.line_start()..];
+        rest.split('\n').next().unwrap_or("").trim_end_matches('\r')
     }
 
-    fn column(&self) -> usize {
-        self.origin.as_str()[..self.from]
-            .lines()
-            .last()
-            .unwrap_or("")
-            .len()
+    fn line_start(&self) -> usize {
+        self.origin.as_str()[..self.from].rfind('\n').map_or(0, |i| i + 1)
     }
 }
 
@@ -46,6 +85,7 @@ impl From<String> for CodeView {
     fn from(value: String) -> Self {
         Self {
             origin: Rc::new(value),
+            file: Default::default(),
             from: 0,
             to: 0,
         }
@@ -56,6 +96,7 @@ impl From<&str> for CodeView {
     fn from(value: &str) -> Self {
         Self {
             origin: Rc::new(String::from(value)),
+            file: Default::default(),
             from: 0,
             to: 0,
         }
@@ -0,0 +1,46 @@
+use super::*;
+
+impl Diagnostic {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            labels: Vec::new(),
+        }
+    }
+
+    pub fn with_label(mut self, code_view: &CodeView, text: &str) -> Self {
+        self.labels.push(Label {
+            code_view: code_view.clone(),
+            text: text.into(),
+        });
+        self
+    }
+}
+
+// Rendered like rustc, primary label is underlined by '^' and secondary ones by '-'.
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let spans = self.labels
+            .iter()
+            .map(|l| l.code_view.trim_start())
+            .collect::<Vec<CodeView>>();
+        let width = spans
+            .iter()
+            .map(|s| s.line().to_string().len())
+            .max()
+            .unwrap_or(0);
+        let pad = " ".repeat(width);
+        let mut lines = vec![format!("error: {}", self.message)];
+        for (i, (label, span)) in self.labels.iter().zip(&spans).enumerate() {
+            let (arrow, mark) = if i == 0 { ("-->", "^") } else { (":::", "-") };
+            // span covering more lines is underlined till end of its first line
+            let length = span.view().split('\n').next().unwrap_or("").chars().count().max(1);
+            let underline = format!("{}{} {}", " ".repeat(span.column() - 1), mark.repeat(length), label.text);
+            lines.push(format!("{}{} {}", pad, arrow, span.location()));
+            lines.push(format!("{} |", pad));
+            lines.push(format!("{:>width$} | {}", span.line(), span.source_line()));
+            lines.push(format!("{} | {}", pad, underline.trim_end()));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
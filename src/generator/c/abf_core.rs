@@ -0,0 +1,189 @@
+pub static SOURCE: &str = "
+#ifndef ABF_H
+#define ABF_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+#define ABF_BIG_ENDIAN <<BIG_ENDIAN>>
+/* Version of compiler which generated this code. */
+#define ABF_COMPILER_VERSION \"<<COMPILER_VERSION>>\"
+
+typedef enum abf_error {
+    ABF_OK = 0,
+    ABF_ERROR_NOT_SET,
+    ABF_ERROR_BUFFER_TOO_SMALL,
+    ABF_ERROR_SOURCE_TOO_SHORT,
+    ABF_ERROR_OVERFLOW,
+    ABF_ERROR_CAPACITY,
+    ABF_ERROR_UNKNOWN_TYPE_ID,
+    ABF_ERROR_UNKNOWN_ENUM_VALUE,
+    ABF_ERROR_CHECKSUM
+} abf_error;
+
+#define ABF_TRY(expr) \\
+    do { \\
+        abf_error abf_error_ = (expr); \\
+        if (abf_error_ != ABF_OK) { \\
+            return abf_error_; \\
+        } \\
+    } while (0)
+
+static inline void abf_write_bits(uint8_t *dest, uint64_t bits, size_t size) {
+    size_t i;
+    for (i = 0; i < size; ++i) {
+        size_t shift = ABF_BIG_ENDIAN ? (size - 1 - i) * 8 : i * 8;
+        dest[i] = (uint8_t)(bits >> shift);
+    }
+}
+
+static inline uint64_t abf_read_bits(const uint8_t *source, size_t size) {
+    uint64_t bits = 0;
+    size_t i;
+    for (i = 0; i < size; ++i) {
+        size_t shift = ABF_BIG_ENDIAN ? (size - 1 - i) * 8 : i * 8;
+        bits |= (uint64_t)source[i] << shift;
+    }
+    return bits;
+}
+
+static inline abf_error abf_write_unsigned(uint8_t *dest, size_t dest_size, uint64_t value, size_t size, size_t *written) {
+    if (size < 8 && (value >> (size * 8)) != 0) {
+        return ABF_ERROR_OVERFLOW;
+    }
+    if (dest_size < size) {
+        return ABF_ERROR_BUFFER_TOO_SMALL;
+    }
+    abf_write_bits(dest, value, size);
+    *written = size;
+    return ABF_OK;
+}
+
+static inline abf_error abf_write_signed(uint8_t *dest, size_t dest_size, int64_t value, size_t size, size_t *written) {
+    if (size < 8) {
+        int64_t limit = (int64_t)1 << (size * 8 - 1);
+        if (value < -limit || value >= limit) {
+            return ABF_ERROR_OVERFLOW;
+        }
+    }
+    if (dest_size < size) {
+        return ABF_ERROR_BUFFER_TOO_SMALL;
+    }
+    abf_write_bits(dest, (uint64_t)value, size);
+    *written = size;
+    return ABF_OK;
+}
+
+static inline abf_error abf_read_unsigned(const uint8_t *source, size_t source_size, size_t size, uint64_t *value, size_t *read) {
+    if (source_size < size) {
+        return ABF_ERROR_SOURCE_TOO_SHORT;
+    }
+    *value = abf_read_bits(source, size);
+    *read = size;
+    return ABF_OK;
+}
+
+static inline abf_error abf_read_signed(const uint8_t *source, size_t source_size, size_t size, int64_t *value, size_t *read) {
+    uint64_t bits;
+    if (source_size < size) {
+        return ABF_ERROR_SOURCE_TOO_SHORT;
+    }
+    bits = abf_read_bits(source, size);
+    if (size < 8 && (bits >> (size * 8 - 1)) != 0) {
+        bits |= ~(uint64_t)0 << (size * 8);
+    }
+    *value = (int64_t)bits;
+    *read = size;
+    return ABF_OK;
+}
+
+static inline abf_error abf_write_f32(uint8_t *dest, size_t dest_size, float value, size_t *written) {
+    uint32_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    return abf_write_unsigned(dest, dest_size, bits, 4, written);
+}
+
+static inline abf_error abf_write_f64(uint8_t *dest, size_t dest_size, double value, size_t *written) {
+    uint64_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    return abf_write_unsigned(dest, dest_size, bits, 8, written);
+}
+
+static inline abf_error abf_read_f32(const uint8_t *source, size_t source_size, float *value, size_t *read) {
+    uint32_t bits;
+    if (source_size < 4) {
+        return ABF_ERROR_SOURCE_TOO_SHORT;
+    }
+    bits = (uint32_t)abf_read_bits(source, 4);
+    memcpy(value, &bits, sizeof(bits));
+    *read = 4;
+    return ABF_OK;
+}
+
+static inline abf_error abf_read_f64(const uint8_t *source, size_t source_size, double *value, size_t *read) {
+    uint64_t bits;
+    if (source_size < 8) {
+        return ABF_ERROR_SOURCE_TOO_SHORT;
+    }
+    bits = abf_read_bits(source, 8);
+    memcpy(value, &bits, sizeof(bits));
+    *read = 8;
+    return ABF_OK;
+}
+
+/* CRC-8/SMBUS: poly 0x07, init 0x00. */
+static inline uint8_t abf_crc8(const uint8_t *data, size_t size) {
+    uint8_t crc = 0;
+    size_t i;
+    int bit;
+    for (i = 0; i < size; ++i) {
+        crc ^= data[i];
+        for (bit = 0; bit < 8; ++bit) {
+            crc = (crc & 0x80) ? (uint8_t)((crc << 1) ^ 0x07) : (uint8_t)(crc << 1);
+        }
+    }
+    return crc;
+}
+
+/* CRC-16/CCITT-FALSE: poly 0x1021, init 0xFFFF. */
+static inline uint16_t abf_crc16_ccitt(const uint8_t *data, size_t size) {
+    uint16_t crc = 0xFFFF;
+    size_t i;
+    int bit;
+    for (i = 0; i < size; ++i) {
+        crc ^= (uint16_t)(data[i] << 8);
+        for (bit = 0; bit < 8; ++bit) {
+            crc = (crc & 0x8000) ? (uint16_t)((crc << 1) ^ 0x1021) : (uint16_t)(crc << 1);
+        }
+    }
+    return crc;
+}
+
+/* CRC-32/ISO-HDLC: reflected poly 0xEDB88320, init and final xor 0xFFFFFFFF. */
+static inline uint32_t abf_crc32(const uint8_t *data, size_t size) {
+    uint32_t crc = 0xFFFFFFFFu;
+    size_t i;
+    int bit;
+    for (i = 0; i < size; ++i) {
+        crc ^= data[i];
+        for (bit = 0; bit < 8; ++bit) {
+            crc = (crc & 1) ? (crc >> 1) ^ 0xEDB88320u : crc >> 1;
+        }
+    }
+    return ~crc;
+}
+
+/* Additive sum of bytes, caller truncates it to width of checksum member. */
+static inline uint64_t abf_sum(const uint8_t *data, size_t size) {
+    uint64_t sum = 0;
+    size_t i;
+    for (i = 0; i < size; ++i) {
+        sum += data[i];
+    }
+    return sum;
+}
+
+#endif
+";
@@ -0,0 +1,131 @@
+use super::*;
+
+impl CMemoryDetail for Memory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+
+    fn typename(&self) -> String {
+        self.memory.typename()
+    }
+}
+
+impl CMemoryDetail for MemoryType {
+    fn name(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.name(),
+            MemoryType::Struct(m) => m.borrow().name(),
+            MemoryType::View(m) => m.name(),
+            MemoryType::Enum(m) => m.name(),
+        }
+    }
+
+    fn typename(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.typename(),
+            MemoryType::Struct(m) => m.borrow().typename(),
+            MemoryType::View(m) => m.typename(),
+            MemoryType::Enum(m) => m.typename(),
+        }
+    }
+}
+
+impl CMemoryDetail for NativeType {
+    fn name(&self) -> String {
+        match self {
+            NativeType::Bool => "b".into(),
+            NativeType::U8 => "u8".into(),
+            NativeType::U16 => "u16".into(),
+            NativeType::U24 => "u24".into(),
+            NativeType::U32 => "u32".into(),
+            NativeType::U64 => "u64".into(),
+            NativeType::ConstU8(_) => "cu8".into(),
+            NativeType::ConstU16(_) => "cu16".into(),
+            NativeType::ConstU24(_) => "cu24".into(),
+            NativeType::ConstU32(_) => "cu32".into(),
+            NativeType::ConstU64(_) => "cu64".into(),
+            NativeType::I8 => "i8".into(),
+            NativeType::I16 => "i16".into(),
+            NativeType::I32 => "i32".into(),
+            NativeType::I64 => "i64".into(),
+            NativeType::F32 => "f32".into(),
+            NativeType::F64 => "f64".into(),
+            NativeType::Char => "string".into(),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.name(),
+            NativeType::ArrayDimensionReference(r) => r.origin.as_ref().name(),
+            NativeType::StructMemberSize(m) => m.native.name(),
+            NativeType::Checksum(c) => c.native.name(),
+            NativeType::BitField(bf) => bf.native.name(),
+        }
+    }
+
+    fn typename(&self) -> String {
+        match self {
+            NativeType::Bool => "bool".into(),
+            NativeType::U8 => "uint8_t".into(),
+            NativeType::U16 => "uint16_t".into(),
+            NativeType::U24 => "uint32_t".into(),
+            NativeType::U32 => "uint32_t".into(),
+            NativeType::U64 => "uint64_t".into(),
+            NativeType::ConstU8(_) => "uint8_t".into(),
+            NativeType::ConstU16(_) => "uint16_t".into(),
+            NativeType::ConstU24(_) => "uint32_t".into(),
+            NativeType::ConstU32(_) => "uint32_t".into(),
+            NativeType::ConstU64(_) => "uint64_t".into(),
+            NativeType::I8 => "int8_t".into(),
+            NativeType::I16 => "int16_t".into(),
+            NativeType::I32 => "int32_t".into(),
+            NativeType::I64 => "int64_t".into(),
+            NativeType::F32 => "float".into(),
+            NativeType::F64 => "double".into(),
+            NativeType::Char => "char".into(),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.typename(),
+            NativeType::ArrayDimensionReference(r) => r.origin.typename(),
+            NativeType::StructMemberSize(m) => m.native.typename(),
+            NativeType::Checksum(c) => c.native.typename(),
+            NativeType::BitField(bf) => bf.native.typename(),
+        }
+    }
+}
+
+impl CMemoryDetail for StructMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn typename(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl CMemoryDetail for ViewMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn typename(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl CMemoryDetail for EnumMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn typename(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl CMemoryDetail for ViewPosibilityMemory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+
+    fn typename(&self) -> String {
+        self.memory.typename()
+    }
+}
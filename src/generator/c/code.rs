@@ -0,0 +1,226 @@
+use super::*;
+
+// Statements are emitted into functions which declare `offset`, `n` and the
+// `dest`/`source` buffers with their sizes.
+
+// Reference members are stored as the native type they were declared with.
+fn underlying(n: &NativeType) -> &NativeType {
+    match n {
+        NativeType::ViewKeyReference(m) => &m.native_key,
+        NativeType::ArrayDimensionReference(r) => &r.origin,
+        NativeType::StructMemberSize(m) => &m.native,
+        NativeType::Checksum(c) => &c.native,
+        _ => n,
+    }
+}
+
+pub fn checksum_call(c: &ChecksumReference, data: &str, size: &str) -> String {
+    format!("({})abf_{}({}, {})", c.native.typename(), c.algorithm.name(), data, size)
+}
+
+// Floats are transferred as their IEEE-754 bit pattern.
+fn float_suffix(n: &NativeType) -> Option<&'static str> {
+    match n {
+        NativeType::F32 => Some("f32"),
+        NativeType::F64 => Some("f64"),
+        _ => None,
+    }
+}
+
+pub fn is_signed(n: &NativeType) -> bool {
+    matches!(underlying(n), NativeType::I8 | NativeType::I16 | NativeType::I32 | NativeType::I64)
+}
+
+pub fn constant_value(n: &NativeType) -> Option<String> {
+    match n {
+        NativeType::ConstU8(v) => Some(v.to_string()),
+        NativeType::ConstU16(v) => Some(v.to_string()),
+        NativeType::ConstU24(v) => Some(v.to_string()),
+        NativeType::ConstU32(v) => Some(v.to_string()),
+        NativeType::ConstU64(v) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+fn generate_loop(m: &Memory, expr: &str, writer: &mut Writer) -> String {
+    match m.array_size {
+        ArraySize::No => panic!("not an array"),
+        ArraySize::Exact(s) => {
+            writer.write_with_offset(&format!("for (size_t i = 0; i < {}; ++i)", s));
+            writer.scope_in();
+            format!("{}[i]", expr)
+        },
+        ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => {
+            writer.write_with_offset(&format!("for (size_t i = 0; i < {}.length; ++i)", expr));
+            writer.scope_in();
+            format!("{}.items[i]", expr)
+        },
+        ArraySize::Until(_) => panic!("terminated array not supported"),
+    }
+}
+
+pub fn generate_size(m: &Memory, expr: &str, size: &str, writer: &mut Writer) {
+    if m.array_size.is_no() {
+        generate_item_size(&m.memory, expr, size, writer);
+    } else {
+        let item = generate_loop(m, expr, writer);
+        generate_item_size(&m.memory, &item, size, writer);
+        writer.scope_out(false);
+    }
+}
+
+pub fn generate_item_size(m: &MemoryType, expr: &str, size: &str, writer: &mut Writer) {
+    match m {
+        // container is accounted to member closing the group
+        MemoryType::Native(NativeType::BitField(bf)) if !bf.closes_group => {},
+        MemoryType::Native(n) => writer.write_line(&format!("{} += {};", size, underlying(n).size())),
+        _ => {
+            writer.write_line(&format!("ABF_TRY({}_size(&{}, &n));", m.typename(), expr));
+            writer.write_line(&format!("{} += n;", size));
+        }
+    }
+}
+
+pub fn generate_serialize(m: &Memory, expr: &str, writer: &mut Writer) {
+    if m.array_size.is_no() {
+        generate_item_serialize(&m.memory, expr, writer);
+    } else {
+        let item = generate_loop(m, expr, writer);
+        generate_item_serialize(&m.memory, &item, writer);
+        writer.scope_out(false);
+    }
+}
+
+pub fn generate_item_serialize(m: &MemoryType, expr: &str, writer: &mut Writer) {
+    match m {
+        MemoryType::Native(n) => match constant_value(n) {
+            Some(v) => generate_native_serialize(n, &v, writer),
+            None => generate_native_serialize(n, expr, writer),
+        },
+        _ => {
+            writer.write_line(&format!("ABF_TRY({}_serialize(&{}, dest + offset, dest_size - offset, &n));",
+                m.typename(),
+                expr));
+            writer.write_line("offset += n;");
+        }
+    }
+}
+
+pub fn generate_native_serialize(n: &NativeType, value: &str, writer: &mut Writer) {
+    let n = underlying(n);
+    if let Some(suffix) = float_suffix(n) {
+        writer.write_line(&format!("ABF_TRY(abf_write_{}(dest + offset, dest_size - offset, {}, &n));",
+            suffix,
+            value));
+    } else if is_signed(n) {
+        writer.write_line(&format!("ABF_TRY(abf_write_signed(dest + offset, dest_size - offset, (int64_t){}, {}, &n));",
+            value,
+            n.size()));
+    } else if n.is_char() {
+        // char may be signed, byte is written as is
+        writer.write_line(&format!("ABF_TRY(abf_write_unsigned(dest + offset, dest_size - offset, (uint8_t){}, 1, &n));",
+            value));
+    } else {
+        writer.write_line(&format!("ABF_TRY(abf_write_unsigned(dest + offset, dest_size - offset, (uint64_t){}, {}, &n));",
+            value,
+            n.size()));
+    }
+    writer.write_line("offset += n;");
+}
+
+pub fn generate_deserialize(m: &Memory, expr: &str, writer: &mut Writer) {
+    if m.array_size.is_no() {
+        generate_item_deserialize(&m.memory, expr, writer);
+    } else {
+        let item = generate_loop(m, expr, writer);
+        generate_item_deserialize(&m.memory, &item, writer);
+        writer.scope_out(false);
+    }
+}
+
+pub fn generate_item_deserialize(m: &MemoryType, expr: &str, writer: &mut Writer) {
+    match m {
+        MemoryType::Native(n) if float_suffix(underlying(n)).is_some() => {
+            writer.write_line(&format!("ABF_TRY(abf_read_{}(source + offset, source_size - offset, &{}, &n));",
+                float_suffix(underlying(n)).unwrap(),
+                expr));
+        },
+        MemoryType::Native(n) => {
+            let n = underlying(n);
+            let (bits, read) = if is_signed(n) {
+                ("int64_t", "abf_read_signed")
+            } else {
+                ("uint64_t", "abf_read_unsigned")
+            };
+            writer.write_line("{");
+            writer.indent();
+            writer.write_line(&format!("{} bits;", bits));
+            writer.write_line(&format!("ABF_TRY({}(source + offset, source_size - offset, {}, &bits, &n));", read, n.size()));
+            if n.is_bool() {
+                writer.write_line(&format!("{} = bits != 0;", expr));
+            } else {
+                writer.write_line(&format!("{} = ({})bits;", expr, n.typename()));
+            }
+            writer.dedent();
+            writer.write_line("}");
+        },
+        _ => writer.write_line(&format!("ABF_TRY({}_deserialize(&{}, source + offset, source_size - offset, &n));",
+            m.typename(),
+            expr)),
+    }
+    writer.write_line("offset += n;");
+}
+
+// Members of bit field group are packed into `container` within block opened
+// by the first member and written by the member closing the group.
+pub fn generate_bit_field_serialize(bf: &BitField, value: &str, writer: &mut Writer) {
+    if bf.opens_group {
+        writer.write_line("{");
+        writer.indent();
+        writer.write_line("uint64_t container = 0;");
+    }
+    if !bf.native.is_bool() {
+        if bf.is_signed() {
+            let limit = 1i64 << (bf.bits - 1);
+            writer.write_with_offset(&format!("if ({} < {} || {} > {})", value, -limit, value, limit - 1));
+        } else {
+            writer.write_with_offset(&format!("if ({} > {})", value, bf.mask() >> bf.shift));
+        }
+        writer.scope_in();
+        writer.write_line("return ABF_ERROR_OVERFLOW;");
+        writer.scope_out(false);
+    }
+    writer.write_line(&format!("container |= ((uint64_t){} & UINT64_C(0x{:X})) << {};", value, bf.mask() >> bf.shift, bf.shift));
+    if bf.closes_group {
+        writer.write_line(&format!("ABF_TRY(abf_write_unsigned(dest + offset, dest_size - offset, container, {}, &n));",
+            bf.container.size()));
+        writer.write_line("offset += n;");
+        writer.dedent();
+        writer.write_line("}");
+    }
+}
+
+pub fn generate_bit_field_deserialize(bf: &BitField, expr: &str, writer: &mut Writer) {
+    if bf.opens_group {
+        writer.write_line("{");
+        writer.indent();
+        writer.write_line("uint64_t container;");
+        writer.write_line(&format!("ABF_TRY(abf_read_unsigned(source + offset, source_size - offset, {}, &container, &n));",
+            bf.container.size()));
+    }
+    let bits = format!("((container >> {}) & UINT64_C(0x{:X}))", bf.shift, bf.mask() >> bf.shift);
+    if bf.native.is_bool() {
+        writer.write_line(&format!("{} = {} != 0;", expr, bits));
+    } else if bf.is_signed() {
+        // sign extension of two's complement value
+        let sign = format!("UINT64_C(0x{:X})", 1u64 << (bf.bits - 1));
+        writer.write_line(&format!("{} = ({})((int64_t)({} ^ {}) - (int64_t){});", expr, bf.native.typename(), bits, sign, sign));
+    } else {
+        writer.write_line(&format!("{} = ({}){};", expr, bf.native.typename(), bits));
+    }
+    if bf.closes_group {
+        writer.write_line("offset += n;");
+        writer.dedent();
+        writer.write_line("}");
+    }
+}
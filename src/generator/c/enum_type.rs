@@ -0,0 +1,75 @@
+use super::*;
+
+pub fn generate_enum_declaration(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("typedef enum {}", m.name));
+    writer.scope_in();
+    for c in &m.constants {
+        writer.write_line(&format!("{}_{} = {},", m.name, c.name, c.value));
+    }
+    writer.dedent();
+    writer.write_line(&format!("}} {};", m.name));
+    generate_prototypes(&m.name, writer);
+}
+
+pub fn generate_enum_definition(m: &EnumMemory, writer: &mut Writer) {
+    generate_size(m, writer);
+    writer.write_line("");
+    generate_serialize(m, writer);
+    writer.write_line("");
+    generate_deserialize(m, writer);
+}
+
+fn generate_size(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_size(const {} *value, size_t *size)", m.name, m.name));
+    writer.scope_in();
+    writer.write_line("(void)value;");
+    writer.write_line(&format!("*size = {};", m.underlaying_type.size()));
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+fn generate_serialize(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_serialize(const {} *value, uint8_t *dest, size_t dest_size, size_t *written)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    let (bits, write) = if code::is_signed(&m.underlaying_type) {
+        ("int64_t", "abf_write_signed")
+    } else {
+        ("uint64_t", "abf_write_unsigned")
+    };
+    writer.write_line(&format!("return {}(dest, dest_size, ({})*value, {}, written);",
+        write,
+        bits,
+        m.underlaying_type.size()));
+    writer.scope_out(false);
+}
+
+fn generate_deserialize(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_deserialize({} *value, const uint8_t *source, size_t source_size, size_t *read)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    let (bits, read) = if code::is_signed(&m.underlaying_type) {
+        ("int64_t", "abf_read_signed")
+    } else {
+        ("uint64_t", "abf_read_unsigned")
+    };
+    writer.write_line(&format!("{} bits;", bits));
+    writer.write_line(&format!("ABF_TRY({}(source, source_size, {}, &bits, read));", read, m.underlaying_type.size()));
+    writer.write_with_offset("switch (bits)");
+    writer.scope_in();
+    for c in &m.constants {
+        writer.write_line(&format!("case {}:", c.value));
+    }
+    writer.indent();
+    writer.write_line(&format!("*value = ({})bits;", m.name));
+    writer.write_line("return ABF_OK;");
+    writer.dedent();
+    writer.write_line("default:");
+    writer.indent();
+    writer.write_line("return ABF_ERROR_UNKNOWN_ENUM_VALUE;");
+    writer.dedent();
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
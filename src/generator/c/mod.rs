@@ -0,0 +1,77 @@
+use std::io::Write;
+
+use super::*;
+
+mod c_memory_detail;
+mod code;
+mod enum_type;
+mod struct_type;
+mod view_type;
+mod abf_core;
+#[cfg(test)]
+mod test;
+
+trait CMemoryDetail {
+    fn name(&self) -> String;
+    fn typename(&self) -> String;
+
+    fn variable(&self) -> String {
+        utils::to_snake_case(&self.name())
+    }
+}
+
+pub fn generate(m: &[MemoryDeclaration], module: &Module, big_endian: bool, args: &Args) {
+    let output_module = module.name.as_str();
+
+    std::fs::create_dir_all(&args.output_dir)
+        .expect("could not create output directory");
+
+    let abf_file = format!("{}/abf.h", args.output_dir);
+    let mut f = std::fs::File::create(&abf_file).expect("could not create abf.h");
+    let abf_source_code = abf_core::SOURCE.replace("<<BIG_ENDIAN>>", if big_endian { "1" } else { "0" })
+        .replace("<<COMPILER_VERSION>>", env!("CARGO_PKG_VERSION"));
+    f.write_all(abf_source_code.as_bytes()).expect("write abf.h failed");
+
+    let guard = format!("{}_H", output_module.to_ascii_uppercase());
+    let mut header = Writer::new(&format!("{}/{}.h", args.output_dir, output_module));
+    header.write_line(&format!("#ifndef {}", guard));
+    header.write_line(&format!("#define {}", guard));
+    header.write_line("");
+    header.write_line("#include \"abf.h\"");
+    for import in &module.imports {
+        header.write_line(&format!("#include \"{}.h\"", import));
+    }
+
+    let mut source = Writer::new(&format!("{}/{}.c", args.output_dir, output_module));
+    source.write_line(&format!("#include \"{}.h\"", output_module));
+
+    for md in m.iter().filter(|md| md.module == module.name) {
+        header.write_line("");
+        source.write_line("");
+        match &md.memory.memory {
+            MemoryType::Native(_) => panic!("Unexpected"),
+            MemoryType::Struct(s) => {
+                struct_type::generate_struct_declaration(&s.borrow(), &mut header);
+                struct_type::generate_struct_definition(&s.borrow(), &mut source);
+            },
+            MemoryType::View(v) => {
+                view_type::generate_view_declaration(v, &mut header);
+                view_type::generate_view_definition(v, &mut source);
+            },
+            MemoryType::Enum(e) => {
+                enum_type::generate_enum_declaration(e, &mut header);
+                enum_type::generate_enum_definition(e, &mut source);
+            },
+        }
+    }
+
+    header.write_line("");
+    header.write_line("#endif");
+}
+
+fn generate_prototypes(name: &str, writer: &mut Writer) {
+    writer.write_line("");
+    writer.write_line(&format!("abf_error {}_size(const {} *value, size_t *size);", name, name));
+    writer.write_line(&format!("abf_error {}_serialize(const {} *value, uint8_t *dest, size_t dest_size, size_t *written);", name, name));
+    writer.write_line(&format!("abf_error {}_deserialize({} *value, const uint8_t *source, size_t source_size, size_t *read);", name, name));
+}
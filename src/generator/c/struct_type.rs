@@ -0,0 +1,269 @@
+use super::*;
+
+pub fn generate_struct_declaration(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("typedef struct {}", m.name));
+    writer.scope_in();
+    if m.fields.is_empty() {
+        // ISO C does not allow structs without members
+        writer.write_line("uint8_t unused_;");
+    }
+    for sm in &m.fields {
+        generate_member_declaration(sm, writer);
+    }
+    writer.dedent();
+    writer.write_line(&format!("}} {};", m.name));
+    generate_prototypes(&m.name, writer);
+    if !m.get_checksums().is_empty() {
+        writer.write_line(&format!("abf_error {}_verify(const {} *value, const uint8_t *source, size_t source_size);", m.name, m.name));
+    }
+}
+
+fn generate_member_declaration(sm: &StructMemberMemory, writer: &mut Writer) {
+    let memory = sm.memory.borrow();
+    match memory.array_size {
+        ArraySize::No => writer.write_line(&format!("{} {};", memory.typename(), sm.name)),
+        ArraySize::Exact(s) => writer.write_line(&format!("{} {}[{}];", memory.typename(), sm.name, s)),
+        // items are supplied by caller, capacity bounds deserialization
+        ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => writer.write_line(&format!("struct {{ {} *items; size_t length; size_t capacity; }} {};",
+            memory.typename(),
+            sm.name)),
+        ArraySize::Until(_) => panic!("terminated array not supported"),
+    }
+}
+
+pub fn generate_struct_definition(m: &StructMemory, writer: &mut Writer) {
+    generate_size(m, writer);
+    writer.write_line("");
+    generate_serialize(m, writer);
+    writer.write_line("");
+    generate_deserialize(m, writer);
+    if !m.get_checksums().is_empty() {
+        writer.write_line("");
+        generate_verify(m, writer);
+    }
+}
+
+fn member(sm: &StructMemberMemory) -> String {
+    format!("value->{}", sm.name)
+}
+
+// Optional member is enclosed in block entered only when its flag is set.
+fn generate_if_present(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("if ({})", condition.test(&member(&condition.flag))));
+        writer.scope_in();
+    }
+}
+
+fn generate_end_if_present(sm: &StructMemberMemory, writer: &mut Writer) {
+    if sm.condition.is_some() {
+        writer.scope_out(false);
+    }
+}
+
+fn generate_size(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_size(const {} *value, size_t *size)", m.name, m.name));
+    writer.scope_in();
+    // size of natives and their fixed arrays does not depend on value
+    let nested = m.fields.iter().any(|f| !f.memory.borrow().memory.is_native());
+    let dynamic = m.fields.iter().any(|f| f.memory.borrow().array_size.is_dynamic() || f.condition.is_some());
+    if nested {
+        writer.write_line("size_t n;");
+    }
+    if !nested && !dynamic {
+        writer.write_line("(void)value;");
+    }
+    writer.write_line("*size = 0;");
+    for sm in &m.fields {
+        generate_if_present(sm, writer);
+        code::generate_size(&sm.memory.borrow(), &member(sm), "*size", writer);
+        generate_end_if_present(sm, writer);
+    }
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+fn generate_serialize(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_serialize(const {} *value, uint8_t *dest, size_t dest_size, size_t *written)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    if m.fields.is_empty() {
+        writer.write_line("(void)value;");
+        writer.write_line("(void)dest;");
+        writer.write_line("(void)dest_size;");
+        writer.write_line("*written = 0;");
+        writer.write_line("return ABF_OK;");
+        writer.scope_out(false);
+        return
+    }
+    writer.write_line("size_t offset = 0;");
+    writer.write_line("size_t n;");
+    let checksums = m.get_checksums();
+    for sm in &m.fields {
+        for c in checksums.iter().filter(|c| c.first.index == sm.index) {
+            writer.write_line(&format!("size_t {}_begin = offset;", c.origin.name));
+        }
+        generate_if_present(sm, writer);
+        generate_member_serialize(sm, writer);
+        generate_end_if_present(sm, writer);
+        for c in checksums.iter().filter(|c| c.last.index == sm.index) {
+            writer.write_line(&format!("size_t {}_end = offset;", c.origin.name));
+        }
+    }
+    // checksum is written again once covered bytes are in place
+    for c in &checksums {
+        writer.write_line(&format!("abf_write_bits(dest + {}_offset, {}, {});",
+            c.origin.name,
+            code::checksum_call(c,
+                &format!("dest + {}_begin", c.origin.name),
+                &format!("{}_end - {}_begin", c.origin.name, c.origin.name)),
+            c.native.size()));
+    }
+    writer.write_line("*written = offset;");
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+fn generate_member_serialize(sm: &StructMemberMemory, writer: &mut Writer) {
+    let memory = sm.memory.borrow();
+    match &memory.memory {
+        MemoryType::Native(NativeType::ViewKeyReference(vkr)) => {
+            writer.write_with_offset(&format!("if (!{}.set)", member(&vkr.view)));
+            writer.scope_in();
+            writer.write_line("return ABF_ERROR_NOT_SET;");
+            writer.scope_out(false);
+            code::generate_native_serialize(&vkr.native_key, &format!("{}.type_id", member(&vkr.view)), writer);
+        },
+        MemoryType::Native(NativeType::ArrayDimensionReference(adr)) =>
+            code::generate_native_serialize(&adr.origin, &format!("{}.length", member(&adr.array)), writer),
+        MemoryType::Native(NativeType::BitField(bf)) => code::generate_bit_field_serialize(bf, &member(sm), writer),
+        MemoryType::Native(NativeType::StructMemberSize(sms)) => {
+            writer.write_line("{");
+            writer.indent();
+            writer.write_line("size_t member_size = 0;");
+            code::generate_size(&sms.member.memory.borrow(), &member(&sms.member), "member_size", writer);
+            code::generate_native_serialize(&sms.native, "member_size", writer);
+            writer.dedent();
+            writer.write_line("}");
+        },
+        MemoryType::Native(NativeType::Checksum(c)) => {
+            // placeholder until covered members are written
+            writer.write_line(&format!("size_t {}_offset = offset;", sm.name));
+            code::generate_native_serialize(&c.native, "0", writer);
+        },
+        _ => code::generate_serialize(&memory, &member(sm), writer),
+    }
+}
+
+// Covered bytes are located by sizes of deserialized members.
+fn generate_verify(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_verify(const {} *value, const uint8_t *source, size_t source_size)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    let checksums = m.get_checksums();
+    let resolved = checksums.iter().map(|c| c.last.index).max().unwrap();
+    writer.write_line("size_t offset = 0;");
+    if m.fields[..(resolved + 1)].iter().any(|f| !f.memory.borrow().memory.is_native()) {
+        writer.write_line("size_t n;");
+    }
+    for sm in &m.fields[..(resolved + 1)] {
+        for c in checksums.iter().filter(|c| c.first.index == sm.index) {
+            writer.write_line(&format!("size_t {}_begin = offset;", c.origin.name));
+        }
+        generate_if_present(sm, writer);
+        code::generate_size(&sm.memory.borrow(), &member(sm), "offset", writer);
+        generate_end_if_present(sm, writer);
+        for c in checksums.iter().filter(|c| c.last.index == sm.index) {
+            writer.write_line(&format!("size_t {}_end = offset;", c.origin.name));
+        }
+    }
+    for c in &checksums {
+        writer.write_with_offset(&format!("if ({}_end > source_size)", c.origin.name));
+        writer.scope_in();
+        writer.write_line("return ABF_ERROR_SOURCE_TOO_SHORT;");
+        writer.scope_out(false);
+        writer.write_with_offset(&format!("if ({} != {})",
+            member(&c.origin),
+            code::checksum_call(c,
+                &format!("source + {}_begin", c.origin.name),
+                &format!("{}_end - {}_begin", c.origin.name, c.origin.name))));
+        writer.scope_in();
+        writer.write_line("return ABF_ERROR_CHECKSUM;");
+        writer.scope_out(false);
+    }
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+fn generate_deserialize(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_deserialize({} *value, const uint8_t *source, size_t source_size, size_t *read)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    if m.fields.is_empty() {
+        writer.write_line("(void)value;");
+        writer.write_line("(void)source;");
+        writer.write_line("(void)source_size;");
+        writer.write_line("*read = 0;");
+        writer.write_line("return ABF_OK;");
+        writer.scope_out(false);
+        return
+    }
+    writer.write_line("size_t offset = 0;");
+    writer.write_line("size_t n;");
+    for (i, sm) in m.fields.iter().enumerate() {
+        if let Some(key) = m.get_view_key_reference_member_index(i) {
+            if key < i {
+                writer.write_line(&format!("{}.type_id = {};", member(sm), member(&m.fields[key])));
+                writer.write_line(&format!("{}.set = true;", member(sm)));
+            }
+        }
+        if sm.memory.borrow().array_size.is_dynamic() {
+            generate_dyn_array_length(sm, i, writer);
+        }
+        if let MemoryType::Native(NativeType::BitField(bf)) = &sm.memory.borrow().memory {
+            code::generate_bit_field_deserialize(bf, &member(sm), writer);
+            continue
+        }
+        generate_if_present(sm, writer);
+        code::generate_deserialize(&sm.memory.borrow(), &member(sm), writer);
+        generate_end_if_present(sm, writer);
+    }
+    writer.write_line("*read = offset;");
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+// Length of dynamic array is read from its dimension member, taken from bytes left
+// for array running to end of message, otherwise preset by caller.
+fn generate_dyn_array_length(sm: &StructMemberMemory, i: usize, writer: &mut Writer) {
+    let rest_item_size = match sm.memory.borrow().array_size {
+        ArraySize::Rest => sm.memory.borrow().memory.exact_size(),
+        _ => None,
+    };
+    if let Some(item_size) = rest_item_size {
+        writer.write_with_offset(&format!("if ((source_size - offset) / {} > {}.capacity)", item_size, member(sm)));
+        writer.scope_in();
+        writer.write_line("return ABF_ERROR_CAPACITY;");
+        writer.scope_out(false);
+        writer.write_line(&format!("{}.length = (source_size - offset) / {};", member(sm), item_size));
+        return
+    }
+    match sm.get_array_size_reference() {
+        Some(dimension) if dimension.index < i => {
+            writer.write_with_offset(&format!("if ((size_t){} > {}.capacity)", member(&dimension), member(sm)));
+            writer.scope_in();
+            writer.write_line("return ABF_ERROR_CAPACITY;");
+            writer.scope_out(false);
+            writer.write_line(&format!("{}.length = (size_t){};", member(sm), member(&dimension)));
+        },
+        _ => {
+            writer.write_with_offset(&format!("if ({}.length > {}.capacity)", member(sm), member(sm)));
+            writer.scope_in();
+            writer.write_line("return ABF_ERROR_CAPACITY;");
+            writer.scope_out(false);
+        },
+    }
+}
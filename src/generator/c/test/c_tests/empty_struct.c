@@ -0,0 +1,16 @@
+#include <utest/utest.h>
+#include "empty_struct.h"
+
+UTEST_MAIN();
+
+UTEST(empty_struct, serde) {
+    uint8_t buffer[16];
+    size_t written = 1;
+    size_t read = 1;
+    Empty empty = {0};
+
+    ASSERT_EQ(Empty_serialize(&empty, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 0u);
+    ASSERT_EQ(Empty_deserialize(&empty, buffer, sizeof(buffer), &read), ABF_OK);
+    ASSERT_EQ(read, 0u);
+}
@@ -0,0 +1,31 @@
+#include <utest/utest.h>
+#include "enum1.h"
+
+UTEST_MAIN();
+
+UTEST(enum1, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Car car = Car_Audi;
+
+    ASSERT_EQ(Car_serialize(&car, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 1u);
+    ASSERT_EQ(buffer[0], 10);
+
+    car = Car_Skoda;
+    ASSERT_EQ(Car_deserialize(&car, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(car, Car_Audi);
+}
+
+UTEST(enum1, unknown_value) {
+    uint8_t buffer[1] = {1};
+    size_t read;
+    Car car;
+
+    ASSERT_EQ(Car_deserialize(&car, buffer, sizeof(buffer), &read), ABF_ERROR_UNKNOWN_ENUM_VALUE);
+}
+
+UTEST(enum1, compiler_version) {
+    ASSERT_STRNE(ABF_COMPILER_VERSION, "");
+}
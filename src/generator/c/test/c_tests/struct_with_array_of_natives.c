@@ -0,0 +1,21 @@
+#include <utest/utest.h>
+#include "struct_with_array_of_natives.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_array_of_natives, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Dimension dim = {{10, 20, 30}};
+    Dimension dim_de = {{0}};
+
+    ASSERT_EQ(Dimension_serialize(&dim, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 6u);
+
+    ASSERT_EQ(Dimension_deserialize(&dim_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 6u);
+    ASSERT_EQ(dim_de.whl[0], 10);
+    ASSERT_EQ(dim_de.whl[1], 20);
+    ASSERT_EQ(dim_de.whl[2], 30);
+}
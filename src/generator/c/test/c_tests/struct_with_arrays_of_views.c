@@ -0,0 +1,45 @@
+#include <utest/utest.h>
+#include "struct_with_arrays_of_views.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_arrays_of_views, serde) {
+    uint8_t buffer[32];
+    size_t written;
+    size_t read;
+    NumbersSeq numbers = {0};
+    NumbersSeq numbers_de = {0};
+
+    numbers.num0[0].set = true; numbers.num0[0].type_id = 0; numbers.num0[0].as.u8 = 1;
+    numbers.num0[1].set = true; numbers.num0[1].type_id = 1; numbers.num0[1].as.u16 = 2;
+    numbers.num1[0].set = true; numbers.num1[0].type_id = 2; numbers.num1[0].as.u32 = 3;
+    numbers.num1[1].set = true; numbers.num1[1].type_id = 0; numbers.num1[1].as.u8 = 4;
+    numbers.num2[0].set = true; numbers.num2[0].type_id = 1; numbers.num2[0].as.u16 = 5;
+    numbers.num2[1].set = true; numbers.num2[1].type_id = 2; numbers.num2[1].as.u32 = 6;
+    ASSERT_EQ(NumbersSeq_serialize(&numbers, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 14u);
+
+    // views without key member are selected by caller
+    numbers_de.num0[0].set = true; numbers_de.num0[0].type_id = 0;
+    numbers_de.num0[1].set = true; numbers_de.num0[1].type_id = 1;
+    numbers_de.num1[0].set = true; numbers_de.num1[0].type_id = 2;
+    numbers_de.num1[1].set = true; numbers_de.num1[1].type_id = 0;
+    numbers_de.num2[0].set = true; numbers_de.num2[0].type_id = 1;
+    numbers_de.num2[1].set = true; numbers_de.num2[1].type_id = 2;
+    ASSERT_EQ(NumbersSeq_deserialize(&numbers_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 14u);
+    ASSERT_EQ(numbers_de.num0[0].as.u8, 1);
+    ASSERT_EQ(numbers_de.num0[1].as.u16, 2);
+    ASSERT_EQ(numbers_de.num1[0].as.u32, 3u);
+    ASSERT_EQ(numbers_de.num1[1].as.u8, 4);
+    ASSERT_EQ(numbers_de.num2[0].as.u16, 5);
+    ASSERT_EQ(numbers_de.num2[1].as.u32, 6u);
+}
+
+UTEST(struct_with_arrays_of_views, not_selected) {
+    uint8_t buffer[32] = {0};
+    size_t read;
+    NumbersSeq numbers_de = {0};
+
+    ASSERT_EQ(NumbersSeq_deserialize(&numbers_de, buffer, sizeof(buffer), &read), ABF_ERROR_NOT_SET);
+}
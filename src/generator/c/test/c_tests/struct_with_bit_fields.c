@@ -0,0 +1,43 @@
+#include <utest/utest.h>
+#include "struct_with_bit_fields.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_bit_fields, serde) {
+    uint8_t buffer[32];
+    size_t size;
+    size_t written;
+    size_t read;
+    Status status = {5, true, 0xA, 0x1234, -3, 0x123};
+    Status status_de = {0};
+
+    ASSERT_EQ(Status_size(&status, &size), ABF_OK);
+    ASSERT_EQ(size, 5u);
+    ASSERT_EQ(Status_serialize(&status, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 5u);
+    ASSERT_EQ(buffer[0], 0xBA);
+    ASSERT_EQ(buffer[1], 0x12);
+    ASSERT_EQ(buffer[2], 0x34);
+    ASSERT_EQ(buffer[3], 0xE9);
+    ASSERT_EQ(buffer[4], 0x23);
+
+    ASSERT_EQ(Status_deserialize(&status_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 5u);
+    ASSERT_EQ(status_de.mode, 5);
+    ASSERT_TRUE(status_de.enabled);
+    ASSERT_EQ(status_de.reserved, 0xA);
+    ASSERT_EQ(status_de.id, 0x1234);
+    ASSERT_EQ(status_de.level, -3);
+    ASSERT_EQ(status_de.counter, 0x123);
+}
+
+UTEST(struct_with_bit_fields, overflow) {
+    uint8_t buffer[32];
+    size_t written;
+    Status status = {8, true, 0, 0, 0, 0};
+
+    ASSERT_EQ(Status_serialize(&status, buffer, sizeof(buffer), &written), ABF_ERROR_OVERFLOW);
+    status.mode = 0;
+    status.level = -17;
+    ASSERT_EQ(Status_serialize(&status, buffer, sizeof(buffer), &written), ABF_ERROR_OVERFLOW);
+}
@@ -0,0 +1,51 @@
+#include <string.h>
+#include <utest/utest.h>
+#include "struct_with_checksum.h"
+
+UTEST_MAIN();
+
+static const uint8_t expected[] = {
+    0x50, 0xA5, 0x03, 0x01, 0x02, 0x03, 0x06,
+    0x6F, 0xAA,
+    0x34, 0xDC, 0xC8, 0xAA
+};
+
+UTEST(struct_with_checksum, serde) {
+    uint8_t buffer[32];
+    size_t written;
+    size_t read;
+    uint8_t payload[3] = {1, 2, 3};
+    uint8_t payload_de[4] = {0};
+    Frame frame = {0};
+    Frame frame_de = {0};
+
+    frame.header = 0xA5;
+    frame.payload.items = payload;
+    frame.payload.length = 3;
+    ASSERT_EQ(Frame_serialize(&frame, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, sizeof(expected));
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    frame_de.payload.items = payload_de;
+    frame_de.payload.capacity = 4;
+    ASSERT_EQ(Frame_deserialize(&frame_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(Frame_verify(&frame_de, buffer, written), ABF_OK);
+    ASSERT_EQ(frame_de.header_crc, 0x50);
+    ASSERT_EQ(frame_de.payload_sum, 0x06);
+    ASSERT_EQ(frame_de.crc, 0x6FAA);
+    ASSERT_EQ(frame_de.frame_crc, 0x34DCC8AAu);
+}
+
+UTEST(struct_with_checksum, mismatch) {
+    uint8_t buffer[sizeof(expected)];
+    size_t read;
+    uint8_t payload_de[4] = {0};
+    Frame frame_de = {0};
+
+    memcpy(buffer, expected, sizeof(expected));
+    buffer[4] ^= 0x01;
+    frame_de.payload.items = payload_de;
+    frame_de.payload.capacity = 4;
+    ASSERT_EQ(Frame_deserialize(&frame_de, buffer, sizeof(buffer), &read), ABF_OK);
+    ASSERT_EQ(Frame_verify(&frame_de, buffer, sizeof(buffer)), ABF_ERROR_CHECKSUM);
+}
@@ -0,0 +1,21 @@
+#include <utest/utest.h>
+#include "struct_with_constant.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_constant, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Message msg = {0};
+    Message msg_de = {0};
+
+    msg.value = 288;
+    ASSERT_EQ(Message_serialize(&msg, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 4u);
+
+    ASSERT_EQ(Message_deserialize(&msg_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(msg_de.byte0, 224);
+    ASSERT_EQ(msg_de.byte1, 3);
+    ASSERT_EQ(msg_de.value, 288);
+}
@@ -0,0 +1,22 @@
+#include <utest/utest.h>
+#include "struct_with_floats.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_floats, serde) {
+    uint8_t buffer[32];
+    size_t written;
+    size_t read;
+    Sample sample = {21.5f, 101325.25, 7};
+    Sample sample_de = {0};
+
+    ASSERT_EQ(Sample_serialize(&sample, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 14u);
+    ASSERT_EQ(buffer[0], 0x41);
+    ASSERT_EQ(buffer[1], 0xAC);
+
+    ASSERT_EQ(Sample_deserialize(&sample_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(sample_de.temperature, 21.5f);
+    ASSERT_EQ(sample_de.pressure, 101325.25);
+    ASSERT_EQ(sample_de.id, 7);
+}
@@ -0,0 +1,40 @@
+#include <string.h>
+#include <utest/utest.h>
+#include "struct_with_import.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_import, serde) {
+    static const uint8_t expected[] = {0x01, 0x00, 0x04, 0xDE, 0xAD, 0xBE, 0xEF};
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Message message = {0};
+    Message message_de = {0};
+
+    message.header.version = 1;
+    message.header.length = 4;
+    message.payload = 0xDEADBEEF;
+    ASSERT_EQ(Message_serialize(&message, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, sizeof(expected));
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    ASSERT_EQ(Message_deserialize(&message_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, sizeof(expected));
+    ASSERT_EQ(message_de.header.version, 1);
+    ASSERT_EQ(message_de.header.length, 4);
+    ASSERT_EQ(message_de.payload, 0xDEADBEEF);
+}
+
+UTEST(struct_with_import, imported_type) {
+    static const uint8_t expected[] = {0x02, 0x01, 0x02};
+    uint8_t buffer[16];
+    size_t written;
+    Header header = {0};
+
+    header.version = 2;
+    header.length = 0x0102;
+    ASSERT_EQ(Header_serialize(&header, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, sizeof(expected));
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+}
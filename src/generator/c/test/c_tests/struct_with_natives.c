@@ -0,0 +1,36 @@
+#include <utest/utest.h>
+#include "struct_with_natives.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_natives, serde) {
+    uint8_t buffer[16];
+    size_t size;
+    size_t written;
+    size_t read;
+    Date xmass = {2023, 24, 12};
+    Date xmass_de = {0};
+
+    ASSERT_EQ(Date_size(&xmass, &size), ABF_OK);
+    ASSERT_EQ(size, 4u);
+    ASSERT_EQ(Date_serialize(&xmass, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 4u);
+    ASSERT_EQ(buffer[0], 0x07);
+    ASSERT_EQ(buffer[1], 0xE7);
+
+    ASSERT_EQ(Date_deserialize(&xmass_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 4u);
+    ASSERT_EQ(xmass_de.year, 2023);
+    ASSERT_EQ(xmass_de.day, 24);
+    ASSERT_EQ(xmass_de.month, 12);
+}
+
+UTEST(struct_with_natives, buffer_too_small) {
+    uint8_t buffer[3];
+    size_t written;
+    size_t read;
+    Date xmass = {2023, 24, 12};
+
+    ASSERT_EQ(Date_serialize(&xmass, buffer, sizeof(buffer), &written), ABF_ERROR_BUFFER_TOO_SMALL);
+    ASSERT_EQ(Date_deserialize(&xmass, buffer, sizeof(buffer), &read), ABF_ERROR_SOURCE_TOO_SHORT);
+}
@@ -0,0 +1,36 @@
+#include <utest/utest.h>
+#include "struct_with_nostd_values.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_nostd_values, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    NoStdValues nostd = {0};
+    NoStdValues nostd_de = {0};
+
+    nostd.a = 120951;
+    ASSERT_EQ(NoStdValues_serialize(&nostd, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 6u);
+    if (ABF_BIG_ENDIAN) {
+        ASSERT_EQ(buffer[0], 0x01);
+        ASSERT_EQ(buffer[2], 0x77);
+    } else {
+        ASSERT_EQ(buffer[0], 0x77);
+        ASSERT_EQ(buffer[2], 0x01);
+    }
+
+    ASSERT_EQ(NoStdValues_deserialize(&nostd_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(nostd_de.a, 120951u);
+    ASSERT_EQ(nostd_de.b, 1234u);
+}
+
+UTEST(struct_with_nostd_values, overflow) {
+    uint8_t buffer[16];
+    size_t written;
+    NoStdValues nostd = {0};
+
+    nostd.a = 0x1000000;
+    ASSERT_EQ(NoStdValues_serialize(&nostd, buffer, sizeof(buffer), &written), ABF_ERROR_OVERFLOW);
+}
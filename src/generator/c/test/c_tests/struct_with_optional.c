@@ -0,0 +1,54 @@
+#include <string.h>
+#include <utest/utest.h>
+#include "struct_with_optional.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_optional, present) {
+    static const uint8_t expected[] = {0x08, 0x01, 0x02, 0x03, 0x00, 0xFF};
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Packet packet = {0};
+    Packet packet_de = {0};
+
+    packet.flags = 0x08;
+    packet.ext.code = 1;
+    packet.ext.value = 0x0203;
+    packet.extended = false;
+    packet.tail = 0x0405;
+    packet.trailer = 0xFF;
+    ASSERT_EQ(Packet_serialize(&packet, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, sizeof(expected));
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    ASSERT_EQ(Packet_deserialize(&packet_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, sizeof(expected));
+    ASSERT_EQ(packet_de.ext.code, 1);
+    ASSERT_EQ(packet_de.ext.value, 0x0203);
+    ASSERT_EQ(packet_de.tail, 0);
+    ASSERT_EQ(packet_de.trailer, 0xFF);
+}
+
+UTEST(struct_with_optional, absent) {
+    static const uint8_t expected[] = {0x00, 0x01, 0x04, 0x05, 0xEE};
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Packet packet = {0};
+    Packet packet_de = {0};
+
+    packet.flags = 0x00;
+    packet.extended = true;
+    packet.tail = 0x0405;
+    packet.trailer = 0xEE;
+    ASSERT_EQ(Packet_serialize(&packet, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, sizeof(expected));
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    ASSERT_EQ(Packet_deserialize(&packet_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, sizeof(expected));
+    ASSERT_EQ(packet_de.ext.code, 0);
+    ASSERT_EQ(packet_de.tail, 0x0405);
+    ASSERT_EQ(packet_de.trailer, 0xEE);
+}
@@ -0,0 +1,35 @@
+#include <utest/utest.h>
+#include "struct_with_reference_view.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_reference_view, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    KnownNumber known_number = {0};
+    KnownNumber known_number_de = {0};
+
+    known_number.number.set = true;
+    known_number.number.type_id = 1;
+    known_number.number.as.u16 = 12500;
+    ASSERT_EQ(KnownNumber_serialize(&known_number, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 3u);
+
+    ASSERT_EQ(KnownNumber_deserialize(&known_number_de, buffer, sizeof(buffer), &read), ABF_OK);
+    ASSERT_EQ(read, 3u);
+    ASSERT_EQ(known_number_de.number_key, 1);
+    ASSERT_TRUE(known_number_de.number.set);
+    ASSERT_EQ(known_number_de.number.type_id, 1u);
+    ASSERT_EQ(known_number_de.number.as.u16, 12500);
+}
+
+UTEST(struct_with_reference_view, errors) {
+    uint8_t buffer[16] = {7};
+    size_t written;
+    size_t read;
+    KnownNumber known_number = {0};
+
+    ASSERT_EQ(KnownNumber_serialize(&known_number, buffer, sizeof(buffer), &written), ABF_ERROR_NOT_SET);
+    ASSERT_EQ(KnownNumber_deserialize(&known_number, buffer, sizeof(buffer), &read), ABF_ERROR_UNKNOWN_TYPE_ID);
+}
@@ -0,0 +1,82 @@
+#include <string.h>
+#include <utest/utest.h>
+#include "struct_with_rest_array.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_rest_array, natives) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    uint16_t payload[] = {0x0102, 0x0304, 0x0506};
+    uint16_t payload_de[4] = {0};
+    Frame frame = {0};
+    Frame frame_de = {0};
+    const uint8_t expected[] = {7, 0x02, 0x01, 0x04, 0x03, 0x06, 0x05};
+
+    frame.kind = 7;
+    frame.payload.items = payload;
+    frame.payload.length = 3;
+    ASSERT_EQ(Frame_serialize(&frame, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 7u);
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    frame_de.payload.items = payload_de;
+    frame_de.payload.capacity = 4;
+    ASSERT_EQ(Frame_deserialize(&frame_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 7u);
+    ASSERT_EQ(frame_de.kind, 7);
+    ASSERT_EQ(frame_de.payload.length, 3u);
+    ASSERT_EQ(payload_de[2], 0x0506);
+
+    // items left in buffer must fit into capacity
+    frame_de.payload.capacity = 2;
+    ASSERT_EQ(Frame_deserialize(&frame_de, buffer, written, &read), ABF_ERROR_CAPACITY);
+}
+
+UTEST(struct_with_rest_array, structs) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Point points[] = {{1, 2}, {3, 4}};
+    Point points_de[2] = {{0}};
+    Path path = {0};
+    Path path_de = {0};
+
+    path.id = 5;
+    path.points.items = points;
+    path.points.length = 2;
+    ASSERT_EQ(Path_serialize(&path, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 5u);
+
+    path_de.points.items = points_de;
+    path_de.points.capacity = 2;
+    ASSERT_EQ(Path_deserialize(&path_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 5u);
+    ASSERT_EQ(path_de.points.length, 2u);
+    ASSERT_EQ(points_de[1].x, 3);
+    ASSERT_EQ(points_de[1].y, 4);
+}
+
+UTEST(struct_with_rest_array, string) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    char body[] = "hello";
+    char body_de[8] = {0};
+    Text text = {0};
+    Text text_de = {0};
+
+    text.id = 1;
+    text.body.items = body;
+    text.body.length = strlen(body);
+    ASSERT_EQ(Text_serialize(&text, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 6u);
+
+    text_de.body.items = body_de;
+    text_de.body.capacity = sizeof(body_de);
+    ASSERT_EQ(Text_deserialize(&text_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 6u);
+    ASSERT_EQ(text_de.body.length, 5u);
+    ASSERT_EQ(memcmp(body_de, "hello", 5), 0);
+}
@@ -0,0 +1,24 @@
+#include <utest/utest.h>
+#include "struct_with_size_reference.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_size_reference, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Main main_ = {0};
+    Main main_de = {0};
+
+    main_.volume.w = 10;
+    main_.volume.h = 20;
+    main_.volume.l = 30;
+    ASSERT_EQ(Main_serialize(&main_, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 8u);
+
+    ASSERT_EQ(Main_deserialize(&main_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(main_de.volume_size, 7);
+    ASSERT_EQ(main_de.volume.w, 10);
+    ASSERT_EQ(main_de.volume.h, 20);
+    ASSERT_EQ(main_de.volume.l, 30u);
+}
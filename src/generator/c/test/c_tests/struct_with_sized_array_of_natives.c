@@ -0,0 +1,39 @@
+#include <utest/utest.h>
+#include "struct_with_sized_array_of_natives.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_sized_array_of_natives, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    uint16_t whl[2] = {57, 58};
+    uint16_t whl_de[4] = {0};
+    Dimension dim = {0};
+    Dimension dim_de = {0};
+
+    dim.whl.items = whl;
+    dim.whl.length = 2;
+    ASSERT_EQ(Dimension_serialize(&dim, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 5u);
+
+    dim_de.whl.items = whl_de;
+    dim_de.whl.capacity = 4;
+    ASSERT_EQ(Dimension_deserialize(&dim_de, buffer, sizeof(buffer), &read), ABF_OK);
+    ASSERT_EQ(read, 5u);
+    ASSERT_EQ(dim_de.whl_dim, 2);
+    ASSERT_EQ(dim_de.whl.length, 2u);
+    ASSERT_EQ(whl_de[0], 57);
+    ASSERT_EQ(whl_de[1], 58);
+}
+
+UTEST(struct_with_sized_array_of_natives, capacity) {
+    uint8_t buffer[16] = {5};
+    size_t read;
+    uint16_t whl_de[4] = {0};
+    Dimension dim_de = {0};
+
+    dim_de.whl.items = whl_de;
+    dim_de.whl.capacity = 4;
+    ASSERT_EQ(Dimension_deserialize(&dim_de, buffer, sizeof(buffer), &read), ABF_ERROR_CAPACITY);
+}
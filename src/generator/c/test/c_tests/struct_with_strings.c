@@ -0,0 +1,34 @@
+#include <string.h>
+#include <utest/utest.h>
+#include "struct_with_strings.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_strings, serde) {
+    uint8_t buffer[32];
+    size_t written;
+    size_t read;
+    char name[] = "sensor";
+    char name_de[16] = {0};
+    Device device = {0};
+    Device device_de = {0};
+    const uint8_t expected[] = "SN42\0\0\0\0\x06sensor\x01\x02";
+
+    strncpy(device.serial, "SN42", sizeof(device.serial));
+    device.name.items = name;
+    device.name.length = strlen(name);
+    device.version = 0x0102;
+    ASSERT_EQ(Device_serialize(&device, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 17u);
+    ASSERT_EQ(memcmp(buffer, expected, written), 0);
+
+    device_de.name.items = name_de;
+    device_de.name.capacity = sizeof(name_de);
+    ASSERT_EQ(Device_deserialize(&device_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(read, 17u);
+    ASSERT_EQ(strncmp(device_de.serial, "SN42", sizeof(device_de.serial)), 0);
+    ASSERT_EQ(device_de.name_len, 6);
+    ASSERT_EQ(device_de.name.length, 6u);
+    ASSERT_EQ(memcmp(name_de, "sensor", 6), 0);
+    ASSERT_EQ(device_de.version, 0x0102);
+}
@@ -0,0 +1,26 @@
+#include <utest/utest.h>
+#include "struct_with_views.h"
+
+UTEST_MAIN();
+
+UTEST(struct_with_views, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    NumberSeq number_seq = {0};
+    NumberSeq number_seq_de = {0};
+
+    number_seq.num0.set = true; number_seq.num0.type_id = 0; number_seq.num0.as.u8 = 1;
+    number_seq.num1.set = true; number_seq.num1.type_id = 1; number_seq.num1.as.u16 = 20000;
+    number_seq.num2.set = true; number_seq.num2.type_id = 2; number_seq.num2.as.u32 = 3000000;
+    ASSERT_EQ(NumberSeq_serialize(&number_seq, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 7u);
+
+    number_seq_de.num0.set = true; number_seq_de.num0.type_id = 0;
+    number_seq_de.num1.set = true; number_seq_de.num1.type_id = 1;
+    number_seq_de.num2.set = true; number_seq_de.num2.type_id = 2;
+    ASSERT_EQ(NumberSeq_deserialize(&number_seq_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(number_seq_de.num0.as.u8, 1);
+    ASSERT_EQ(number_seq_de.num1.as.u16, 20000);
+    ASSERT_EQ(number_seq_de.num2.as.u32, 3000000u);
+}
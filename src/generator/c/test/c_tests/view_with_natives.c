@@ -0,0 +1,33 @@
+#include <utest/utest.h>
+#include "view_with_natives.h"
+
+UTEST_MAIN();
+
+UTEST(view_with_natives, serde) {
+    uint8_t buffer[16];
+    size_t written;
+    size_t read;
+    Numbers numbers = {0};
+    Numbers numbers_de = {0};
+
+    numbers.set = true;
+    numbers.type_id = 1;
+    numbers.as.u16 = 60000;
+    ASSERT_EQ(Numbers_serialize(&numbers, buffer, sizeof(buffer), &written), ABF_OK);
+    ASSERT_EQ(written, 2u);
+    ASSERT_EQ(buffer[0], 0x60);
+    ASSERT_EQ(buffer[1], 0xEA);
+
+    numbers_de.set = true;
+    numbers_de.type_id = 1;
+    ASSERT_EQ(Numbers_deserialize(&numbers_de, buffer, written, &read), ABF_OK);
+    ASSERT_EQ(numbers_de.as.u16, 60000);
+}
+
+UTEST(view_with_natives, not_set) {
+    uint8_t buffer[16];
+    size_t written;
+    Numbers numbers = {0};
+
+    ASSERT_EQ(Numbers_serialize(&numbers, buffer, sizeof(buffer), &written), ABF_ERROR_NOT_SET);
+}
@@ -0,0 +1,126 @@
+mod tests;
+
+use super::*;
+
+static ABF_DIR: &str = "src/generator/cpp/test/cpp_tests";
+static TEST_DIR: &str = "src/generator/c/test/c_tests";
+static UTEST_DIR: &str = "src/generator/cpp/test";
+
+// Generated code must build as strict C99, test itself uses utest which needs gnu extensions.
+fn compile_c(c_file: &str, object_file: &str, include_dir: &str, strict: bool) {
+    let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let mut args = vec![
+        format!("-I{}", include_dir),
+        format!("-I{}/{}", pwd, UTEST_DIR),
+    ];
+    if strict {
+        args.extend(["-std=c99", "-pedantic", "-Wall", "-Wextra", "-Werror"].map(String::from));
+    } else {
+        args.push("-std=gnu99".into());
+    }
+    args.extend(["-c".into(), c_file.into(), "-o".into(), object_file.into()]);
+
+    let term_out = std::process::Command::new("gcc")
+        .args(&args)
+        .output()
+        .expect("compilation failed");
+
+    println!("{}", String::from_utf8(term_out.stderr).unwrap());
+}
+
+fn generate_test(buffer_file: &str, test_file: &str, generate: bool, big_endian: bool) {
+    let endian: String = if big_endian { "big".into() } else { "little".into() };
+    let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let test_file_noext = std::path::Path::new(test_file)
+        .file_stem()
+        .expect("could not extract stem")
+        .to_str()
+        .unwrap();
+
+    let test_out_dir = format!("{}/{}/{}_{}_endian", pwd, TEST_DIR, test_file_noext, endian);
+
+    if generate {
+        let _ = std::fs::remove_dir_all(&test_out_dir); // try remove folder
+    }
+
+    let buffer_file_path = format!("{}/{}/{}", pwd, ABF_DIR, buffer_file);
+
+    if generate {
+        generate_code(
+            interpet_memory(&buffer_file_path)
+                .unwrap_or_else(|e| panic!("interpret failed: {}", render_diagnostics(&e))),
+            &Args {
+                protofile: buffer_file.into(),
+                language: Language::C,
+                endian: Some(endian),
+                output_dir: test_out_dir.clone(),
+                no_exceptions: false,
+            },
+        );
+    }
+
+    // every imported file is generated into its own source
+    let mut objects = Vec::new();
+    for entry in std::fs::read_dir(&test_out_dir).expect("could not read output directory") {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|e| e == "c") {
+            let object = path.with_extension("o").to_string_lossy().to_string();
+            compile_c(&path.to_string_lossy(), &object, &test_out_dir, true);
+            objects.push(object);
+        }
+    }
+
+    let test_object = format!("{}/{}_test.o", test_out_dir, test_file_noext);
+    compile_c(
+        &format!("{}/{}/{}", pwd, TEST_DIR, test_file),
+        &test_object,
+        &test_out_dir,
+        false,
+    );
+
+    let out = format!("{}/{}", test_out_dir, test_file_noext);
+
+    let res = std::process::Command::new("gcc")
+        .args(objects.iter().chain([&test_object, "-o", &out].map(String::from).iter()))
+        .output()
+        .expect("linking failed");
+
+    println!("{}", String::from_utf8(res.stderr).unwrap());
+
+    let result = std::process::Command::new(&out)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .expect("test failed");
+
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    let stderr = String::from_utf8(result.stderr).unwrap();
+
+    let status = result.status.success();
+
+    println!("{}", stdout);
+    println!("{}", stderr);
+
+    assert!(status);
+}
+
+// Generator refuses schema before any file is written.
+fn unsupported_feature(buffer_file: &str) -> String {
+    let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let buffer_file_path = format!("{}/{}/{}", pwd, ABF_DIR, buffer_file);
+    let result = crate::generator::generate(
+        interpet_memory(&buffer_file_path)
+            .unwrap_or_else(|e| panic!("interpret failed: {}", render_diagnostics(&e))),
+        &Args {
+            protofile: buffer_file.into(),
+            language: Language::C,
+            endian: Some("big".into()),
+            output_dir: format!("{}/{}/unsupported", pwd, TEST_DIR),
+            no_exceptions: false,
+        },
+    );
+    match result {
+        Ok(()) => panic!("generation succeeded"),
+        Err(e) => e.to_string(),
+    }
+}
@@ -0,0 +1,217 @@
+use super::*;
+
+#[test]
+fn view_with_natives() {
+    generate_test(
+        "view_with_natives.abf", 
+        "view_with_natives.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_natives() {
+    generate_test(
+        "struct_with_natives.abf", 
+        "struct_with_natives.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_views() {
+    generate_test(
+        "struct_with_views.abf", 
+        "struct_with_views.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_array_of_natives() {
+    generate_test(
+        "struct_with_array_of_natives.abf", 
+        "struct_with_array_of_natives.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_arrays_of_views() {
+    generate_test(
+        "struct_with_arrays_of_views.abf", 
+        "struct_with_arrays_of_views.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_sized_array_of_natives() {
+    generate_test(
+        "struct_with_sized_array_of_natives.abf", 
+        "struct_with_sized_array_of_natives.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_reference_view() {
+    generate_test(
+        "struct_with_reference_view.abf", 
+        "struct_with_reference_view.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_constant() {
+    generate_test(
+        "struct_with_constant.abf", 
+        "struct_with_constant.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_big_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_little_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.c", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn enum1() {
+    generate_test(
+        "enum1.abf", 
+        "enum1.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn empty_struct() {
+    generate_test(
+        "empty_struct.abf", 
+        "empty_struct.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_size_view() {
+    generate_test(
+        "struct_with_size_reference.abf", 
+        "struct_with_size_reference.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_floats() {
+    generate_test(
+        "struct_with_floats.abf", 
+        "struct_with_floats.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_bit_fields() {
+    generate_test(
+        "struct_with_bit_fields.abf", 
+        "struct_with_bit_fields.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_strings() {
+    generate_test(
+        "struct_with_strings.abf", 
+        "struct_with_strings.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_checksum() {
+    generate_test(
+        "struct_with_checksum.abf", 
+        "struct_with_checksum.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_optional() {
+    generate_test(
+        "struct_with_optional.abf", 
+        "struct_with_optional.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_import() {
+    generate_test(
+        "struct_with_import.abf", 
+        "struct_with_import.c", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_rest_array() {
+    generate_test(
+        "struct_with_rest_array.abf", 
+        "struct_with_rest_array.c", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_rest_array_of_unsized_items() {
+    assert_eq!(
+        unsupported_feature("struct_with_rest_array_of_unsized_items.abf"),
+        "Unsupported feature: Arrays running to end of message must have items of fixed size in language C"
+    );
+}
+
+#[test]
+fn struct_with_bounded_arrays() {
+    assert_eq!(
+        unsupported_feature("struct_with_bounded_arrays.abf"),
+        "Unsupported feature: Bounded arrays are not supported in language C"
+    );
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+pub fn generate_view_declaration(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("typedef struct {}", m.name));
+    writer.scope_in();
+    writer.write_line("bool set;");
+    writer.write_line("size_t type_id;");
+    writer.write_with_offset("union");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("{} {};", t.typename(), t.variable()));
+    }
+    writer.dedent();
+    writer.write_line("} as;");
+    writer.dedent();
+    writer.write_line(&format!("}} {};", m.name));
+    generate_prototypes(&m.name, writer);
+}
+
+pub fn generate_view_definition(m: &ViewMemory, writer: &mut Writer) {
+    generate_size(m, writer);
+    writer.write_line("");
+    generate_serialize(m, writer);
+    writer.write_line("");
+    generate_deserialize(m, writer);
+}
+
+fn alternative(t: &ViewPosibilityMemory) -> String {
+    format!("value->as.{}", t.variable())
+}
+
+fn generate_check_set(writer: &mut Writer) {
+    writer.write_with_offset("if (!value->set)");
+    writer.scope_in();
+    writer.write_line("return ABF_ERROR_NOT_SET;");
+    writer.scope_out(false);
+}
+
+// Emits switch over alternatives, `case_body` generates statements for single alternative.
+fn generate_switch(m: &ViewMemory, writer: &mut Writer, case_body: impl Fn(&ViewPosibilityMemory, &mut Writer)) {
+    writer.write_with_offset("switch (value->type_id)");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("case {}:", t.constant.get_value()));
+        writer.indent();
+        case_body(t, writer);
+        writer.write_line("break;");
+        writer.dedent();
+    }
+    writer.write_line("default:");
+    writer.indent();
+    writer.write_line("return ABF_ERROR_UNKNOWN_TYPE_ID;");
+    writer.dedent();
+    writer.scope_out(false);
+}
+
+fn generate_size(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_size(const {} *value, size_t *size)", m.name, m.name));
+    writer.scope_in();
+    if m.types.iter().any(|t| !t.memory.is_native()) {
+        writer.write_line("size_t n;");
+    }
+    generate_check_set(writer);
+    writer.write_line("*size = 0;");
+    generate_switch(m, writer, |t, writer| code::generate_item_size(&t.memory, &alternative(t), "*size", writer));
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+fn generate_serialize(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_serialize(const {} *value, uint8_t *dest, size_t dest_size, size_t *written)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    writer.write_line("size_t offset = 0;");
+    writer.write_line("size_t n;");
+    generate_check_set(writer);
+    generate_switch(m, writer, |t, writer| code::generate_item_serialize(&t.memory, &alternative(t), writer));
+    writer.write_line("*written = offset;");
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
+
+// Alternative is selected by caller or by key member of owning struct.
+fn generate_deserialize(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("abf_error {}_deserialize({} *value, const uint8_t *source, size_t source_size, size_t *read)",
+        m.name,
+        m.name));
+    writer.scope_in();
+    writer.write_line("size_t offset = 0;");
+    writer.write_line("size_t n;");
+    generate_check_set(writer);
+    generate_switch(m, writer, |t, writer| code::generate_item_deserialize(&t.memory, &alternative(t), writer));
+    writer.write_line("*read = offset;");
+    writer.write_line("return ABF_OK;");
+    writer.scope_out(false);
+}
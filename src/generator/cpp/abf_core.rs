@@ -5,17 +5,508 @@ pub static SOURCE: &str = "
 #include <cstdint>
 #include <cstring>
 #include <stdexcept>
+#include <string>
+#include <string_view>
 #include <vector>
 #include <limits>
+#include <optional>
+#include <type_traits>
+#if __cplusplus >= 202002L
+#include <bit>
+#include <span>
+#endif
+
+// Generated methods report errors by Expected values instead of exceptions when disabled.
+#ifndef ABF_EXCEPTIONS
+#define ABF_EXCEPTIONS <<EXCEPTIONS>>
+#endif
+
+#if ABF_EXCEPTIONS
+#define ABF_RAISE(error, exception, ...) throw exception
+#define ABF_RESULT(...) __VA_ARGS__
+#define ABF_ERROR_SCOPE
+#define ABF_RETURN(...) return __VA_ARGS__
+#else
+#define ABF_RAISE(error, exception, ...) do { abf::raise(error); return __VA_ARGS__; } while (false)
+#define ABF_RESULT(...) abf::Expected<__VA_ARGS__>
+#define ABF_ERROR_SCOPE abf::ErrorScope error_scope_
+#define ABF_RETURN(...) return {__VA_ARGS__, error_scope_.error()}
+#endif
 
 namespace abf {
-<<BSWAP_SOURCE>>
+    // Version of compiler which generated this code.
+    inline constexpr std::string_view COMPILER_VERSION = \"<<COMPILER_VERSION>>\";
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // ERRORS
+    ////////////////////////////////////////////////////////////////////////////////
+
+    enum class Error {
+        None,
+        NotSet,
+        SourceNotSet,
+        NotDeserialized,
+        // Position of member is not known, e.g. it follows view without key.
+        Unresolved,
+        // Optional member is not present.
+        Absent,
+        OutOfRange,
+        BufferTooShort,
+        BufferTooSmall,
+        WrongViewType,
+        UnknownViewType,
+        EmptyItem,
+        UnsupportedSize,
+    };
+
+    // Without exceptions failing call records its error and returns default value,
+    // first error recorded is kept until cleared.
+    inline Error& last_error() {
+        static thread_local Error error = Error::None;
+        return error;
+    }
+
+    inline void clear_error() {
+        last_error() = Error::None;
+    }
+
+    inline void raise(Error error) {
+        if (last_error() == Error::None) {
+            last_error() = error;
+        }
+    }
+
+    // Public method collects errors of calls it makes, they are passed on to method calling it.
+    class ErrorScope {
+    public:
+        ErrorScope() : outer_(last_error()) {
+            last_error() = Error::None;
+        }
+
+        ~ErrorScope() {
+            if (outer_ != Error::None) {
+                last_error() = outer_;
+            }
+        }
+
+        Error error() const {
+            return last_error();
+        }
+
+    private:
+        Error outer_;
+    };
+
+    // Value of public method when exceptions are disabled, value is default one on error.
+    template <typename T>
+    class Expected {
+    public:
+        Expected(T value) : value_(value), error_(Error::None) {}
+        Expected(Error error) : value_(), error_(error) {}
+        Expected(T value, Error error) : value_(value), error_(error) {}
+
+        bool has_value() const {
+            return error_ == Error::None;
+        }
+
+        explicit operator bool() const {
+            return has_value();
+        }
+
+        Error error() const {
+            return error_;
+        }
+
+        T& value() {
+            return value_;
+        }
+
+        T& operator*() {
+            return value_;
+        }
+
+        T* operator->() {
+            return &value_;
+        }
+
+    private:
+        T value_;
+        Error error_;
+    };
+
+    // Nested deserializer is returned even on error, so calls on it can be chained.
+    template <typename T>
+    class Expected<T&> {
+    public:
+        Expected(T& value) : value_(&value), error_(Error::None) {}
+        Expected(T& value, Error error) : value_(&value), error_(error) {}
+
+        bool has_value() const {
+            return error_ == Error::None;
+        }
+
+        explicit operator bool() const {
+            return has_value();
+        }
+
+        Error error() const {
+            return error_;
+        }
+
+        T& value() {
+            return *value_;
+        }
+
+        T& operator*() {
+            return *value_;
+        }
+
+        T* operator->() {
+            return value_;
+        }
+
+    private:
+        T* value_;
+        Error error_;
+    };
+
+    template <typename T>
+    inline T value_of(T value) {
+        return value;
+    }
+
+    template <typename T>
+    inline T value_of(Expected<T> result) {
+        if (!result) {
+            raise(result.error());
+        }
+        return *result;
+    }
+
+    // Item handed out for index out of range when exceptions are disabled, writes to it are dropped.
+    template <typename T>
+    inline T& discarded() {
+        static thread_local T item;
+        item.init();
+        return item;
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // BYTE ORDER
+    ////////////////////////////////////////////////////////////////////////////////
+
+    // Endian of schema, struct or member may override it.
+    inline constexpr bool SCHEMA_BIG_ENDIAN = <<BIG_ENDIAN>>;
+    constexpr bool detect_host_big_endian() {
+#if __cplusplus >= 202002L
+        return std::endian::native == std::endian::big;
+#elif defined(__BYTE_ORDER__) && defined(__ORDER_BIG_ENDIAN__)
+        return __BYTE_ORDER__ == __ORDER_BIG_ENDIAN__;
+#elif defined(_MSC_VER)
+        return false;
+#else
+#error \"Host endian cannot be detected, generate code with explicit endian\"
+#endif
+    }
+
+    // Endian of machine compiling the code unless it was given to generator.
+    inline constexpr bool HOST_BIG_ENDIAN = <<HOST_BIG_ENDIAN>>;
+
+    inline uint8_t bswap8(uint8_t value) {
+        return value;
+    }
+
+    inline uint16_t bswap16(uint16_t value) {
+        return (static_cast<uint16_t>(bswap8(static_cast<uint8_t>(value))) << 8) |
+               (bswap8(static_cast<uint8_t>(value >> 8)));
+    }
+
+    inline uint32_t bswap32(uint32_t value) {
+        return (static_cast<uint32_t>(bswap16(static_cast<uint16_t>(value))) << 16) |
+               (bswap16(static_cast<uint16_t>(value >> 16)));
+    }
+
+    inline uint64_t bswap64(uint64_t value) {
+        return (static_cast<uint64_t>(bswap32(static_cast<uint32_t>(value))) << 32) |
+               (bswap32(static_cast<uint32_t>(value >> 32)));
+    }
+
+    inline void bswap8_ptr(uint8_t* ptr) {
+        *ptr = bswap8(*ptr);
+    }
+
+    inline void bswap16_ptr(uint16_t* ptr) {
+        *ptr = bswap16(*ptr);
+    }
+
+    inline void bswap32_ptr(uint32_t* ptr) {
+        *ptr = bswap32(*ptr);
+    }
+
+    inline void bswap64_ptr(uint64_t* ptr) {
+        *ptr = bswap64(*ptr);
+    }
+
+    inline void bswap_copy(void* dest, const void* source, size_t size) {
+        switch (size) {
+            case 1:
+                std::memcpy(dest, source, size);
+                bswap8_ptr(static_cast<uint8_t*>(dest));
+                break;
+            case 2:
+                std::memcpy(dest, source, size);
+                bswap16_ptr(static_cast<uint16_t*>(dest));
+                break;
+            case 3:
+                for (size_t i = 0; i < size; i++) {
+                    static_cast<uint8_t*>(dest)[i] = static_cast<const uint8_t*>(source)[size - 1 - i];
+                }
+                break;
+            case 4:
+                std::memcpy(dest, source, size);
+                bswap32_ptr(static_cast<uint32_t*>(dest));
+                break;
+            case 8:
+                std::memcpy(dest, source, size);
+                bswap64_ptr(static_cast<uint64_t*>(dest));
+                break;
+            default:
+                ABF_RAISE(Error::UnsupportedSize, std::runtime_error(\"Unsupported size\"));
+        }
+    }
+
+    inline void no_bswap_copy(void* dest, const void* source, size_t size) {
+        switch (size) {
+            case 1:
+            case 2:
+            case 3:
+            case 4:
+            case 8:
+                std::memcpy(dest, source, size);
+                break;
+        }
+    }
+
+    // Bytes are swapped only when data endian differs from host endian.
+    template <bool BigEndian>
+    inline void copy(void* dest, const void* source, size_t size) {
+        if constexpr (BigEndian == HOST_BIG_ENDIAN) {
+            no_bswap_copy(dest, source, size);
+        } else {
+            bswap_copy(dest, source, size);
+        }
+    }
+
+    // Value is read from exactly size bytes of buffer. Three bytes are assembled
+    // into wider value, so its layout on host does not matter.
+    template <bool BigEndian, typename T>
+    inline void load(T& value, const uint8_t* source, size_t size) {
+        if (size != 3) {
+            copy<BigEndian>(&value, source, size);
+            return;
+        }
+        uint32_t u24 = 0;
+        for (size_t i = 0; i < size; i++) {
+            u24 |= static_cast<uint32_t>(source[i]) << (8 * (BigEndian ? size - 1 - i : i));
+        }
+        value = static_cast<T>(u24);
+    }
+
+    // Value is written to exactly size bytes of buffer, taken from its low bytes.
+    template <bool BigEndian, typename T>
+    inline void store(uint8_t* dest, const T& value, size_t size) {
+        if (size != 3) {
+            copy<BigEndian>(dest, &value, size);
+            return;
+        }
+        uint32_t u24 = static_cast<uint32_t>(value);
+        for (size_t i = 0; i < size; i++) {
+            dest[i] = static_cast<uint8_t>(u24 >> (8 * (BigEndian ? size - 1 - i : i)));
+        }
+    }
+    ////////////////////////////////////////////////////////////////////////////////
+    // BOUNDS
+    ////////////////////////////////////////////////////////////////////////////////
+
+    // Data of truncated buffer ends before the bytes being read.
+    class BufferTooShort : public std::runtime_error {
+    public:
+        BufferTooShort() : std::runtime_error(\"Buffer too short\") {}
+    };
+
+    // Buffer given to serializer cannot hold the whole message.
+    class BufferTooSmall : public std::runtime_error {
+    public:
+        BufferTooSmall() : std::runtime_error(\"Buffer too small\") {}
+    };
+
+    // Limit is end of buffer given to deserializer, nullptr when its length is not known.
+    inline bool in_bounds(const uint8_t* source, const uint8_t* limit, size_t size) {
+        return limit == nullptr || (source <= limit && static_cast<size_t>(limit - source) >= size);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // TERMINATOR
+    ////////////////////////////////////////////////////////////////////////////////
+
+    // Item holding sentinel ends terminated array, struct item is compared by its member at Offset.
+    template <typename TData, uint32_t Size, TData Sentinel, uint32_t Stride = Size, uint32_t Offset = 0, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    struct Terminator {
+        static constexpr uint32_t stride = Stride;
+
+        static bool at(const uint8_t* item) {
+            TData value{};
+            load<BigEndian>(value, item + Offset, Size);
+            return value == Sentinel;
+        }
+
+        // Bytes of terminating item other than sentinel are zero.
+        static uint32_t serialize(uint8_t* dest) {
+            std::memset(dest, 0, Stride);
+            TData value = Sentinel;
+            store<BigEndian>(dest + Offset, value, Size);
+            return Stride;
+        }
+    };
+
+    // Count is number of items before terminator, false when limit is reached first.
+    template <typename TTerminator>
+    inline bool find_terminator(const uint8_t* source, const uint8_t* limit, uint32_t& count) {
+        for (count = 0; in_bounds(source, limit, TTerminator::stride); count++, source += TTerminator::stride) {
+            if (TTerminator::at(source)) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    // Without limit items are scanned until terminator is found.
+    template <typename TTerminator>
+    inline uint32_t count_until(const uint8_t* source, const uint8_t* limit) {
+        uint32_t count = 0;
+        if (!find_terminator<TTerminator>(source, limit, count)) {
+            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), count);
+        }
+        return count;
+    }
+
+    template <typename TTerminator>
+    inline uint32_t count_until(const uint8_t* source, uint32_t size) {
+        return count_until<TTerminator>(source, source + size);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // VALIDATION
+    ////////////////////////////////////////////////////////////////////////////////
+
+    enum class ValidationErrorKind {
+        None,
+        // Bytes of member are past end of buffer.
+        BufferTooShort,
+        // Position of member is not known, e.g. it follows view without key.
+        Unresolved,
+        UnknownEnumValue,
+        UnknownViewKey,
+        ConstantMismatch,
+        SizeMismatch,
+        // Bounded array has more items than its bound.
+        BoundExceeded,
+    };
+
+    // First error found in message, path names the failing member, e.g. \"entry.values[1]\".
+    struct ValidationError {
+        ValidationErrorKind kind = ValidationErrorKind::None;
+        std::string path;
+
+        bool ok() const {
+            return kind == ValidationErrorKind::None;
+        }
+    };
+
+    inline ValidationError prefix_path(ValidationError error, std::string_view prefix) {
+        if (error.ok()) {
+            return error;
+        }
+        if (error.path.empty() || error.path[0] == '[') {
+            error.path = std::string(prefix) + error.path;
+        } else {
+            error.path = std::string(prefix) + \".\" + error.path;
+        }
+        return error;
+    }
+
+    template <typename TDeserializer>
+    inline ValidationError validate_item(TDeserializer& item, uint32_t i) {
+        return prefix_path(item._validate(), \"[\" + std::to_string(i) + \"]\");
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // CHECKSUM
+    ////////////////////////////////////////////////////////////////////////////////
+
+    // CRC-8/SMBUS: poly 0x07, init 0x00.
+    inline uint8_t crc8(const uint8_t* data, uint32_t size) {
+        uint8_t crc = 0;
+        for (uint32_t i = 0; i < size; i++) {
+            crc ^= data[i];
+            for (int bit = 0; bit < 8; bit++) {
+                crc = (crc & 0x80) ? static_cast<uint8_t>((crc << 1) ^ 0x07) : static_cast<uint8_t>(crc << 1);
+            }
+        }
+        return crc;
+    }
+
+    // CRC-16/CCITT-FALSE: poly 0x1021, init 0xFFFF.
+    inline uint16_t crc16_ccitt(const uint8_t* data, uint32_t size) {
+        uint16_t crc = 0xFFFF;
+        for (uint32_t i = 0; i < size; i++) {
+            crc ^= static_cast<uint16_t>(data[i]) << 8;
+            for (int bit = 0; bit < 8; bit++) {
+                crc = (crc & 0x8000) ? static_cast<uint16_t>((crc << 1) ^ 0x1021) : static_cast<uint16_t>(crc << 1);
+            }
+        }
+        return crc;
+    }
+
+    // CRC-32/ISO-HDLC: reflected poly 0xEDB88320, init and final xor 0xFFFFFFFF.
+    inline uint32_t crc32(const uint8_t* data, uint32_t size) {
+        uint32_t crc = 0xFFFFFFFF;
+        for (uint32_t i = 0; i < size; i++) {
+            crc ^= data[i];
+            for (int bit = 0; bit < 8; bit++) {
+                crc = (crc & 1) ? (crc >> 1) ^ 0xEDB88320 : crc >> 1;
+            }
+        }
+        return ~crc;
+    }
+
+    // Additive sum of bytes truncated to width of TData.
+    template <typename TData>
+    inline TData sum(const uint8_t* data, uint32_t size) {
+        TData sum = 0;
+        for (uint32_t i = 0; i < size; i++) {
+            sum = static_cast<TData>(sum + data[i]);
+        }
+        return sum;
+    }
+
     ////////////////////////////////////////////////////////////////////////////////
     // SERIALIZER
     ////////////////////////////////////////////////////////////////////////////////
 
-    template <typename TData, uint32_t Size>
+    // Nothing is written unless all bytes fit into capacity.
+    template <typename TSerializer>
+    inline ABF_RESULT(uint32_t) serialize_into(TSerializer& serializer, uint8_t* dest, size_t capacity) {
+        ABF_ERROR_SCOPE;
+        if (serializer.size() > capacity) {
+            ABF_RAISE(Error::BufferTooSmall, BufferTooSmall(), Error::BufferTooSmall);
+        }
+        ABF_RETURN(value_of(serializer.serialize(dest)));
+    }
+
+    template <typename TData, uint32_t Size, bool BigEndian = SCHEMA_BIG_ENDIAN>
     class NativeSerializer {
+        static_assert(sizeof(TData) >= Size, \"Native type smaller than its bytes\");
     public:
         using Data = TData;
 
@@ -23,17 +514,34 @@ namespace abf {
 
         uint32_t serialize(uint8_t* dest) {
             if (!set_) {
-                throw std::runtime_error(\"Not set\");
+                ABF_RAISE(Error::NotSet, std::runtime_error(\"Not set\"), 0);
             }
-            copy(dest, &data_, Size);
+            store<BigEndian>(dest, data_, Size);
             return Size;
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
         void set_data(TData data) {
             data_ = data;
             set_ = true;
         }
 
+        TData get_data() {
+            if (!set_) {
+                ABF_RAISE(Error::NotSet, std::runtime_error(\"Not set\"), TData());
+            }
+            return data_;
+        }
+
         uint32_t size() {
             return Size;
         }
@@ -47,6 +555,91 @@ namespace abf {
         bool set_;
     };
 
+    // Bit field occupies Bits bits of container starting at Shift.
+    template <typename TData, typename TContainer, uint32_t Shift, uint32_t Bits>
+    struct BitFieldPacking {
+        static constexpr TContainer mask() {
+            return ((static_cast<TContainer>(1) << Bits) - 1) << Shift;
+        }
+
+        static TContainer insert(TContainer container, TData data) {
+            return (container & ~mask()) | ((static_cast<TContainer>(data) << Shift) & mask());
+        }
+
+        static TData extract(TContainer container) {
+            TContainer value = (container & mask()) >> Shift;
+            if (std::is_signed<TData>::value && ((value >> (Bits - 1)) & 1)) {
+                value |= ~(mask() >> Shift);
+            }
+            return static_cast<TData>(value);
+        }
+    };
+
+    // Members of bit field group share container, the member closing the group
+    // accounts for its bytes.
+    template <typename TData, typename TContainer, uint32_t Size, uint32_t Shift, uint32_t Bits, bool ClosesGroup, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    class BitFieldSerializer {
+        static_assert(sizeof(TContainer) >= Size && Shift + Bits <= Size * 8, \"Bit field outside its container\");
+    public:
+        using Data = TData;
+        using Packing = BitFieldPacking<TData, TContainer, Shift, Bits>;
+
+        BitFieldSerializer() : data_(), set_(false) {}
+
+        uint32_t serialize(uint8_t* dest) {
+            if (!set_) {
+                ABF_RAISE(Error::NotSet, std::runtime_error(\"Not set\"), 0);
+            }
+            TContainer container = 0;
+            load<BigEndian>(container, dest, Size);
+            container = Packing::insert(container, data_);
+            store<BigEndian>(dest, container, Size);
+            return size();
+        }
+
+        // Container is written whole, also by members not accounting for its bytes.
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            ABF_ERROR_SCOPE;
+            if (Size > capacity) {
+                ABF_RAISE(Error::BufferTooSmall, BufferTooSmall(), Error::BufferTooSmall);
+            }
+            ABF_RETURN(serialize(dest));
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
+        void set_data(TData data) {
+            if (Packing::extract(Packing::insert(0, data)) != data) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Bit field overflow\"));
+            }
+            data_ = data;
+            set_ = true;
+        }
+
+        TData get_data() {
+            if (!set_) {
+                ABF_RAISE(Error::NotSet, std::runtime_error(\"Not set\"), TData());
+            }
+            return data_;
+        }
+
+        uint32_t size() {
+            return ClosesGroup ? Size : 0;
+        }
+
+        void init() {
+            set_ = false;
+        }
+
+    private:
+        TData data_;
+        bool set_;
+    };
+
     template <typename TSerializer, typename TValue, TValue Value>
     class ConstantSerializer {
     public:
@@ -60,6 +653,16 @@ namespace abf {
             return serializer_.serialize(dest);
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
         void set_data(Data data) {
         }
 
@@ -80,16 +683,33 @@ namespace abf {
     public:
         using Data = TSerializer::Data;
 
-        LazySerializer() : serializer_(), set_(false) {}
+        LazySerializer() : serializer_(), dest_(nullptr), set_(false) {}
 
         uint32_t serialize(uint8_t* dest) {
             dest_ = dest;
+            if (set_) {
+                serializer_.serialize(dest_);
+            }
             return serializer_.size();
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
+        // Value set before its place is known is written by serialize.
         void set_data(Data data) {
             serializer_.set_data(data);
-            serializer_.serialize(dest_);
+            set_ = true;
+            if (dest_ != nullptr) {
+                serializer_.serialize(dest_);
+            }
         }
 
         uint32_t size() {
@@ -98,6 +718,7 @@ namespace abf {
 
         void init() {
             serializer_.init();
+            set_ = false;
         }
 
     private:
@@ -116,7 +737,7 @@ namespace abf {
         virtual void set_u64(uint64_t data) = 0;
     };
 
-    template <typename TNativeData, uint32_t NativeSize>
+    template <typename TNativeData, uint32_t NativeSize, bool BigEndian = SCHEMA_BIG_ENDIAN>
     class ViewKeySerializer : public IViewKeySetter {
         friend class ViewKeySetter;
     public:
@@ -126,6 +747,16 @@ namespace abf {
             return native_.serialize(dest);
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
         void set_u8(uint8_t data) override {
             native_.set_data(static_cast<TNativeData>(data));
         }
@@ -151,7 +782,7 @@ namespace abf {
         }
 
     private:
-        LazySerializer<NativeSerializer<TNativeData, NativeSize>> native_;
+        LazySerializer<NativeSerializer<TNativeData, NativeSize, BigEndian>> native_;
     };
 
     template <typename TSerialzer, uint32_t Size>
@@ -164,14 +795,24 @@ namespace abf {
         uint32_t serialize(uint8_t* dest) {
             uint8_t* current_dest = dest;
             for (auto& si : serializers_) {
-                current_dest += si.serialize(current_dest);
+                current_dest += value_of(si.serialize(current_dest));
             }
             return current_dest - dest;
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
         TSerialzer& get(uint32_t i) {
-            if (i > Size) {
-                throw std::runtime_error(\"Out of range\");
+            if (i >= Size) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), discarded<TSerialzer>());
             }
             return serializers_[i];
         }
@@ -194,6 +835,12 @@ namespace abf {
             return Size;
         }
 
+        void resize(uint32_t length) {
+            if (length > Size) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"));
+            }
+        }
+
     private:
         TSerialzer serializers_[Size];
     };
@@ -208,11 +855,21 @@ namespace abf {
         uint32_t serialize(uint8_t* dest) {
             uint8_t* current_dest = dest;
             for (auto& si : serializers_) {
-                current_dest += si.serialize(current_dest);
+                current_dest += value_of(si.serialize(current_dest));
             }
             return current_dest - dest;
         }
 
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
         TSerialzer& get(uint32_t i) {
             while (i + 1 > serializers_.size()) {
                 serializers_.push_back(TSerialzer());
@@ -239,27 +896,224 @@ namespace abf {
             return serializers_.size();
         }
 
+        void resize(uint32_t length) {
+            serializers_.resize(length);
+        }
+
     private:
         std::vector<TSerialzer> serializers_;
     };
 
-    template <typename TArraySerialzer, typename TSizeSerializer>
-    class ArraySizedSerializer {
+    // Items of bounded array are stored inline, nothing is allocated.
+    template <typename TSerialzer, uint32_t MaxSize>
+    class BoundedArraySerializer {
     public:
-        ArraySizedSerializer() : array_(), size_() {}
+        using ItemSerializer = TSerialzer;
+
+        BoundedArraySerializer() : serializers_(), length_(0) {}
 
         uint32_t serialize(uint8_t* dest) {
-            size_->set_data(array_.length());
-            return array_.serialize(dest);
+            uint8_t* current_dest = dest;
+            for (uint32_t i = 0; i < length_; i++) {
+                current_dest += value_of(serializers_[i].serialize(current_dest));
+            }
+            return current_dest - dest;
+        }
+
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
+        TSerialzer& get(uint32_t i) {
+            if (i >= MaxSize) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), discarded<TSerialzer>());
+            }
+            while (length_ < i + 1) {
+                serializers_[length_++].init();
+            }
+            return serializers_[i];
+        }
+
+        void init() {
+            for (uint32_t i = 0; i < length_; i++) {
+                serializers_[i].init();
+            }
+        }
+
+        uint32_t size() {
+            uint32_t all_sizes = 0;
+            for (uint32_t i = 0; i < length_; i++) {
+                all_sizes += serializers_[i].size();
+            }
+            return all_sizes;
+        }
+
+        uint32_t length() {
+            return length_;
+        }
+
+        void resize(uint32_t length) {
+            if (length > MaxSize) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"));
+            }
+            while (length_ < length) {
+                serializers_[length_++].init();
+            }
+            length_ = length;
+        }
+
+    private:
+        TSerialzer serializers_[MaxSize];
+        uint32_t length_;
+    };
+
+    // Terminator is written after items, item holding sentinel would end array early.
+    template <typename TSerialzer, typename TTerminator>
+    class TerminatedArraySerializer {
+    public:
+        using ItemSerializer = TSerialzer;
+
+        TerminatedArraySerializer() : array_() {}
+
+        // Item equal to terminator would end array early when read back.
+        uint32_t serialize(uint8_t* dest) {
+            uint32_t items_size = array_.serialize(dest);
+            for (uint32_t i = 0; i < array_.length(); i++) {
+                if (TTerminator::at(dest + i * TTerminator::stride)) {
+                    ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Item equals terminator\"), 0);
+                }
+            }
+            return items_size + TTerminator::serialize(dest + items_size);
+        }
+
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
+        TSerialzer& get(uint32_t i) {
+            return array_.get(i);
+        }
+
+        void init() {
+            array_.init();
+        }
+
+        uint32_t size() {
+            return array_.size() + TTerminator::stride;
+        }
+
+        uint32_t length() {
+            return array_.length();
+        }
+
+        void resize(uint32_t length) {
+            array_.resize(length);
+        }
+
+    private:
+        DynArraySerializer<TSerialzer> array_;
+    };
+
+    // Fixed size string is padded with NUL characters, dynamic string takes
+    // exactly length of data.
+    template <typename TArraySerialzer>
+    class StringSerializer {
+    public:
+        using ItemSerializer = TArraySerialzer::ItemSerializer;
+        using Data = std::string_view;
+
+        StringSerializer() : array_() {}
+
+        uint32_t serialize(uint8_t* dest) {
+            return array_.serialize(dest);
+        }
+
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
         }
+#endif
+
+        void set_data(std::string_view data) {
+            array_.resize(data.size());
+            for (uint32_t i = 0; i < array_.length(); i++) {
+                array_.get(i).set_data(i < data.size() ? data[i] : '\\0');
+            }
+        }
+
+        ItemSerializer& get(uint32_t i) {
+            return array_.get(i);
+        }
+
+        void init() {
+            array_.init();
+        }
+
+        uint32_t size() {
+            return array_.size();
+        }
+
+        uint32_t length() {
+            return array_.length();
+        }
+
+    private:
+        TArraySerialzer array_;
+    };
+
+    template <typename TArraySerialzer, typename TSizeSerializer>
+    class ArraySizedSerializer {
+    public:
+        ArraySizedSerializer() : array_(), size_(nullptr) {}
+
+        // size member is wired by owning struct, or by struct embedding it when it lies outside
+        uint32_t serialize(uint8_t* dest) {
+            if (size_ != nullptr) {
+                size_->set_data(array_.length());
+            }
+            return array_.serialize(dest);
+        }
+
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
 
         TArraySerialzer::ItemSerializer& get(uint32_t i) {
             return array_.get(i);
         }
 
+        template <typename TData>
+        void set_data(TData data) {
+            array_.set_data(data);
+        }
+
         void init() {
             array_.init();
-            size_->init();
+            if (size_ != nullptr) {
+                size_->init();
+            }
         }
 
         uint32_t size() {
@@ -283,48 +1137,360 @@ namespace abf {
         void set_data(TDataFrom data) {
             using DataType = typename TSerialzer::Data;
 
-            if (data < std::numeric_limits<DataType>::min()) {
-                throw std::runtime_error(\"minimum violated\");
+            if (data < std::numeric_limits<DataType>::min()) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"minimum violated\"));
+            }
+            if (data > std::numeric_limits<DataType>::max()) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"maximum violated\"));
+            }
+            serializer_.set_data(reinterpret_cast<DataType>(data));
+        }
+
+        uint32_t serialize(uint8_t* dest) {
+            return serializer_.serialize(dest);
+        }
+
+        ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity) {
+            return serialize_into(*this, dest, capacity);
+        }
+
+#if __cplusplus >= 202002L
+        ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest) {
+            return serialize(dest.data(), dest.size());
+        }
+#endif
+
+        uint32_t size() {
+            return serializer_.size();
+        }
+
+        void init() {
+            serializer_.init();
+        }
+
+    private:
+        TSerialzer serializer_;
+    };
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // DESERIALIZER
+    ////////////////////////////////////////////////////////////////////////////////
+
+    template <typename TData, uint32_t Size, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    class NativeDeserializer {
+        static_assert(sizeof(TData) >= Size, \"Native type smaller than its bytes\");
+    public:
+        NativeDeserializer() : source_(nullptr), limit_(nullptr) {}
+        NativeDeserializer(const uint8_t* source) : source_(source), limit_(nullptr) {}
+
+        TData get_data() {
+            if (!_deserialized()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), TData());
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                ABF_RAISE(Error::BufferTooShort, BufferTooShort(), TData());
+            }
+            TData value = 0;
+            load<BigEndian>(value, source_, Size);
+            return value;
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return source_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            return source_ + Size;
+        }
+
+        void init() {
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+    private:
+        const uint8_t *source_;
+        const uint8_t *limit_;
+    };
+
+    template <typename TData, typename TContainer, uint32_t Size, uint32_t Shift, uint32_t Bits, bool ClosesGroup, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    class BitFieldDeserializer {
+        static_assert(sizeof(TContainer) >= Size && Shift + Bits <= Size * 8, \"Bit field outside its container\");
+    public:
+        using Packing = BitFieldPacking<TData, TContainer, Shift, Bits>;
+
+        BitFieldDeserializer() : source_(nullptr), limit_(nullptr) {}
+        BitFieldDeserializer(const uint8_t* source) : source_(source), limit_(nullptr) {}
+
+        TData get_data() {
+            if (!_deserialized()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), TData());
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                ABF_RAISE(Error::BufferTooShort, BufferTooShort(), TData());
+            }
+            TContainer container = 0;
+            load<BigEndian>(container, source_, Size);
+            return Packing::extract(container);
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return source_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            return ClosesGroup ? source_ + Size : source_;
+        }
+
+        void init() {
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+    private:
+        const uint8_t *source_;
+        const uint8_t *limit_;
+    };
+
+    // Fixed size string ends at first NUL character.
+    template <uint32_t Size>
+    class StringDeserializer {
+    public:
+        StringDeserializer() : source_(nullptr), limit_(nullptr) {}
+        StringDeserializer(const uint8_t* source) : source_(source), limit_(nullptr) {}
+
+        std::string_view get_data() {
+            if (!_deserialized()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), std::string_view());
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                ABF_RAISE(Error::BufferTooShort, BufferTooShort(), std::string_view());
+            }
+            auto data = reinterpret_cast<const char*>(source_);
+            return std::string_view(data, strnlen(data, Size));
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return source_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            if (!in_bounds(source_, limit_, Size)) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            return source_ + Size;
+        }
+
+        void init() {
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+    private:
+        const uint8_t *source_;
+        const uint8_t *limit_;
+    };
+
+    // Length of dynamic string is given by its dimension member.
+    template <typename TSizeDeserialzer, uint32_t MaxLength = std::numeric_limits<uint32_t>::max()>
+    class DynStringDeserializer {
+    public:
+        DynStringDeserializer() : source_(nullptr), limit_(nullptr), size_(nullptr) {}
+        DynStringDeserializer(const uint8_t* source) : source_(source), limit_(nullptr), size_(nullptr) {}
+
+        std::string_view get_data() {
+            if (!_deserialized()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), std::string_view());
+            }
+            if (size_->get_data() > MaxLength) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), std::string_view());
+            }
+            if (!in_bounds(source_, limit_, size_->get_data())) {
+                ABF_RAISE(Error::BufferTooShort, BufferTooShort(), std::string_view());
+            }
+            return std::string_view(reinterpret_cast<const char*>(source_), size_->get_data());
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return source_ != nullptr && size_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            auto error = size_->_validate();
+            if (!error.ok()) {
+                return error;
+            }
+            if (size_->get_data() > MaxLength) {
+                return ValidationError{ValidationErrorKind::BoundExceeded};
+            }
+            if (!in_bounds(source_, limit_, size_->get_data())) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            if (size_ == nullptr) {
+                return source_;
+            }
+            return source_ + size_->get_data();
+        }
+
+        void init() {
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+        void set_size_deserializer(TSizeDeserialzer *size) {
+            size_ = size;
+        }
+
+    private:
+        const uint8_t *source_;
+        const uint8_t *limit_;
+        TSizeDeserialzer* size_;
+    };
+
+    // String running to end of message takes all bytes left in buffer, so its length must be known.
+    class RestStringDeserializer {
+    public:
+        RestStringDeserializer() : source_(nullptr), limit_(nullptr) {}
+        RestStringDeserializer(const uint8_t* source) : source_(source), limit_(nullptr) {}
+
+        std::string_view get_data() {
+            if (!_source_set()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), std::string_view());
+            }
+            if (!_deserialized()) {
+                ABF_RAISE(Error::Unresolved, std::runtime_error(\"Buffer length not known\"), std::string_view());
+            }
+            if (!in_bounds(source_, limit_, 0)) {
+                ABF_RAISE(Error::BufferTooShort, BufferTooShort(), std::string_view());
+            }
+            return std::string_view(reinterpret_cast<const char*>(source_), limit_ - source_);
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return source_ != nullptr && limit_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
             }
-            if (data > std::numeric_limits<DataType>::max()) {
-                throw std::runtime_error(\"maximum violated\");
+            if (!in_bounds(source_, limit_, 0)) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
             }
-            serializer_.set_data(reinterpret_cast<DataType>(data));
+            return ValidationError();
         }
 
-        uint32_t serialize(uint8_t* dest) {
-            return serializer_.serialize(dest);
+        const uint8_t* _end() {
+            return limit_ != nullptr ? limit_ : source_;
         }
 
         void init() {
-            serializer_.init();
+            source_ = nullptr;
+            limit_ = nullptr;
         }
 
     private:
-        TSerialzer serializer_;
+        const uint8_t *source_;
+        const uint8_t *limit_;
     };
 
-    ////////////////////////////////////////////////////////////////////////////////
-    // DESERIALIZER
-    ////////////////////////////////////////////////////////////////////////////////
-
-    template <typename TData, uint32_t Size>
-    class NativeDeserializer {
+    // String ends before its terminator, e.g. NUL character of C string.
+    template <typename TTerminator>
+    class TerminatedStringDeserializer {
     public:
-        NativeDeserializer() : source_(nullptr) {}
-        NativeDeserializer(uint8_t* source) : source_(source) {}
+        TerminatedStringDeserializer() : source_(nullptr), limit_(nullptr) {}
+        TerminatedStringDeserializer(const uint8_t* source) : source_(source), limit_(nullptr) {}
 
-        TData get_data() {
+        std::string_view get_data() {
             if (!_deserialized()) {
-                throw std::runtime_error(\"Source not set\");
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), std::string_view());
             }
-            TData value = 0;
-            copy(&value, source_, Size);
-            return value;
+            uint32_t length = count_until<TTerminator>(source_, limit_);
+            return std::string_view(reinterpret_cast<const char*>(source_), length);
         }
 
-        void _set_source(uint8_t *source) {
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
             source_ = source;
+            limit_ = limit;
         }
 
         bool _source_set() {
@@ -335,16 +1501,29 @@ namespace abf {
             return source_ != nullptr;
         }
 
-        uint8_t* _end() {
-            return source_ + Size;
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            uint32_t length = 0;
+            if (!find_terminator<TTerminator>(source_, limit_, length)) {
+                return ValidationError{ValidationErrorKind::BufferTooShort};
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            return source_ + (count_until<TTerminator>(source_, limit_) + 1) * TTerminator::stride;
         }
 
         void init() {
             source_ = nullptr;
+            limit_ = nullptr;
         }
 
     private:
-        uint8_t *source_;
+        const uint8_t *source_;
+        const uint8_t *limit_;
     };
 
     template <typename TDeserialzer, uint32_t Size>
@@ -356,21 +1535,24 @@ namespace abf {
             init();
         }
 
-        ArrayDeserializer(uint8_t* source) {
+        ArrayDeserializer(const uint8_t* source) {
             init();
-            _set_source(source);
+            _set_source(source, nullptr);
         }
 
         TDeserialzer& get(uint32_t i) {
             if (!deserializers_[i]._source_set()) {
                 if (i > 0) {
                     if (deserializers_[i - 1]._deserialized()) {
-                        deserializers_[i]._set_source(deserializers_[i - 1]._end());
+                        if (!in_bounds(deserializers_[i - 1]._end(), limit_, 0)) {
+                            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), deserializers_[i]);
+                        }
+                        deserializers_[i]._set_source(deserializers_[i - 1]._end(), limit_);
                     } else {
-                        throw std::runtime_error(\"Previous member not deserialized\");
+                        ABF_RAISE(Error::NotDeserialized, std::runtime_error(\"Previous member not deserialized\"), deserializers_[i]);
                     }
                 } else {
-                    throw std::runtime_error(\"First member not deserialized\");
+                    ABF_RAISE(Error::NotDeserialized, std::runtime_error(\"First member not deserialized\"), deserializers_[i]);
                 }
             }
             return deserializers_[i];
@@ -380,10 +1562,12 @@ namespace abf {
             for (auto i = 0; i < Size; i++) {
                 deserializers_[i].init();
             }
+            limit_ = nullptr;
         }
 
-        void _set_source(uint8_t *source) {
-            deserializers_[0]._set_source(source);
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            deserializers_[0]._set_source(source, limit);
+            limit_ = limit;
         }
 
         bool _source_set() {
@@ -394,12 +1578,23 @@ namespace abf {
             return deserializers_[Size - 1]._deserialized();
         }
 
-        uint8_t* _end() {
+        ValidationError _validate() {
+            for (uint32_t i = 0; i < Size; i++) {
+                auto error = validate_item(get(i), i);
+                if (!error.ok()) {
+                    return error;
+                }
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
             return deserializers_[Size - 1]._end();
         }
 
     private:
         TDeserialzer deserializers_[Size];
+        const uint8_t* limit_;
     };
 
     template <typename TDeserialzer>
@@ -411,9 +1606,9 @@ namespace abf {
             init();
         }
 
-        DynArrayDeserializer(uint8_t* source) {
+        DynArrayDeserializer(const uint8_t* source) {
             init();
-            _set_source(source);
+            _set_source(source, nullptr);
         }
 
         TDeserialzer& get(uint32_t i) {
@@ -424,15 +1619,18 @@ namespace abf {
             if (!deserializers_[i]._source_set()) {
                 if (i == 0) {
                     if (!_source_set()) {
-                        throw std::runtime_error(\"Source not set\");
+                        ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), deserializers_[i]);
                     }
-                    deserializers_[0]._set_source(source_);
+                    deserializers_[0]._set_source(source_, limit_);
                 }
                 if (i > 0) {
                     if (deserializers_[i - 1]._deserialized()) {
-                        deserializers_[i]._set_source(deserializers_[i - 1]._end());
+                        if (!in_bounds(deserializers_[i - 1]._end(), limit_, 0)) {
+                            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), deserializers_[i]);
+                        }
+                        deserializers_[i]._set_source(deserializers_[i - 1]._end(), limit_);
                     } else {
-                        throw std::runtime_error(\"Previous member not deserialized\");
+                        ABF_RAISE(Error::NotDeserialized, std::runtime_error(\"Previous member not deserialized\"), deserializers_[i]);
                     }
                 }
             }
@@ -441,10 +1639,13 @@ namespace abf {
 
         void init() {
             deserializers_ = std::vector<TDeserialzer>();
+            source_ = nullptr;
+            limit_ = nullptr;
         }
 
-        void _set_source(uint8_t *source) {
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
             source_ = source;
+            limit_ = limit;
         }
 
         bool _source_set() {
@@ -459,7 +1660,36 @@ namespace abf {
             }
         }
 
-        uint8_t* _end() {
+        // Without dimension items are walked to end of buffer, or only those accessed when its length is not known.
+        ValidationError _validate() {
+            if (!_source_set()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            uint32_t accessed = deserializers_.size();
+            for (uint32_t i = 0; limit_ != nullptr ? _end() < limit_ : i < accessed; i++) {
+                const uint8_t* begin = _end();
+                auto error = validate_item(get(i), i);
+                if (!error.ok()) {
+                    return error;
+                }
+                if (_end() == begin) {
+                    break;
+                }
+            }
+            return ValidationError();
+        }
+
+        ValidationError _validate(uint32_t count) {
+            for (uint32_t i = 0; i < count; i++) {
+                auto error = validate_item(get(i), i);
+                if (!error.ok()) {
+                    return error;
+                }
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
             if (deserializers_.size()) {
                 return deserializers_.back()._end();
             } else {
@@ -469,7 +1699,295 @@ namespace abf {
 
     private:
         std::vector<TDeserialzer> deserializers_;
-        uint8_t* source_;
+        const uint8_t* source_;
+        const uint8_t* limit_;
+    };
+
+    template <typename TDeserialzer, uint32_t MaxSize>
+    class BoundedArrayDeserializer {
+    public:
+        using ItemDeserializer = TDeserialzer;
+
+        BoundedArrayDeserializer() {
+            init();
+        }
+
+        BoundedArrayDeserializer(const uint8_t* source) {
+            init();
+            _set_source(source, nullptr);
+        }
+
+        TDeserialzer& get(uint32_t i) {
+            if (i >= MaxSize) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), discarded<TDeserialzer>());
+            }
+            while (length_ < i + 1) {
+                deserializers_[length_++].init();
+            }
+            if (!deserializers_[i]._source_set()) {
+                if (i == 0) {
+                    if (!_source_set()) {
+                        ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), deserializers_[i]);
+                    }
+                    deserializers_[0]._set_source(source_, limit_);
+                }
+                if (i > 0) {
+                    if (deserializers_[i - 1]._deserialized()) {
+                        if (!in_bounds(deserializers_[i - 1]._end(), limit_, 0)) {
+                            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), deserializers_[i]);
+                        }
+                        deserializers_[i]._set_source(deserializers_[i - 1]._end(), limit_);
+                    } else {
+                        ABF_RAISE(Error::NotDeserialized, std::runtime_error(\"Previous member not deserialized\"), deserializers_[i]);
+                    }
+                }
+            }
+            return deserializers_[i];
+        }
+
+        void init() {
+            length_ = 0;
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            if (length_) {
+                return deserializers_[length_ - 1]._deserialized();
+            } else {
+                return _source_set();
+            }
+        }
+
+        // Without dimension items are walked to end of buffer, which must not hold more than bound.
+        ValidationError _validate() {
+            if (!_source_set()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            uint32_t accessed = length_;
+            for (uint32_t i = 0; limit_ != nullptr ? _end() < limit_ : i < accessed; i++) {
+                if (i >= MaxSize) {
+                    return ValidationError{ValidationErrorKind::BoundExceeded};
+                }
+                const uint8_t* begin = _end();
+                auto error = validate_item(get(i), i);
+                if (!error.ok()) {
+                    return error;
+                }
+                if (_end() == begin) {
+                    break;
+                }
+            }
+            return ValidationError();
+        }
+
+        ValidationError _validate(uint32_t count) {
+            if (count > MaxSize) {
+                return ValidationError{ValidationErrorKind::BoundExceeded};
+            }
+            for (uint32_t i = 0; i < count; i++) {
+                auto error = validate_item(get(i), i);
+                if (!error.ok()) {
+                    return error;
+                }
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            if (length_) {
+                return deserializers_[length_ - 1]._end();
+            } else {
+                return source_;
+            }
+        }
+
+    private:
+        TDeserialzer deserializers_[MaxSize];
+        uint32_t length_;
+        const uint8_t* source_;
+        const uint8_t* limit_;
+    };
+
+    // Array running to end of message holds every whole item left in buffer, so its length must be known.
+    template <typename TDeserialzer>
+    class RestArrayDeserializer {
+    public:
+        using ItemDeserializer = TDeserialzer;
+
+        RestArrayDeserializer() {
+            init();
+        }
+
+        RestArrayDeserializer(const uint8_t* source) {
+            init();
+            _set_source(source, nullptr);
+        }
+
+        TDeserialzer& get(uint32_t i) {
+            if (i >= length()) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), discarded<TDeserialzer>());
+            }
+            return array_.get(i);
+        }
+
+        uint32_t length() {
+            if (!_source_set()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), 0);
+            }
+            if (limit_ == nullptr) {
+                ABF_RAISE(Error::Unresolved, std::runtime_error(\"Buffer length not known\"), 0);
+            }
+            if (length_ == unknown_length) {
+                uint32_t i = 0;
+                items_end_ = array_._end();
+                while (items_end_ < limit_) {
+                    array_.get(i);
+                    if (array_._end() == items_end_ || array_._end() > limit_) {
+                        break;
+                    }
+                    items_end_ = array_._end();
+                    i++;
+                }
+                // trailing bytes not making whole item are not part of array
+                length_ = i;
+            }
+            return length_;
+        }
+
+        void init() {
+            array_.init();
+            length_ = unknown_length;
+            items_end_ = nullptr;
+            limit_ = nullptr;
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            array_._set_source(source, limit);
+            length_ = unknown_length;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return array_._source_set();
+        }
+
+        bool _deserialized() {
+            return _source_set() && limit_ != nullptr;
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            auto error = array_._validate(length());
+            if (!error.ok()) {
+                return error;
+            }
+            if (items_end_ != limit_) {
+                return prefix_path(ValidationError{ValidationErrorKind::BufferTooShort}, \"[\" + std::to_string(length_) + \"]\");
+            }
+            return ValidationError();
+        }
+
+        const uint8_t* _end() {
+            return limit_ != nullptr ? limit_ : array_._end();
+        }
+
+    private:
+        static constexpr uint32_t unknown_length = std::numeric_limits<uint32_t>::max();
+
+        DynArrayDeserializer<TDeserialzer> array_;
+        uint32_t length_;
+        const uint8_t* items_end_;
+        const uint8_t* limit_;
+    };
+
+    // Array ends before first item holding sentinel, terminator follows its items.
+    template <typename TDeserialzer, typename TTerminator>
+    class TerminatedArrayDeserializer {
+    public:
+        using ItemDeserializer = TDeserialzer;
+
+        TerminatedArrayDeserializer() {
+            init();
+        }
+
+        TerminatedArrayDeserializer(const uint8_t* source) {
+            init();
+            _set_source(source, nullptr);
+        }
+
+        TDeserialzer& get(uint32_t i) {
+            if (i >= length()) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Out of range\"), discarded<TDeserialzer>());
+            }
+            return array_.get(i);
+        }
+
+        uint32_t length() {
+            if (!_source_set()) {
+                ABF_RAISE(Error::SourceNotSet, std::runtime_error(\"Source not set\"), 0);
+            }
+            if (length_ == unknown_length) {
+                length_ = count_until<TTerminator>(source_, limit_);
+            }
+            return length_;
+        }
+
+        void init() {
+            array_.init();
+            length_ = unknown_length;
+            source_ = nullptr;
+            limit_ = nullptr;
+        }
+
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            array_._set_source(source, limit);
+            length_ = unknown_length;
+            source_ = source;
+            limit_ = limit;
+        }
+
+        bool _source_set() {
+            return source_ != nullptr;
+        }
+
+        bool _deserialized() {
+            return _source_set();
+        }
+
+        ValidationError _validate() {
+            if (!_deserialized()) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            uint32_t count = 0;
+            if (!find_terminator<TTerminator>(source_, limit_, count)) {
+                return prefix_path(ValidationError{ValidationErrorKind::BufferTooShort}, \"[\" + std::to_string(count) + \"]\");
+            }
+            return array_._validate(count);
+        }
+
+        const uint8_t* _end() {
+            return source_ + (length() + 1) * TTerminator::stride;
+        }
+
+    private:
+        static constexpr uint32_t unknown_length = std::numeric_limits<uint32_t>::max();
+
+        DynArrayDeserializer<TDeserialzer> array_;
+        uint32_t length_;
+        const uint8_t* source_;
+        const uint8_t* limit_;
     };
 
     template <typename TIArrayDeserialzer, typename TSizeDeserialzer>
@@ -477,21 +1995,21 @@ namespace abf {
     public:
         using ItemDeserializer = TIArrayDeserialzer::ItemDeserializer;
 
-        ArraySizedDeserializer() {
+        ArraySizedDeserializer() : size_(nullptr) {
             init();
         }
 
-        ArraySizedDeserializer(uint8_t* source) {
+        ArraySizedDeserializer(const uint8_t* source) : size_(nullptr) {
             init();
-            _set_source(source);
+            _set_source(source, nullptr);
         }
 
         ItemDeserializer& get(uint32_t i) {
-            if (!size_->_deserialized()) {
-                throw std::runtime_error(\"Array size not deserialized yet\");
+            if (size_ == nullptr || !size_->_deserialized()) {
+                ABF_RAISE(Error::NotDeserialized, std::runtime_error(\"Array size not deserialized yet\"), discarded<ItemDeserializer>());
             }
             if (i >= size_->get_data()) {
-                throw std::runtime_error(\"Array size not deserialized yet\");
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Array size not deserialized yet\"), discarded<ItemDeserializer>());
             }
             return array_.get(i);
         }
@@ -500,8 +2018,8 @@ namespace abf {
             array_ .init();
         }
 
-        void _set_source(uint8_t *source) {
-            array_._set_source(source);
+        void _set_source(const uint8_t *source, const uint8_t *limit) {
+            array_._set_source(source, limit);
         }
 
         bool _source_set() {
@@ -512,7 +2030,24 @@ namespace abf {
             return array_._deserialized();
         }
 
-        uint8_t* _end() {
+        // all items given by dimension are walked, so end does not depend on items accessed so far
+        ValidationError _validate() {
+            if (size_ == nullptr) {
+                return ValidationError{ValidationErrorKind::Unresolved};
+            }
+            auto error = size_->_validate();
+            if (!error.ok()) {
+                return error;
+            }
+            return array_._validate(size_->get_data());
+        }
+
+        const uint8_t* _end() {
+            if (size_ != nullptr && size_->_deserialized()) {
+                for (uint32_t i = 0; i < size_->get_data(); i++) {
+                    array_.get(i);
+                }
+            }
             return array_._end();
         }
 
@@ -525,90 +2060,132 @@ namespace abf {
         TSizeDeserialzer* size_;
     };
 
-}
-";
+    ////////////////////////////////////////////////////////////////////////////////
+    // VIEW
+    ////////////////////////////////////////////////////////////////////////////////
 
-pub static BSWAP_SOURCE: &str = "
-inline uint8_t bswap8(uint8_t value) {
-    value = ((value & 0x55) << 1) | ((value & 0xAA) >> 1);
-    value = ((value & 0x33) << 2) | ((value & 0xCC) >> 2);
-    value = ((value & 0x0F) << 4) | ((value & 0xF0) >> 4);
-    return value;
-}
+    // Bytes of source left after offset, none when offset is past its end.
+    inline uint32_t remaining(uint32_t size, uint32_t offset) {
+        return offset < size ? size - offset : 0;
+    }
 
-inline uint16_t bswap16(uint16_t value) {
-    return (static_cast<uint16_t>(bswap8(static_cast<uint8_t>(value))) << 8) |
-           (bswap8(static_cast<uint8_t>(value >> 8)));
-}
+    // Dimension held outside of viewed struct is given by view of struct embedding it.
+    inline uint32_t known_dimension(std::optional<uint32_t> dimension) {
+        if (!dimension) {
+            ABF_RAISE(Error::Unresolved, std::runtime_error(\"Dimension not known\"), 0);
+        }
+        return *dimension;
+    }
 
-inline uint32_t bswap32(uint32_t value) {
-    return (static_cast<uint32_t>(bswap16(static_cast<uint16_t>(value))) << 16) |
-           (bswap16(static_cast<uint16_t>(value >> 16)));
-}
+    // Views keep only their source and its size, values are read from it on every access.
+    template <typename TData, uint32_t Size, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    inline TData read(const uint8_t* source, uint32_t size) {
+        static_assert(sizeof(TData) >= Size, \"Native type smaller than its bytes\");
+        if (size < Size) {
+            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), TData());
+        }
+        TData value{};
+        load<BigEndian>(value, source, Size);
+        return value;
+    }
 
-inline uint64_t bswap64(uint64_t value) {
-    return (static_cast<uint64_t>(bswap32(static_cast<uint32_t>(value))) << 32) |
-           (bswap32(static_cast<uint32_t>(value >> 32)));
-}
+    template <typename TData, typename TContainer, uint32_t Size, uint32_t Shift, uint32_t Bits, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    inline TData read_bit_field(const uint8_t* source, uint32_t size) {
+        return BitFieldPacking<TData, TContainer, Shift, Bits>::extract(read<TContainer, Size, BigEndian>(source, size));
+    }
 
-inline void bswap8_ptr(uint8_t* ptr) {
-    *ptr = bswap8(*ptr);
-}
+    // Fixed size string ends at first NUL character.
+    inline std::string_view read_string(const uint8_t* source, uint32_t size, uint32_t length) {
+        if (size < length) {
+            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), std::string_view());
+        }
+        auto data = reinterpret_cast<const char*>(source);
+        return std::string_view(data, strnlen(data, length));
+    }
 
-inline void bswap16_ptr(uint16_t* ptr) {
-    *ptr = bswap16(*ptr);
-}
+    inline std::string_view read_dyn_string(const uint8_t* source, uint32_t size, uint32_t length) {
+        if (size < length) {
+            ABF_RAISE(Error::BufferTooShort, BufferTooShort(), std::string_view());
+        }
+        return std::string_view(reinterpret_cast<const char*>(source), length);
+    }
 
-inline void bswap32_ptr(uint32_t* ptr) {
-    *ptr = bswap32(*ptr);
-}
+    template <typename TData, uint32_t Size, bool BigEndian = SCHEMA_BIG_ENDIAN>
+    class NativeArrayView {
+    public:
+        NativeArrayView(const uint8_t* source, uint32_t size, uint32_t count) : source_(source), size_(size), count_(count) {}
 
-inline void bswap64_ptr(uint64_t* ptr) {
-    *ptr = bswap64(*ptr);
-}
+        TData get(uint32_t i) const {
+            if (i >= count_) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Index out of range\"), TData());
+            }
+            return read<TData, Size, BigEndian>(source_ + i * Size, remaining(size_, i * Size));
+        }
 
-inline void copy(void* dest, void* source, size_t size) {
-    switch (size) {
-        case 1:
-            std::memcpy(dest, source, size);
-            bswap8_ptr(static_cast<uint8_t*>(dest));
-            break;
-        case 2:
-            std::memcpy(dest, source, size);
-            bswap16_ptr(static_cast<uint16_t*>(dest));
-            break;
-        case 3:
-            uint32_t u32_temp;
-            std::memcpy(&u32_temp, source, 4);
-            bswap32_ptr(&u32_temp);
-            std::memcpy(dest, reinterpret_cast<uint8_t*>(&u32_temp) + 1, 3);
-            break;
-        case 4:
-            std::memcpy(dest, source, size);
-            bswap32_ptr(static_cast<uint32_t*>(dest));
-            break;
-        case 8:
-            std::memcpy(dest, source, size);
-            bswap64_ptr(static_cast<uint64_t*>(dest));
-            break;
-        default:
-            throw std::runtime_error(\"Unsupported size\");
-    }
-}
-";
+        uint32_t count() const {
+            return count_;
+        }
+
+        uint32_t _size() const {
+            return count_ * Size;
+        }
+
+    private:
+        const uint8_t* source_;
+        uint32_t size_;
+        uint32_t count_;
+    };
+
+    // Item begins where previous one ends, so items before it are walked on access.
+    template <typename TView>
+    class ArrayView {
+    public:
+        ArrayView(const uint8_t* source, uint32_t size, uint32_t count) : source_(source), size_(size), count_(count) {}
+
+        // Items fill the rest of source.
+        ArrayView(const uint8_t* source, uint32_t size) : source_(source), size_(size), count_(_count(source, size)) {}
+
+        TView get(uint32_t i) const {
+            if (i >= count_) {
+                ABF_RAISE(Error::OutOfRange, std::runtime_error(\"Index out of range\"), TView(source_, 0));
+            }
+            uint32_t offset = _offset(i);
+            return TView(source_ + offset, remaining(size_, offset));
+        }
+
+        uint32_t count() const {
+            return count_;
+        }
+
+        uint32_t _size() const {
+            return _offset(count_);
+        }
+
+    private:
+        uint32_t _offset(uint32_t i) const {
+            uint32_t offset = 0;
+            for (uint32_t j = 0; j < i; j++) {
+                offset += TView(source_ + offset, remaining(size_, offset))._size();
+            }
+            return offset;
+        }
+
+        static uint32_t _count(const uint8_t* source, uint32_t size) {
+            uint32_t count = 0;
+            for (uint32_t offset = 0; offset < size; count++) {
+                uint32_t item_size = TView(source + offset, size - offset)._size();
+                if (item_size == 0) {
+                    ABF_RAISE(Error::EmptyItem, std::runtime_error(\"Empty item\"), count);
+                }
+                offset += item_size;
+            }
+            return count;
+        }
+
+        const uint8_t* source_;
+        uint32_t size_;
+        uint32_t count_;
+    };
 
-pub static NO_BSWAP_SOURCE: &str = "
-inline void copy(void* dest, void* source, size_t size) {
-    switch (size) {
-        case 1:
-        case 2:
-        case 4:
-        case 8:
-            std::memcpy(dest, source, size);
-            break;
-        case 3:
-            std::memcpy(dest, static_cast<uint8_t*>(source), size);
-            break;
-    }
 }
 ";
@@ -42,7 +42,7 @@ impl CppMemoryDetail for Memory {
     fn user_value_serializable(&self) -> bool {
         match self.array_size {
             ArraySize::No => self.memory.user_value_serializable(),
-            ArraySize::Dyn => true,
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest | ArraySize::Until(_) => true,
             ArraySize::Exact(_) => true,
         }
     }
@@ -50,35 +50,56 @@ impl CppMemoryDetail for Memory {
     fn directly_deserializable(&self) -> bool {
         match self.array_size {
             ArraySize::No => self.memory.directly_deserializable(),
-            ArraySize::Dyn => false,
-            ArraySize::Exact(_) => false,
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest | ArraySize::Until(_) => self.is_string(),
+            ArraySize::Exact(_) => self.is_string(),
         }
     }
 
     fn serializer_typename(&self) -> String {
-        match self.array_size {
-            ArraySize::No => self.memory.serializer_typename(),
-            ArraySize::Dyn => format!("abf::DynArraySerializer<{}>", self.memory.serializer_typename()),
-            ArraySize::Exact(s) => format!("abf::ArraySerializer<{}, {}>", self.memory.serializer_typename(), s),
+        let item = self.memory.endian_serializer_typename(self.big_endian);
+        let array = match &self.array_size {
+            ArraySize::No => return item,
+            ArraySize::Dyn | ArraySize::Rest => format!("abf::DynArraySerializer<{}>", item),
+            ArraySize::Exact(s) => format!("abf::ArraySerializer<{}, {}>", item, s),
+            ArraySize::Bounded(s) => format!("abf::BoundedArraySerializer<{}, {}>", item, s),
+            ArraySize::Until(t) => format!("abf::TerminatedArraySerializer<{}, {}>", item, terminator_typename(self, t)),
+        };
+        if self.is_string() {
+            format!("abf::StringSerializer<{}>", array)
+        } else {
+            array
         }
     }
 
     fn deserializer_typename(&self) -> String {
-        match self.array_size {
-            ArraySize::No => self.memory.deserializer_typename(),
-            ArraySize::Dyn => format!("abf::DynArrayDeserializer<{}>", self.memory.deserializer_typename()),
-            ArraySize::Exact(s) => format!("abf::ArrayDeserializer<{}, {}>", self.memory.deserializer_typename(), s),
+        let item = self.memory.endian_deserializer_typename(self.big_endian);
+        match &self.array_size {
+            ArraySize::No => item,
+            // length of dynamic string is known only to owning struct
+            ArraySize::Dyn | ArraySize::Bounded(_) if self.is_string() => panic!("dynamic string without dimension"),
+            ArraySize::Dyn => format!("abf::DynArrayDeserializer<{}>", item),
+            ArraySize::Bounded(s) => format!("abf::BoundedArrayDeserializer<{}, {}>", item, s),
+            ArraySize::Rest if self.is_string() => "abf::RestStringDeserializer".into(),
+            ArraySize::Rest => format!("abf::RestArrayDeserializer<{}>", item),
+            ArraySize::Until(t) if self.is_string() => format!("abf::TerminatedStringDeserializer<{}>", terminator_typename(self, t)),
+            ArraySize::Until(t) => format!("abf::TerminatedArrayDeserializer<{}, {}>", item, terminator_typename(self, t)),
+            ArraySize::Exact(s) if self.is_string() => format!("abf::StringDeserializer<{}>", s),
+            ArraySize::Exact(s) => format!("abf::ArrayDeserializer<{}, {}>", item, s),
         }
     }
 
     fn native_typename(&self) -> String {
-        self.memory.native_typename()
+        if self.is_string() {
+            "std::string_view".into()
+        } else {
+            self.memory.native_typename()
+        }
     }
 
     fn bytes(&self) -> Option<u32> {
         match self.array_size {
             ArraySize::No => return self.memory.bytes(),
-            ArraySize::Dyn => return None,
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest | ArraySize::Until(_) => return None,
             ArraySize::Exact(s) => {
                 if let Some(b) = self.memory.bytes() {
                     return Some(s * b)
@@ -92,7 +113,7 @@ impl CppMemoryDetail for Memory {
     fn default_constructible_deserializer(&self) -> bool {
         match self.array_size {
             ArraySize::No => self.memory.default_constructible_deserializer(),
-            ArraySize::Dyn => false,
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest | ArraySize::Until(_) => false,
             ArraySize::Exact(_) => false,
         }
     }
@@ -127,21 +148,11 @@ impl CppMemoryDetail for MemoryType {
     }
 
     fn serializer_typename(&self) -> String {
-        match &self {
-            MemoryType::Native(m) => m.serializer_typename(),
-            MemoryType::Struct(m) => m.borrow().serializer_typename(),
-            MemoryType::View(m) => m.serializer_typename(),
-            MemoryType::Enum(m) => m.serializer_typename(),
-        }
+        self.endian_serializer_typename(None)
     }
 
     fn deserializer_typename(&self) -> String {
-        match &self {
-            MemoryType::Native(m) => m.deserializer_typename(),
-            MemoryType::Struct(m) => m.borrow().deserializer_typename(),
-            MemoryType::View(m) => m.deserializer_typename(),
-            MemoryType::Enum(m) => m.deserializer_typename(),
-        }
+        self.endian_deserializer_typename(None)
     }
 
     fn native_typename(&self) -> String {
@@ -190,10 +201,15 @@ impl CppMemoryDetail for NativeType {
             NativeType::I16 => "i16".into(),
             NativeType::I32 => "i32".into(),
             NativeType::I64 => "i64".into(),
+            NativeType::F32 => "f32".into(),
+            NativeType::F64 => "f64".into(),
+            NativeType::Char => "string".into(),
             NativeType::Unknown => panic!("unknown type"),
             NativeType::ViewKeyReference(m) => m.native_key.name(),
             NativeType::ArrayDimensionReference(r) => r.origin.as_ref().name(),
             NativeType::StructMemberSize(m) => m.origin.name(),
+            NativeType::Checksum(c) => c.native.name(),
+            NativeType::BitField(bf) => bf.native.name(),
         }
     }
     fn user_value_serializable(&self) -> bool {
@@ -207,6 +223,7 @@ impl CppMemoryDetail for NativeType {
             NativeType::ConstU32(_) => false,
             NativeType::ConstU64(_) => false,
             NativeType::StructMemberSize(_) => false,
+            NativeType::Checksum(_) => false,
             _ => true
         }
     }
@@ -214,50 +231,10 @@ impl CppMemoryDetail for NativeType {
         true
     }
     fn serializer_typename(&self) -> String {
-        match self {
-            NativeType::Bool => "abf::NativeSerializer<bool, 1>".into(),
-            NativeType::U8 => "abf::NativeSerializer<uint8_t, 1>".into(),
-            NativeType::U16 => "abf::NativeSerializer<uint16_t, 2>".into(),
-            NativeType::U24 => "abf::NativeSerializer<uint32_t, 3>".into(),
-            NativeType::U32 => "abf::NativeSerializer<uint32_t, 4>".into(),
-            NativeType::U64 => "abf::NativeSerializer<uint64_t, 8>".into(),
-            NativeType::ConstU8(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint8_t, 1>, uint8_t, {}>", v),
-            NativeType::ConstU16(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint16_t, 2>, uint16_t, {}>", v),
-            NativeType::ConstU24(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint32_t, 3>, uint32_t, {}>", v),
-            NativeType::ConstU32(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint32_t, 4>, uint32_t, {}>", v),
-            NativeType::ConstU64(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint64_t, 8>, uint64_t, {}>", v),
-            NativeType::I8 => "abf::NativeSerializer<int8_t, 1>".into(),
-            NativeType::I16 => "abf::NativeSerializer<int16_t, 2>".into(),
-            NativeType::I32 => "abf::NativeSerializer<int32_t, 4>".into(),
-            NativeType::I64 => "abf::NativeSerializer<int64_t, 8>".into(),
-            NativeType::Unknown => panic!("unknown type"),
-            NativeType::ViewKeyReference(m) => format!("abf::ViewKeySerializer<{}, {}>", m.native_key.native_typename(), m.native_key.bytes().unwrap()),
-            NativeType::ArrayDimensionReference(r) => format!("abf::LazySerializer<{}>", r.origin.as_ref().serializer_typename()),
-            NativeType::StructMemberSize(m) => format!("abf::LazySerializer<{}>", m.native.serializer_typename()),
-        }
+        self.endian_serializer_typename(None)
     }
     fn deserializer_typename(&self) -> String {
-        match self {
-            NativeType::Bool => "abf::NativeDeserializer<bool, 1>".into(),
-            NativeType::U8 => "abf::NativeDeserializer<uint8_t, 1>".into(),
-            NativeType::U16 => "abf::NativeDeserializer<uint16_t, 2>".into(),
-            NativeType::U24 => "abf::NativeDeserializer<uint32_t, 3>".into(),
-            NativeType::U32 => "abf::NativeDeserializer<uint32_t, 4>".into(),
-            NativeType::U64 => "abf::NativeDeserializer<uint64_t, 8>".into(),
-            NativeType::ConstU8(_) => "abf::NativeDeserializer<uint8_t, 1>".into(),
-            NativeType::ConstU16(_) => "abf::NativeDeserializer<uint16_t, 2>".into(),
-            NativeType::ConstU24(_) => "abf::NativeDeserializer<uint32_t, 3>".into(),
-            NativeType::ConstU32(_) => "abf::NativeDeserializer<uint32_t, 4>".into(),
-            NativeType::ConstU64(_) => "abf::NativeDeserializer<uint64_t, 8>".into(),
-            NativeType::I8 => "abf::NativeDeserializer<int8_t, 1>".into(),
-            NativeType::I16 => "abf::NativeDeserializer<int16_t, 2>".into(),
-            NativeType::I32 => "abf::NativeDeserializer<int32_t, 4>".into(),
-            NativeType::I64 => "abf::NativeDeserializer<int64_t, 8>".into(),
-            NativeType::Unknown => panic!("unknown type"),
-            NativeType::ViewKeyReference(m) => m.native_key.deserializer_typename(),
-            NativeType::ArrayDimensionReference(r) => r.origin.deserializer_typename(),
-            NativeType::StructMemberSize(m) => m.native.deserializer_typename(),
-        }
+        self.endian_deserializer_typename(None)
     }
     fn native_typename(&self) -> String {
         match self {
@@ -276,10 +253,15 @@ impl CppMemoryDetail for NativeType {
             NativeType::I16 => "int16_t".into(),
             NativeType::I32 => "int32_t".into(),
             NativeType::I64 => "int64_t".into(),
+            NativeType::F32 => "float".into(),
+            NativeType::F64 => "double".into(),
+            NativeType::Char => "char".into(),
             NativeType::Unknown => panic!("unknown type"),
             NativeType::ViewKeyReference(m) => m.native_key.native_typename(),
             NativeType::ArrayDimensionReference(r) => r.origin.native_typename(),
             NativeType::StructMemberSize(m) => m.native.native_typename(),
+            NativeType::Checksum(c) => c.native.native_typename(),
+            NativeType::BitField(bf) => bf.native.native_typename(),
         }
     }
     fn bytes(&self) -> Option<u32> {
@@ -299,10 +281,15 @@ impl CppMemoryDetail for NativeType {
             NativeType::I16 => Some(2),
             NativeType::I32 => Some(4),
             NativeType::I64 => Some(8),
+            NativeType::F32 => Some(4),
+            NativeType::F64 => Some(8),
+            NativeType::Char => Some(1),
             NativeType::Unknown => panic!("unknown type"),
             NativeType::ViewKeyReference(m) => m.native_key.bytes(),
             NativeType::ArrayDimensionReference(r) => r.origin.bytes(),
             NativeType::StructMemberSize(m) => m.native.bytes(),
+            NativeType::Checksum(c) => c.native.bytes(),
+            NativeType::BitField(bf) => Some(bf.bytes() as u32),
         }
     }
     fn default_constructible_deserializer(&self) -> bool {
@@ -387,10 +374,10 @@ impl CppMemoryDetail for EnumMemory {
         true
     }
     fn serializer_typename(&self) -> String {
-        format!("{}Ser", self.name)
+        self.endian_serializer_typename(None)
     }
     fn deserializer_typename(&self) -> String {
-        format!("{}De", self.name)
+        self.endian_deserializer_typename(None)
     }
     fn native_typename(&self) -> String {
         self.name.clone()
@@ -415,7 +402,7 @@ impl CppMemoryDetail for StructMemberMemory {
     }
     fn serializer_typename(&self) -> String {
         let m = self.memory.borrow();
-        if let Some(size_member) = self.get_array_size_reference() {
+        if let Some(size_member) = self.get_array_size_reference().or_else(|| self.get_outer_dimension()) {
             let size_member_nt = size_member.as_ref().memory.borrow().memory.as_native().unwrap().clone();
             if size_member_nt.bytes().unwrap() != 32 {
                 return format!("abf::ArraySizedSerializer<{}, {}>", m.serializer_typename(), size_member.serializer_typename());
@@ -425,8 +412,16 @@ impl CppMemoryDetail for StructMemberMemory {
     }
     fn deserializer_typename(&self) -> String {
         let m = self.memory.borrow();
-        if let Some(size_member) = self.get_array_size_reference() {
+        if let Some(size_member) = self.get_array_size_reference().or_else(|| self.get_outer_dimension()) {
             let size_member_nt = size_member.as_ref().memory.borrow().memory.as_native().unwrap().clone();
+            if let ArraySize::Bounded(s) = m.array_size {
+                if m.is_string() {
+                    return format!("abf::DynStringDeserializer<{}, {}>", size_member.deserializer_typename(), s);
+                }
+            }
+            if m.is_string() {
+                return format!("abf::DynStringDeserializer<{}>", size_member.deserializer_typename());
+            }
             if size_member_nt.bytes().unwrap() != 32 {
                 return format!("abf::ArraySizedDeserializer<{}, {}>", m.deserializer_typename(), size_member.deserializer_typename());
             }
@@ -470,3 +465,109 @@ impl CppMemoryDetail for ViewPosibilityMemory {
         self.memory.default_constructible_deserializer()
     }
 }
+
+// Typenames of memory serialized in given endian, schema endian when none.
+trait CppEndianTypename {
+    fn endian_serializer_typename(&self, big_endian: Option<bool>) -> String;
+    fn endian_deserializer_typename(&self, big_endian: Option<bool>) -> String;
+}
+
+pub(super) fn endian_argument(big_endian: Option<bool>) -> String {
+    big_endian.map(|b| format!(", {}", b)).unwrap_or_default()
+}
+
+// Terminating item is compared whole, or by member of struct item at its offset.
+pub(super) fn terminator_typename(m: &Memory, t: &Terminator) -> String {
+    let compared_member = t.compared_member(&m.memory);
+    let compared = compared_member.as_ref().map(|(member, _)| member.memory.borrow());
+    let (memory, big_endian) = match &compared {
+        Some(c) => (&c.memory, c.big_endian),
+        None => (&m.memory, m.big_endian),
+    };
+    let typename = memory.native_typename();
+    format!("abf::Terminator<{}, {}, static_cast<{}>({}), {}, {}{}>",
+        typename,
+        memory.bytes().unwrap(),
+        typename,
+        t.sentinel,
+        m.memory.exact_size().unwrap(),
+        compared_member.as_ref().map_or(0, |(_, offset)| *offset),
+        endian_argument(big_endian))
+}
+
+impl CppEndianTypename for MemoryType {
+    fn endian_serializer_typename(&self, big_endian: Option<bool>) -> String {
+        match &self {
+            MemoryType::Native(m) => m.endian_serializer_typename(big_endian),
+            MemoryType::Struct(m) => m.borrow().serializer_typename(),
+            MemoryType::View(m) => m.serializer_typename(),
+            MemoryType::Enum(m) => m.endian_serializer_typename(big_endian),
+        }
+    }
+
+    fn endian_deserializer_typename(&self, big_endian: Option<bool>) -> String {
+        match &self {
+            MemoryType::Native(m) => m.endian_deserializer_typename(big_endian),
+            MemoryType::Struct(m) => m.borrow().deserializer_typename(),
+            MemoryType::View(m) => m.deserializer_typename(),
+            MemoryType::Enum(m) => m.endian_deserializer_typename(big_endian),
+        }
+    }
+}
+
+impl CppEndianTypename for NativeType {
+    fn endian_serializer_typename(&self, big_endian: Option<bool>) -> String {
+        let e = endian_argument(big_endian);
+        match self {
+            NativeType::ConstU8(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint8_t, 1{}>, uint8_t, {}>", e, v),
+            NativeType::ConstU16(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint16_t, 2{}>, uint16_t, {}>", e, v),
+            NativeType::ConstU24(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint32_t, 3{}>, uint32_t, {}>", e, v),
+            NativeType::ConstU32(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint32_t, 4{}>, uint32_t, {}>", e, v),
+            NativeType::ConstU64(v) => format!("abf::ConstantSerializer<abf::NativeSerializer<uint64_t, 8{}>, uint64_t, {}>", e, v),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => format!("abf::ViewKeySerializer<{}, {}{}>", m.native_key.native_typename(), m.native_key.bytes().unwrap(), e),
+            NativeType::ArrayDimensionReference(r) => format!("abf::LazySerializer<{}>", r.origin.endian_serializer_typename(big_endian)),
+            NativeType::StructMemberSize(m) => format!("abf::LazySerializer<{}>", m.native.endian_serializer_typename(big_endian)),
+            NativeType::Checksum(c) => format!("abf::LazySerializer<{}>", c.native.endian_serializer_typename(big_endian)),
+            NativeType::BitField(bf) => format!("abf::BitFieldSerializer<{}, {}, {}, {}, {}, {}{}>",
+                bf.native.native_typename(),
+                bf.container.native_typename(),
+                bf.container.size(),
+                bf.shift,
+                bf.bits,
+                bf.closes_group,
+                e),
+            _ => format!("abf::NativeSerializer<{}, {}{}>", self.native_typename(), self.bytes().unwrap(), e),
+        }
+    }
+
+    fn endian_deserializer_typename(&self, big_endian: Option<bool>) -> String {
+        let e = endian_argument(big_endian);
+        match self {
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.endian_deserializer_typename(big_endian),
+            NativeType::ArrayDimensionReference(r) => r.origin.endian_deserializer_typename(big_endian),
+            NativeType::StructMemberSize(m) => m.native.endian_deserializer_typename(big_endian),
+            NativeType::Checksum(c) => c.native.endian_deserializer_typename(big_endian),
+            NativeType::BitField(bf) => format!("abf::BitFieldDeserializer<{}, {}, {}, {}, {}, {}{}>",
+                bf.native.native_typename(),
+                bf.container.native_typename(),
+                bf.container.size(),
+                bf.shift,
+                bf.bits,
+                bf.closes_group,
+                e),
+            _ => format!("abf::NativeDeserializer<{}, {}{}>", self.native_typename(), self.bytes().unwrap(), e),
+        }
+    }
+}
+
+impl CppEndianTypename for EnumMemory {
+    fn endian_serializer_typename(&self, big_endian: Option<bool>) -> String {
+        format!("{}Ser<{}>", self.name, big_endian.map(|b| b.to_string()).unwrap_or_default())
+    }
+
+    fn endian_deserializer_typename(&self, big_endian: Option<bool>) -> String {
+        format!("{}De<{}>", self.name, big_endian.map(|b| b.to_string()).unwrap_or_default())
+    }
+}
@@ -1,23 +1,27 @@
 use super::*;
 
 pub fn generate_enum_deserializer(m: &EnumMemory, writer: &mut Writer) {
-    writer.write(&format!("class {}", m.deserializer_typename()));
+    writer.write_line("template <bool BigEndian = abf::SCHEMA_BIG_ENDIAN>");
+    writer.write(&format!("class {}De", m.name));
     writer.scope_in();
     writer.public();
+    generate_size_constants(m, writer);
     generate_ctor(m, writer);
     generate_get(m, writer);
     generate_init(m, writer);
     generate_deserialized(m, writer);
-    generate_source_set(m, writer);
+    generate_set_source(writer);
+    generate_source_set(writer);
     generate_end(writer);
+    generate_validate(m, writer);
     writer.private();
-    writer.write_line(&format!("abf::NativeDeserializer<{}, {}> native_;", m.underlaying_type.native_typename(), m.underlaying_type.bytes().unwrap()));
+    writer.write_line(&format!("abf::NativeDeserializer<{}, {}, BigEndian> native_;", m.underlaying_type.native_typename(), m.underlaying_type.bytes().unwrap()));
     writer.scope_out(true);
 }
 
 fn generate_ctor(m: &EnumMemory, writer: &mut Writer) {
-    writer.write_line(&format!("{}() : native_(nullptr) {{}}", m.deserializer_typename()));
-    writer.write_line(&format!("{}(uint8_t* source) : native_(source) {{}}", m.deserializer_typename()));
+    writer.write_line(&format!("{}De() : native_(nullptr) {{}}", m.name));
+    writer.write_line(&format!("{}De(const uint8_t* source) : native_(source) {{}}", m.name));
    
 }
 
@@ -35,20 +39,49 @@ fn generate_deserialized(m: &EnumMemory, writer: &mut Writer) {
     writer.scope_out(false);
 }
 
-fn generate_source_set(m: &EnumMemory, writer: &mut Writer) {
-    writer.write_with_offset("void _set_source(uint8_t* source)");
+fn generate_set_source(writer: &mut Writer) {
+    writer.write_with_offset("void _set_source(const uint8_t* source, const uint8_t* limit)");
     writer.scope_in();
-    writer.write_line("native_._set_source(source);");
+    writer.write_line("native_._set_source(source, limit);");
+    writer.scope_out(false);
+}
+
+fn generate_source_set(writer: &mut Writer) {
+    writer.write_with_offset("bool _source_set()");
+    writer.scope_in();
+    writer.write_line("return native_._source_set();");
     writer.scope_out(false);
 }
 
 fn generate_end(writer: &mut Writer) {
-    writer.write_with_offset("uint8_t* _end()");
+    writer.write_with_offset("const uint8_t* _end()");
     writer.scope_in();
     writer.write_line("return native_._end();");
     writer.scope_out(false);
 }
 
+fn generate_validate(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset("abf::ValidationError validate()");
+    writer.scope_in();
+    writer.write_line("return _validate();");
+    writer.scope_out(false);
+    writer.write_with_offset("abf::ValidationError _validate()");
+    writer.scope_in();
+    writer.write_line("auto error = native_._validate();");
+    writer.write_with_offset("if (!error.ok())");
+    writer.scope_in();
+    writer.write_line("return error;");
+    writer.scope_out(false);
+    writer.write_with_offset("switch (native_.get_data())");
+    writer.scope_in();
+    for c in &m.constants {
+        writer.write_line(&format!("case {}: return error;", c.value));
+    }
+    writer.scope_out(false);
+    writer.write_line("return abf::ValidationError{abf::ValidationErrorKind::UnknownEnumValue};");
+    writer.scope_out(false);
+}
+
 fn generate_get(m: &EnumMemory, writer: &mut Writer) {
     writer.write_with_offset(&format!("{} get_data()", m.name));
     writer.scope_in();
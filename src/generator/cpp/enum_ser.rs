@@ -1,21 +1,25 @@
 use super::*;
 
 pub fn generate_enum_serializer(m: &EnumMemory, writer: &mut Writer) {
-    writer.write(&format!("class {}", m.serializer_typename()));
+    writer.write_line("template <bool BigEndian = abf::SCHEMA_BIG_ENDIAN>");
+    writer.write(&format!("class {}Ser", m.name));
     writer.scope_in();
     writer.public();
+    generate_size_constants(m, writer);
     generate_ctor(m, writer);
     generate_with_method(m, writer);
     generate_size(writer);
     generate_serialize(writer);
     generate_serialize_into_vector(writer);
+    generate_serialize_with_capacity(writer);
+    generate_init(writer);
     writer.private();
-    writer.write_line(&format!("abf::NativeSerializer<{}, {}> native_;", m.underlaying_type.native_typename(), m.underlaying_type.bytes().unwrap()));
+    writer.write_line(&format!("abf::NativeSerializer<{}, {}, BigEndian> native_;", m.underlaying_type.native_typename(), m.underlaying_type.bytes().unwrap()));
     writer.scope_out(true);
 }
 
 fn generate_ctor(m: &EnumMemory, writer: &mut Writer) {
-    writer.write_line(&format!("{}() : native_() {{}}", m.serializer_typename()));
+    writer.write_line(&format!("{}Ser() : native_() {{}}", m.name));
 }
 
 fn generate_with_method(m: &EnumMemory, writer: &mut Writer) {
@@ -25,10 +29,18 @@ fn generate_with_method(m: &EnumMemory, writer: &mut Writer) {
     writer.scope_out(false);
 }
 
+fn generate_init(writer: &mut Writer) {
+    writer.write_with_offset("void init()");
+    writer.scope_in();
+    writer.write_line("native_.init();");
+    writer.scope_out(false);
+}
+
 fn generate_serialize(writer: &mut Writer) {
-    writer.write_with_offset("uint32_t serialize(uint8_t* dest)");
+    writer.write_with_offset("ABF_RESULT(uint32_t) serialize(uint8_t* dest)");
     writer.scope_in();
-    writer.write_line("return native_.serialize(dest);");
+    writer.write_line("ABF_ERROR_SCOPE;");
+    writer.write_line("ABF_RETURN(native_.serialize(dest));");
     writer.scope_out(false);
 }
 
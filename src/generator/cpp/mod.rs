@@ -2,7 +2,6 @@ use std::{fmt::format, io::Write};
 
 use super::*;
 
-mod writer;
 mod cpp_memory_detail;
 mod enum_type;
 mod enum_ser;
@@ -11,16 +10,12 @@ mod struct_ser;
 mod struct_de;
 mod view_ser;
 mod view_de;
+mod struct_view;
+mod view_view;
 mod abf_core;
 #[cfg(test)]
 mod test;
 
-pub struct Writer {
-    begin_spaces: usize,
-    buffer: String,
-    filename: String,
-}
-
 trait CppMemoryDetail {
     fn name(&self) -> String;
     fn user_value_serializable(&self) -> bool;
@@ -37,39 +32,45 @@ trait CppMemoryDetail {
 
 }
 
-pub fn generate(m: &Vec<MemoryDeclaration>, byte_swap: bool, args: &Args) {
-    let output_namespace = std::path::Path::new(&args.protofile)
-        .file_stem()
-        .expect("could not extract stem")
-        .to_str()
-        .unwrap();
+pub fn generate(m: &[MemoryDeclaration], module: &Module, big_endian: bool, big_endian_on_machine: Option<bool>, args: &Args) {
+    let output_namespace = module.name.as_str();
 
     std::fs::create_dir_all(&args.output_dir)
         .expect("could not create output directory");
 
     let abf_file = format!("{}/abf.h", args.output_dir);
     let mut f = std::fs::File::create(&abf_file).expect("could not create abf.h");
-    let abf_source_code = abf_core::SOURCE.replace("<<BSWAP_SOURCE>>", if byte_swap {
-        abf_core::BSWAP_SOURCE
-    } else {
-        abf_core::NO_BSWAP_SOURCE
-    });
+    let abf_source_code = abf_core::SOURCE.replace("<<BIG_ENDIAN>>", &big_endian.to_string())
+        .replace("<<HOST_BIG_ENDIAN>>", &big_endian_on_machine
+            .map(|b| b.to_string())
+            .unwrap_or("detect_host_big_endian()".into()))
+        .replace("<<COMPILER_VERSION>>", env!("CARGO_PKG_VERSION"))
+        .replace("<<EXCEPTIONS>>", if args.no_exceptions { "0" } else { "1" });
     let _ = f.write_all(abf_source_code.as_bytes()).expect("write abf.h failed");
     
     let mut writer = Writer::new(&format!("{}/{}.h", args.output_dir, output_namespace));
     writer.write_line("#pragma once");
     writer.write_line("#include \"abf.h\"");
+    for import in &module.imports {
+        writer.write_line(&format!("#include \"{}.h\"", import));
+    }
     writer.write_line(&format!("namespace {} {{", output_namespace));
-    for md in m {
+    for import in &module.imports {
+        writer.write_line(&format!("using namespace {};", import));
+    }
+    for md in m.iter().filter(|md| md.module == module.name) {
         match &md.memory.memory {
             MemoryType::Native(_) => panic!("Unexpected"),
             MemoryType::Struct(s) => {
                 struct_ser::generate_struct_serializer(&s.borrow(), &mut writer);
                 struct_de::generate_struct_deserializer(&s.borrow(), &mut writer);
+                struct_view::generate_struct_view(&s.borrow(), &mut writer);
+                generate_layout_asserts(&s.borrow(), &mut writer);
             },
             MemoryType::View(v) => {
                 view_ser::generate_view_serializer(v, &mut writer);
                 view_de::generate_view_deserializer(v, &mut writer);
+                view_view::generate_view_view(v, &mut writer);
             },
             MemoryType::Enum(e) => {
                 enum_type::generate_enum_type(e, &mut writer);
@@ -82,11 +83,74 @@ pub fn generate(m: &Vec<MemoryDeclaration>, byte_swap: bool, args: &Args) {
     
 }
 
+fn checksum_call(c: &ChecksumReference, data: &str, size: &str) -> String {
+    match c.algorithm {
+        ChecksumAlgorithm::Sum => format!("abf::sum<{}>({}, {})", c.native.native_typename(), data, size),
+        _ => format!("abf::{}({}, {})", c.algorithm.name(), data, size),
+    }
+}
+
 fn generate_serialize_into_vector(writer: &mut Writer) {
-    writer.write_with_offset("std::vector<uint8_t> serialize() ");
+    writer.write_with_offset("ABF_RESULT(std::vector<uint8_t>) serialize() ");
     writer.scope_in();
+    writer.write_line("ABF_ERROR_SCOPE;");
     writer.write_line("std::vector<uint8_t> out(size(), 0);");
     writer.write_line("serialize(out.data());");
-    writer.write_line("return out;");
+    writer.write_line("ABF_RETURN(out);");
+    writer.scope_out(false);
+}
+
+// Nothing is written unless whole message fits into capacity.
+fn generate_serialize_with_capacity(writer: &mut Writer) {
+    writer.write_with_offset("ABF_RESULT(uint32_t) serialize(uint8_t* dest, size_t capacity)");
+    writer.scope_in();
+    writer.write_line("return abf::serialize_into(*this, dest, capacity);");
+    writer.scope_out(false);
+    writer.write_line("#if __cplusplus >= 202002L");
+    writer.write_with_offset("ABF_RESULT(uint32_t) serialize(std::span<uint8_t> dest)");
+    writer.scope_in();
+    writer.write_line("return serialize(dest.data(), dest.size());");
     writer.scope_out(false);
+    writer.write_line("#endif");
+}
+
+// Sizes known from schema, e.g. for static buffers.
+fn generate_size_constants<T: MemoryDetails>(m: &T, writer: &mut Writer) {
+    if let Some(size) = m.exact_size() {
+        writer.write_line(&format!("static constexpr size_t EXACT_SIZE = {};", size));
+    }
+    if let Some(size) = m.max_size() {
+        writer.write_line(&format!("static constexpr size_t MAX_SIZE = {};", size));
+    }
+}
+
+// Nested types may come from separately generated header, their sizes must be
+// those this code was generated with.
+fn generate_layout_asserts(m: &StructMemory, writer: &mut Writer) {
+    for sm in &m.fields {
+        let memory = sm.memory.borrow();
+        if memory.memory.is_native() {
+            continue
+        }
+        if let Some(size) = memory.memory.exact_size() {
+            writer.write_line(&format!("static_assert({}::EXACT_SIZE == {}, \"{}.{}\");",
+                memory.memory.serializer_typename(),
+                size,
+                m.name(),
+                sm.name));
+        }
+    }
+    if m.exact_size().is_some() {
+        writer.write_line(&format!("static_assert({}::EXACT_SIZE == {}::EXACT_SIZE && {}::_size() == {}::EXACT_SIZE, \"{}\");",
+            m.serializer_typename(),
+            m.deserializer_typename(),
+            struct_view::view_typename(&m.name()),
+            m.serializer_typename(),
+            m.name()));
+    }
+}
+
+// Exception is thrown, or without exceptions error is recorded and value returned.
+fn raise(error: &str, exception: &str, value: &str) -> String {
+    format!("ABF_RAISE(abf::Error::{}, {}, {});", error, exception, value)
 }
\ No newline at end of file
@@ -4,28 +4,42 @@ pub fn generate_struct_deserializer(m: &StructMemory, writer: &mut Writer) {
     writer.write(&format!("class {}", m.deserializer_typename()));
     writer.scope_in();
     writer.public();
-    generate_ctor(m, writer);
+    generate_size_constants(m, writer);
+    generate_ctor(m, "const uint8_t* source", "source, nullptr", writer);
+    generate_ctor(m, "const uint8_t* data, size_t len", "data, data + len", writer);
+    generate_ctor(m, "", "nullptr, nullptr", writer);
+    generate_copy_ctor(m, writer);
     for i in 0..m.fields.len() {
         generate_deserialze(m, i, writer);
     }
+    for sm in &m.fields {
+        generate_has_method(sm, writer);
+    }
     let groups = m.get_groups();
     if groups.is_empty() {
         generate_empty_struct_methods(m, writer);
     } else {
         generate_init(m, writer);
-        generate_deserialize_methods(m, 0, groups[0].0, groups[0].1, writer);
+        generate_set_source(m, groups[0].1, writer);
         generate_deserialized(m, writer);
         generate_source_set(m, writer);
         generate_end(m, writer);
+        generate_verify(m, writer);
     }
+    generate_validate(m, writer);
+    generate_member_accessors(m, writer);
     writer.private();
-    for i in 1..groups.len() {
-        generate_deserialize_methods(m, i, groups[i].0, groups[i].1, writer);
+    for i in 0..m.fields.len() {
+        generate_resolve(m, i, writer);
+    }
+    for i in 0..m.fields.len() {
+        generate_optional_end(m, i, writer);
     }
     for i in 0..m.fields.len() {
         generate_member_deserialzier(m, i, writer);
     }
-    writer.write_line("uint8_t* source_;");
+    writer.write_line("const uint8_t* source_;");
+    writer.write_line("const uint8_t* limit_;");
     writer.scope_out(true);
 }
 
@@ -33,14 +47,16 @@ fn generate_init(m: &StructMemory, writer: &mut Writer) {
     writer.write_with_offset("void init()");
     writer.scope_in();
     writer.write_line("source_ = nullptr;");
+    writer.write_line("limit_ = nullptr;");
     for f in &m.fields {
         writer.write_line(&format!("{}_.init();", f.name));
     }
     writer.scope_out(false);
 }
 
-fn generate_ctor(m: &StructMemory, writer: &mut Writer) {
-    writer.write_with_offset(&format!("{}(uint8_t* source)", m.deserializer_typename()));
+// Deserializer given buffer length checks that bytes it reads are inside the buffer.
+fn generate_ctor(m: &StructMemory, params: &str, source: &str, writer: &mut Writer) {
+    writer.write_with_offset(&format!("{}({})", m.deserializer_typename(), params));
     let init = m.fields
         .iter()
         //.filter(|f| f.default_constructible_deserializer())
@@ -55,47 +71,319 @@ fn generate_ctor(m: &StructMemory, writer: &mut Writer) {
     let _ = m.fields.iter()
             .filter(|f| f.default_constructible_deserializer())
             .inspect(|f| writer.write_line(&format!("{}_.init();", f.name)));
-    writer.write_line("_set_source(source);");
+    writer.write_line(&format!("_set_source({});", source));
     for f in &m.fields {
         if let Some(asr) = f.get_array_size_reference() {
             writer.write_line(&format!("{}_.set_size_deserializer(&{}_);", f.name, asr.name));
         }
     }
+    for r in nested_dimensions(m) {
+        writer.write_line(&format!("{}.set_size_deserializer(&{});", nested_deserializer(&r.target), nested_deserializer(&r.holder)));
+    }
+    writer.scope_out(false);
+}
+
+fn nested_dimensions(m: &StructMemory) -> Vec<NestedReference> {
+    m.get_nested_references()
+        .into_iter()
+        .filter(|r| r.holder.last().unwrap().memory.borrow().memory.as_native().is_some_and(|n| n.is_array_dimension_reference()))
+        .collect()
+}
+
+// Deserializer of member given by path through nested struct members.
+fn nested_deserializer(path: &[Rc<StructMemberMemory>]) -> String {
+    let mut deserializer = format!("{}_", path[0].name);
+    for sm in &path[1..] {
+        deserializer.push_str(&format!("._{}_deserializer()", sm.name));
+    }
+    deserializer
+}
+
+// Struct embedding this one connects members referring across it.
+fn generate_member_accessors(m: &StructMemory, writer: &mut Writer) {
+    for i in 0..m.fields.len() {
+        if m.is_wired_from_outside(i) {
+            writer.write_with_offset(&format!("{}& _{}_deserializer()", m.fields[i].deserializer_typename(), m.fields[i].name));
+            writer.scope_in();
+            writer.write_line(&format!("return {}_;", m.fields[i].name));
+            writer.scope_out(false);
+        }
+    }
+}
+
+// Array sized members point to their size member, so copy (e.g. an array item) is wired to its own members.
+fn generate_copy_ctor(m: &StructMemory, writer: &mut Writer) {
+    if m.fields.iter().all(|f| f.get_array_size_reference().is_none()) && nested_dimensions(m).is_empty() {
+        return
+    }
+    let typename = m.deserializer_typename();
+    writer.write_with_offset(&format!("{}(const {}& other) : {}()", typename, typename, typename));
+    writer.scope_in();
+    writer.write_line("_set_source(other.source_, other.limit_);");
     writer.scope_out(false);
 }
 
 fn generate_deserialze(m: &StructMemory, i: usize, writer: &mut Writer) {
     if m.fields[i].directly_deserializable() {
-        writer.write_with_offset(&format!("{} {}()", 
+        writer.write_with_offset(&format!("ABF_RESULT({}) {}()", 
             m.fields[i].native_typename(), 
             m.fields[i].name));
         writer.scope_in();
-        generate_if_not_prev_deserialized_throw(m, i, writer);
-        writer.write_line(&format!("return {}_.get_data();", m.fields[i].name));
+        writer.write_line("ABF_ERROR_SCOPE;");
+        generate_if_not_resolved_throw(m, i, writer);
+        generate_if_absent_throw(m, i, writer);
+        writer.write_line(&format!("ABF_RETURN({}_.get_data());", m.fields[i].name));
         writer.scope_out(false);
     } else {
-        writer.write_with_offset(&format!("{}& {}()", 
+        writer.write_with_offset(&format!("ABF_RESULT({}&) {}()", 
             m.fields[i].deserializer_typename(), 
             m.fields[i].name));
             writer.scope_in();
-            generate_if_not_prev_deserialized_throw(m, i, writer);
-            writer.write_line(&format!("return {}_;", m.fields[i].name));
+            writer.write_line("ABF_ERROR_SCOPE;");
+            generate_if_not_resolved_throw(m, i, writer);
+            generate_if_absent_throw(m, i, writer);
+            writer.write_line(&format!("ABF_RETURN({}_);", m.fields[i].name));
             writer.scope_out(false);
     }
 }
 
-fn generate_if_not_prev_deserialized_throw(m: &StructMemory, i: usize, writer: &mut Writer) {
+// Nested deserializer is returned with error, so calls on it can still be chained.
+fn error_value(m: &StructMemory, i: usize, error: &str) -> String {
+    if m.fields[i].directly_deserializable() {
+        format!("abf::Error::{}", error)
+    } else {
+        format!("{{{}_, abf::Error::{}}}", m.fields[i].name, error)
+    }
+}
+
+fn generate_if_not_resolved_throw(m: &StructMemory, i: usize, writer: &mut Writer) {
     if i > 0 {
-        writer.write_with_offset(&format!("if (!{}_._deserialized())", m.fields[i - 1].as_ref().name));
+        writer.write_with_offset(&format!("if (!_{}_resolve())", m.fields[i].name));
+        writer.scope_in();
+        writer.write_line(&raise("Unresolved",
+            &format!("std::runtime_error(\"{}\")", m.fields[i - 1].as_ref().name),
+            &error_value(m, i, "Unresolved")));
+        writer.scope_out(false);
+    }
+}
+
+// Source of member is found by resolving members before it, once set it is kept.
+// Keyed view gets its type from key, so members after it can be resolved too.
+fn generate_resolve(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let name = &m.fields[i].name;
+    writer.write_with_offset(&format!("bool _{}_resolve()", name));
+    writer.scope_in();
+    if i == 0 {
+        writer.write_line(&format!("return {}_._source_set();", name));
+        writer.scope_out(false);
+        return
+    }
+    writer.write_with_offset(&format!("if (!{}_._source_set())", name));
+    writer.scope_in();
+    writer.write_with_offset(&format!("if (!_{}_resolve() || !{})",
+        m.fields[i - 1].name,
+        member_deserialized(m, i - 1)));
+    writer.scope_in();
+    writer.write_line("return false;");
+    writer.scope_out(false);
+    writer.write_line(&format!("{}_._set_source({}, limit_);", name, member_end(m, i - 1)));
+    writer.scope_out(false);
+    if let Some(key) = m.get_view_key_reference_member_index(i).filter(|key| *key < i) {
+        let index_typename = m.fields[i].memory.borrow().memory.as_view().unwrap().get_index_typename().native_typename();
+        writer.write_line(&format!("{}_._set_type_id(static_cast<{}>(abf::value_of({}())));",
+            name,
+            index_typename,
+            m.fields[key].name));
+    }
+    // key held in nested struct is read by now, since it comes before
+    for r in m.get_nested_references().iter().filter(|r| Rc::ptr_eq(&r.target[0], &m.fields[i]) && r.holder[0].index < i) {
+        if let Some(vkr) = r.holder.last().unwrap().memory.borrow().memory.as_native().and_then(|n| n.as_view_key_reference()) {
+            let holder = match r.holder.len() {
+                1 => format!("abf::value_of({}())", r.holder[0].name),
+                _ => format!("{}.get_data()", nested_deserializer(&r.holder)),
+            };
+            writer.write_line(&format!("{}._set_type_id(static_cast<{}>({}));",
+                nested_deserializer(&r.target),
+                vkr.view.memory.borrow().memory.as_view().unwrap().get_index_typename().native_typename(),
+                holder));
+        }
+    }
+    writer.write_line("return true;");
+    writer.scope_out(false);
+}
+
+fn generate_if_absent_throw(m: &StructMemory, i: usize, writer: &mut Writer) {
+    if m.fields[i].condition.is_some() {
+        writer.write_with_offset(&format!("if (!has_{}())", m.fields[i].variable()));
+        writer.scope_in();
+        writer.write_line(&raise("Absent",
+            &format!("std::runtime_error(\"{}\")", m.fields[i].name),
+            &error_value(m, i, "Absent")));
+        writer.scope_out(false);
+    }
+}
+
+fn generate_has_method(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("bool has_{}()", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("return {};", condition.test(&format!("abf::value_of({}())", condition.flag.name))));
+        writer.scope_out(false);
+    }
+}
+
+// Bytes of absent optional member are skipped, it ends where it begins.
+fn generate_optional_end(m: &StructMemory, i: usize, writer: &mut Writer) {
+    if m.fields[i].condition.is_some() {
+        writer.write_with_offset(&format!("const uint8_t* _{}_end()", m.fields[i].name));
+        writer.scope_in();
+        writer.write_line(&format!("_{}_resolve();", m.fields[i].name));
+        writer.write_line(&format!("return has_{}() ? {}_._end() : {};",
+            m.fields[i].variable(),
+            m.fields[i].name,
+            member_end(m, i - 1)));
+        writer.scope_out(false);
+    }
+}
+
+fn member_end(m: &StructMemory, i: usize) -> String {
+    match &m.fields[i].condition {
+        Some(_) => format!("_{}_end()", m.fields[i].name),
+        None => format!("{}_._end()", m.fields[i].name),
+    }
+}
+
+fn member_deserialized(m: &StructMemory, i: usize) -> String {
+    match &m.fields[i].condition {
+        Some(_) => format!("(!has_{}() || {}_._deserialized())", m.fields[i].variable(), m.fields[i].name),
+        None => format!("{}_._deserialized()", m.fields[i].name),
+    }
+}
+
+// Members up to the last one needed are resolved in order, then covered bytes are checksummed.
+fn generate_verify(m: &StructMemory, writer: &mut Writer) {
+    let checksums = m.get_checksums();
+    if checksums.is_empty() {
+        return
+    }
+    writer.write_with_offset("ABF_RESULT(bool) verify()");
+    writer.scope_in();
+    writer.write_line("ABF_ERROR_SCOPE;");
+    let resolved = checksums.iter().map(|c| c.last.index.max(c.origin.index)).max().unwrap();
+    for i in 1..(resolved + 1) {
+        match &m.fields[i].condition {
+            Some(_) => writer.write_line(&format!("if (has_{}()) {}();", m.fields[i].variable(), m.fields[i].name)),
+            None => writer.write_line(&format!("{}();", m.fields[i].name)),
+        }
+    }
+    for c in &checksums {
+        let begin = if c.first.index == 0 {
+            "source_".to_string()
+        } else {
+            member_end(m, c.first.index - 1)
+        };
+        writer.write_line(&format!("const uint8_t* {}_begin = {};", c.origin.name, begin));
+        writer.write_with_offset(&format!("if (!abf::in_bounds({}_begin, limit_, {} - {}_begin))",
+            c.origin.name,
+            member_end(m, c.last.index),
+            c.origin.name));
+        writer.scope_in();
+        writer.write_line(&raise("BufferTooShort", "abf::BufferTooShort()", "false"));
+        writer.scope_out(false);
+        writer.write_with_offset(&format!("if (abf::value_of({}()) != {})",
+            c.origin.name,
+            checksum_call(c,
+                &format!("{}_begin", c.origin.name),
+                &format!("{} - {}_begin", member_end(m, c.last.index), c.origin.name))));
+        writer.scope_in();
+        writer.write_line("ABF_RETURN(false);");
+        writer.scope_out(false);
+    }
+    writer.write_line("ABF_RETURN(true);");
+    writer.scope_out(false);
+}
+
+// Members are walked in order, first one failing names the error.
+fn generate_validate(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("abf::ValidationError validate()");
+    writer.scope_in();
+    writer.write_line("return _validate();");
+    writer.scope_out(false);
+    writer.write_with_offset("abf::ValidationError _validate()");
+    writer.scope_in();
+    if !m.fields.is_empty() {
+        writer.write_line("abf::ValidationError error;");
+    }
+    for i in 0..m.fields.len() {
+        generate_validate_member(m, i, writer);
+        let sizes = m.fields.iter().filter_map(|f| f.memory.borrow().memory.as_native().and_then(|n| n.as_struct_member_size().cloned()));
+        // size of member outside of this struct is validated by struct embedding it
+        for sm in sizes.filter(|sm| m.fields.iter().any(|f| Rc::ptr_eq(f, &sm.member))) {
+            if sm.origin.index.max(sm.member.index) == i {
+                generate_validate_member_size(m, &sm, writer);
+            }
+        }
+    }
+    writer.write_line("return abf::ValidationError();");
+    writer.scope_out(false);
+}
+
+fn generate_validate_member(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    generate_return_error_if(&format!("!_{}_resolve()", sm.name), "Unresolved", &sm.name, writer);
+    if sm.condition.is_some() {
+        writer.write_with_offset(&format!("if (has_{}())", sm.variable()));
         writer.scope_in();
-        writer.write_line(&format!("throw std::runtime_error(\"{}\");", m.fields[i - 1].as_ref().name));
+    }
+    if m.get_view_key_reference_member_index(i).filter(|key| *key < i).is_some() {
+        generate_return_error_if(&format!("!{}_._deserialized()", sm.name), "UnknownViewKey", &sm.name, writer);
+    }
+    writer.write_line(&format!("error = abf::prefix_path({}_._validate(), \"{}\");", sm.name, sm.name));
+    writer.write_with_offset("if (!error.ok())");
+    writer.scope_in();
+    writer.write_line("return error;");
+    writer.scope_out(false);
+    if let Some(value) = constant_value(&sm.memory.borrow().memory) {
+        generate_return_error_if(&format!("abf::value_of({}()) != {}", sm.name, value), "ConstantMismatch", &sm.name, writer);
+    }
+    if sm.condition.is_some() {
         writer.scope_out(false);
-        writer.write_line(&format!("{}_._set_source({}_._end());",
-            m.fields[i].as_ref().name,
-            m.fields[i - 1].as_ref().name));
     }
 }
 
+fn constant_value(memory: &MemoryType) -> Option<String> {
+    match memory.as_native()? {
+        NativeType::ConstU8(v) => Some(v.to_string()),
+        NativeType::ConstU16(v) => Some(v.to_string()),
+        NativeType::ConstU24(v) => Some(v.to_string()),
+        NativeType::ConstU32(v) => Some(v.to_string()),
+        NativeType::ConstU64(v) => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+// Size member holds byte count of measured member, both are validated by now.
+fn generate_validate_member_size(m: &StructMemory, smr: &StructMemberSizeReference, writer: &mut Writer) {
+    let t = smr.member.index;
+    let begin = if t == 0 {
+        "source_".to_string()
+    } else {
+        member_end(m, t - 1)
+    };
+    generate_return_error_if(
+        &format!("abf::value_of({}()) != static_cast<size_t>({} - {})", smr.origin.name, member_end(m, t), begin),
+        "SizeMismatch",
+        &smr.origin.name,
+        writer);
+}
+
+fn generate_return_error_if(condition: &str, kind: &str, path: &str, writer: &mut Writer) {
+    writer.write_with_offset(&format!("if ({})", condition));
+    writer.scope_in();
+    writer.write_line(&format!("return abf::ValidationError{{abf::ValidationErrorKind::{}, \"{}\"}};", kind, path));
+    writer.scope_out(false);
+}
+
 fn generate_member_deserialzier(m: &StructMemory, i: usize, writer: &mut Writer) {
     let sm = m.fields[i].as_ref();
     writer.write_line(&format!("{} {}_;", 
@@ -112,9 +400,10 @@ fn generate_empty_struct_methods(
     writer.write_line("return source_ != nullptr;");
     writer.scope_out(false);
 
-    writer.write_with_offset("void _set_source(uint8_t *source) ");
+    writer.write_with_offset("void _set_source(const uint8_t *source, const uint8_t *limit) ");
     writer.scope_in();
     writer.write_line("source_ = source;");
+    writer.write_line("limit_ = limit;");
     writer.scope_out(false);
 
     writer.write_with_offset("bool _source_set() ");
@@ -122,7 +411,7 @@ fn generate_empty_struct_methods(
     writer.write_line("return source_ != nullptr;");
     writer.scope_out(false);
 
-    writer.write_with_offset("uint8_t* _end() ");
+    writer.write_with_offset("const uint8_t* _end() ");
     writer.scope_in();
     writer.write_line("return source_;");
     writer.scope_out(false);
@@ -130,36 +419,25 @@ fn generate_empty_struct_methods(
     writer.write_with_offset("void init() ");
     writer.scope_in();
     writer.write_line("source_ = nullptr;");
+    writer.write_line("limit_ = nullptr;");
     writer.scope_out(false);
 }
 
-fn generate_deserialize_methods(
+// Members of first group follow each other at known offsets, sources of the rest are resolved on demand.
+fn generate_set_source(
     m: &StructMemory, 
-    group_id: usize, 
-    i0: usize, 
     i1: usize, 
     writer: &mut Writer
 ) {
-    if group_id == 0 {
-        writer.write_with_offset(&format!("void _set_source(uint8_t* source)"));
-        writer.scope_in();
-        writer.write_line("source_ = source;");
-        writer.write_line(&format!("{}_._set_source(source_);", m.fields[0].name));
-        for i in 1..(i1 + 1) {
-            writer.write_line(&format!("{}_._set_source({}_._end());", m.fields[i].name, m.fields[i - 1].name));
-        }
-        writer.scope_out(false);
-    } else {
-        writer.write_with_offset(&format!("bool deserialize_group{}()", group_id));
-        writer.scope_in();
-        writer.write_line(&format!("if ({}_._deserialized()) return true;", m.fields[i0].name));
-        writer.write_line(&format!("if (!{}_._deserialized()) return false;", m.fields[i0 - 1].name));
-        for i in i0..(i1 + 1) {
-            writer.write_line(&format!("{}_._set_source({}_._end());", m.fields[i].name, m.fields[i - 1].name));
-        }
-        writer.write_line("return true;");
-        writer.scope_out(false);
+    writer.write_with_offset("void _set_source(const uint8_t* source, const uint8_t* limit)");
+    writer.scope_in();
+    writer.write_line("source_ = source;");
+    writer.write_line("limit_ = limit;");
+    writer.write_line(&format!("{}_._set_source(source_, limit_);", m.fields[0].name));
+    for i in 1..(i1 + 1) {
+        writer.write_line(&format!("{}_._set_source({}_._end(), limit_);", m.fields[i].name, m.fields[i - 1].name));
     }
+    writer.scope_out(false);
 }
 
 fn generate_deserialized(
@@ -171,7 +449,9 @@ fn generate_deserialized(
     if m.fields.is_empty() {
         writer.write_line("return source_ != nullptr;");
     } else {
-        writer.write_line(&format!("return {}_._deserialized();", m.fields.last().unwrap().name));
+        writer.write_line(&format!("return _{}_resolve() && {};",
+            m.fields.last().unwrap().name,
+            member_deserialized(m, m.fields.len() - 1)));
     }
     writer.scope_out(false);
 }
@@ -190,8 +470,9 @@ fn generate_end(
     m: &StructMemory, 
     writer: &mut Writer
 ) {
-    writer.write_with_offset(&format!("uint8_t* _end()"));
+    writer.write_with_offset("const uint8_t* _end()");
     writer.scope_in();
-    writer.write_line(&format!("return {}_._end();", m.fields.first().unwrap().name));
+    writer.write_line(&format!("_{}_resolve();", m.fields.last().unwrap().name));
+    writer.write_line(&format!("return {};", member_end(m, m.fields.len() - 1)));
     writer.scope_out(false);
 }
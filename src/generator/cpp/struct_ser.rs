@@ -4,16 +4,22 @@ pub fn generate_struct_serializer(m: &StructMemory, writer: &mut Writer) {
     writer.write(&format!("class {}", m.serializer_typename()));
     writer.scope_in();
     writer.public();
+    generate_size_constants(m, writer);
     generate_ctor(m, writer);
     for i in 0..m.fields.len() {
         if m.fields[i].user_value_serializable() {
             generate_with_method(m, i, writer);
         }
     }
+    for sm in &m.fields {
+        generate_has_method(sm, writer);
+    }
     generate_size(m, writer);
     generate_serialize(m, writer);
     generate_serialize_into_vector(writer);
+    generate_serialize_with_capacity(writer);
     generate_init(m, writer);
+    generate_member_accessors(m, writer);
     writer.private();
     for i in 0..m.fields.len() {
         generate_member_serialzier(m, i, writer);
@@ -41,9 +47,39 @@ fn generate_ctor(m: &StructMemory, writer: &mut Writer) {
             writer.write_line(&format!("{}_.set_typeid_setter(&{}_);", vkr.view.name, f.name));
         }
     }
+    for r in m.get_nested_references() {
+        match r.holder.last().unwrap().memory.borrow().memory.as_native() {
+            Some(NativeType::ArrayDimensionReference(_)) =>
+                writer.write_line(&format!("{}.set_size_serializer(&{});", nested_serializer(&r.target), nested_serializer(&r.holder))),
+            Some(NativeType::ViewKeyReference(_)) =>
+                writer.write_line(&format!("{}.set_typeid_setter(&{});", nested_serializer(&r.target), nested_serializer(&r.holder))),
+            _ => {},
+        }
+    }
     writer.scope_out(false)
 }
 
+// Serializer of member given by path through nested struct members.
+fn nested_serializer(path: &[Rc<StructMemberMemory>]) -> String {
+    let mut serializer = format!("{}_", path[0].name);
+    for sm in &path[1..] {
+        serializer.push_str(&format!("._{}_serializer()", sm.name));
+    }
+    serializer
+}
+
+// Struct embedding this one connects members referring across it.
+fn generate_member_accessors(m: &StructMemory, writer: &mut Writer) {
+    for i in 0..m.fields.len() {
+        if m.is_wired_from_outside(i) {
+            writer.write_with_offset(&format!("{}& _{}_serializer()", m.fields[i].serializer_typename(), m.fields[i].name));
+            writer.scope_in();
+            writer.write_line(&format!("return {}_;", m.fields[i].name));
+            writer.scope_out(false);
+        }
+    }
+}
+
 fn generate_member_serialzier(m: &StructMemory, i: usize, writer: &mut Writer) {
     writer.write_line(&format!("{} {}_;", m.fields[i].as_ref().serializer_typename(), m.fields[i].name));
 }
@@ -69,31 +105,71 @@ fn generate_with_method(m: &StructMemory, i: usize, writer: &mut Writer) {
     }
 }
 
+// Optional member is written only when its flag, set by user, says so.
+fn generate_has_method(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("bool has_{}()", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("return {};", condition.test(&format!("{}_.get_data()", condition.flag.name))));
+        writer.scope_out(false);
+    }
+}
+
 fn generate_size(m: &StructMemory, writer: &mut Writer) {
     writer.write_with_offset("uint32_t size()");
     writer.scope_in();
     writer.write_line("uint32_t size = 0;");
     for sm in &m.fields {
-        writer.write_line(&format!("size += {}_.size();", sm.as_ref().variable()));
+        writer.write_line(&format!("{}size += {}_.size();", if_present(sm), sm.as_ref().variable()));
     }
     writer.write_line("return size;");
     writer.scope_out(false);
 }
 
 fn generate_serialize(m: &StructMemory, writer: &mut Writer) {
-    writer.write_with_offset("uint32_t serialize(uint8_t *dest)");
+    writer.write_with_offset("ABF_RESULT(uint32_t) serialize(uint8_t *dest)");
     writer.scope_in();
+    writer.write_line("ABF_ERROR_SCOPE;");
     writer.write_line("uint32_t offset = 0;");
+    let checksums = m.get_checksums();
+    let nested_sizes: Vec<NestedReference> = m.get_nested_references()
+        .into_iter()
+        .filter(|r| r.holder.last().unwrap().memory.borrow().memory.as_native().is_some_and(|n| n.as_struct_member_size().is_some()))
+        .collect();
     for sm in &m.fields {
         if let Some(smr) = sm.get_struct_member_size_reference() {
             writer.write_line(&format!("{}_.set_data({}_.size());", smr.origin.name, smr.member.name));
         }
-        writer.write_line(&format!("offset += {}_.serialize(dest + offset);", sm.as_ref().variable()));
+        for r in nested_sizes.iter().filter(|r| Rc::ptr_eq(&r.target[0], sm)) {
+            writer.write_line(&format!("{}.set_data({}.size());", nested_serializer(&r.holder), nested_serializer(&r.target)));
+        }
+        for c in checksums.iter().filter(|c| c.first.index == sm.index) {
+            writer.write_line(&format!("uint32_t {}_begin = offset;", c.origin.name));
+        }
+        writer.write_line(&format!("{}offset += abf::value_of({}_.serialize(dest + offset));", if_present(sm), sm.as_ref().variable()));
+        for c in checksums.iter().filter(|c| c.last.index == sm.index) {
+            writer.write_line(&format!("uint32_t {}_end = offset;", c.origin.name));
+        }
+    }
+    // lazy members are written by now, so covered bytes are final
+    for c in &checksums {
+        writer.write_line(&format!("{}_.set_data({});",
+            c.origin.name,
+            checksum_call(c,
+                &format!("dest + {}_begin", c.origin.name),
+                &format!("{}_end - {}_begin", c.origin.name, c.origin.name))));
     }
-    writer.write_line("return offset;");
+    writer.write_line("ABF_RETURN(offset);");
     writer.scope_out(false);
 }
 
+fn if_present(sm: &StructMemberMemory) -> String {
+    match &sm.condition {
+        Some(_) => format!("if (has_{}()) ", sm.variable()),
+        None => String::new(),
+    }
+}
+
 fn generate_init(m: &StructMemory, writer: &mut Writer) {
     writer.write_with_offset("void init()");
     writer.scope_in();
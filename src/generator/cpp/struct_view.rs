@@ -0,0 +1,348 @@
+use super::*;
+use super::cpp_memory_detail::{endian_argument, terminator_typename};
+
+// Offsets of members within a fixed size group are constant relative to the
+// group start, only the start of a group depends on members before it.
+pub fn generate_struct_view(m: &StructMemory, writer: &mut Writer) {
+    writer.write(&format!("class {}", view_typename(&m.name())));
+    writer.scope_in();
+    writer.public();
+    let slots = view_slots(m);
+    let params: String = slots
+        .iter()
+        .map(|(path, key)| format!(", std::optional<{}> {} = std::nullopt", slot_typename(path, *key), slot_name(path, *key)))
+        .collect();
+    let init: String = slots
+        .iter()
+        .map(|(path, key)| format!(", {}_({})", slot_name(path, *key), slot_name(path, *key)))
+        .collect();
+    writer.write_line(&format!("{}(const uint8_t* source, uint32_t size{}) : source_(source), size_(size){} {{}}",
+        view_typename(&m.name()),
+        params,
+        init));
+    for i in 0..m.fields.len() {
+        generate_get_method(m, i, writer);
+    }
+    for sm in &m.fields {
+        generate_has_method(sm, writer);
+    }
+    generate_size(m, writer);
+    generate_verify(m, writer);
+    writer.private();
+    for (i0, i1) in m.get_groups() {
+        for i in i0..(i1 + 1) {
+            generate_member_offset(m, i0, i, writer);
+            generate_member_size(m, i, writer);
+        }
+    }
+    writer.write_line("const uint8_t* source_;");
+    writer.write_line("uint32_t size_;");
+    for (path, key) in &slots {
+        writer.write_line(&format!("std::optional<{}> {}_;", slot_typename(path, *key), slot_name(path, *key)));
+    }
+    writer.scope_out(true);
+}
+
+// Dimension or view key of member, given by path from viewed struct.
+enum ReferenceValue {
+    // read from member holding it, which is viewed too
+    Holder(String),
+    // given by view of struct embedding this one, named by its slot
+    Slot(String),
+}
+
+fn reference_value(m: &StructMemory, path: &[Rc<StructMemberMemory>], key: bool) -> Option<ReferenceValue> {
+    let target = path.last().unwrap();
+    let holder = m.member_paths().into_iter().find(|p| {
+        let holder = p.last().unwrap();
+        let kind = holder.memory.borrow().memory.as_native().is_some_and(|n| match key {
+            true => n.is_view_key_reference(),
+            false => n.is_array_dimension_reference(),
+        });
+        kind && holder.get_referenced_member().is_some_and(|t| Rc::ptr_eq(&t, target))
+    });
+    if let Some(holder) = holder {
+        return Some(ReferenceValue::Holder(holder
+            .iter()
+            .map(|sm| format!("{}()", sm.name))
+            .collect::<Vec<String>>()
+            .join(".")));
+    }
+    let outer = match key {
+        true => target.has_outer_key(),
+        false => target.get_outer_dimension().is_some(),
+    };
+    outer.then(|| ReferenceValue::Slot(slot_name(path, key)))
+}
+
+// Members whose dimension or key is held outside of viewed struct, with true for keys.
+fn view_slots(m: &StructMemory) -> Vec<(Vec<Rc<StructMemberMemory>>, bool)> {
+    let mut slots = Vec::new();
+    for path in m.member_paths() {
+        for key in [false, true] {
+            if let Some(ReferenceValue::Slot(_)) = reference_value(m, &path, key) {
+                slots.push((path.clone(), key));
+            }
+        }
+    }
+    slots
+}
+
+fn slot_name(path: &[Rc<StructMemberMemory>], key: bool) -> String {
+    let names = path.iter().map(|sm| sm.name.clone()).collect::<Vec<String>>().join("_");
+    format!("{}_{}", names, if key { "type_id" } else { "dimension" })
+}
+
+fn slot_typename(path: &[Rc<StructMemberMemory>], key: bool) -> String {
+    match key {
+        true => path.last().unwrap().memory.borrow().memory.as_view().unwrap().get_index_typename().native_typename(),
+        false => "uint32_t".into(),
+    }
+}
+
+// Slots of nested struct view are filled by members viewed here or by slots of this view.
+fn slot_arguments(m: &StructMemory, i: usize, nested: &StructMemory) -> String {
+    view_slots(nested)
+        .iter()
+        .map(|(path, key)| {
+            let path = [vec![m.fields[i].clone()], path.clone()].concat();
+            match reference_value(m, &path, *key) {
+                Some(ReferenceValue::Holder(value)) => format!(", static_cast<{}>({})", slot_typename(&path, *key), value),
+                Some(ReferenceValue::Slot(slot)) => format!(", {}_", slot),
+                None => panic!("slot of nested view not filled"),
+            }
+        })
+        .collect()
+}
+
+pub fn view_typename(name: &str) -> String {
+    format!("{}View", name)
+}
+
+// Type and expression reading single item placed at source.
+pub fn item_view(memory: &MemoryType, big_endian: Option<bool>, source: &str, size: &str) -> (String, String) {
+    let e = endian_argument(big_endian);
+    match memory {
+        MemoryType::Native(NativeType::BitField(bf)) => (
+            bf.native.native_typename(),
+            format!("abf::read_bit_field<{}, {}, {}, {}, {}{}>({}, {})",
+                bf.native.native_typename(),
+                bf.container.native_typename(),
+                bf.container.size(),
+                bf.shift,
+                bf.bits,
+                e,
+                source,
+                size)),
+        MemoryType::Native(n) => (
+            n.native_typename(),
+            format!("abf::read<{}, {}{}>({}, {})", n.native_typename(), n.bytes().unwrap(), e, source, size)),
+        MemoryType::Enum(en) => (
+            en.native_typename(),
+            format!("abf::read<{}, {}{}>({}, {})", en.native_typename(), en.bytes().unwrap(), e, source, size)),
+        MemoryType::Struct(s) => {
+            let typename = view_typename(&s.borrow().name());
+            (typename.clone(), format!("{}({}, {})", typename, source, size))
+        },
+        MemoryType::View(v) => {
+            let typename = view_typename(&v.name());
+            (typename.clone(), format!("{}({}, {})", typename, source, size))
+        },
+    }
+}
+
+fn member_source(sm: &StructMemberMemory) -> String {
+    format!("source_ + _{}_offset()", sm.name)
+}
+
+fn member_remaining(sm: &StructMemberMemory) -> String {
+    format!("abf::remaining(size_, _{}_offset())", sm.name)
+}
+
+// Dynamic array without dimension takes the rest of source, None when its items are not sized.
+// Terminated array holds items before its terminator.
+fn array_count(m: &StructMemory, i: usize) -> Option<String> {
+    let sm = m.fields[i].as_ref();
+    let memory = sm.memory.borrow();
+    match &memory.array_size {
+        ArraySize::No => panic!("not an array"),
+        ArraySize::Exact(s) => Some(s.to_string()),
+        ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => match reference_value(m, &m.fields[i..i + 1], false) {
+            Some(ReferenceValue::Holder(value)) => Some(format!("static_cast<uint32_t>({})", value)),
+            Some(ReferenceValue::Slot(slot)) => Some(format!("abf::known_dimension({}_)", slot)),
+            None => memory.memory
+                .bytes()
+                .map(|bytes| format!("({}) / {}", member_remaining(sm), bytes)),
+        },
+        ArraySize::Until(t) => Some(format!("abf::count_until<{}>({}, {})",
+            terminator_typename(&memory, t),
+            member_source(sm),
+            member_remaining(sm))),
+    }
+}
+
+fn member_view(m: &StructMemory, i: usize) -> (String, String) {
+    let sm = m.fields[i].as_ref();
+    let memory = sm.memory.borrow();
+    let source = member_source(sm);
+    let size = member_remaining(sm);
+    if memory.array_size.is_no() {
+        match (&memory.memory, reference_value(m, &m.fields[i..i + 1], true)) {
+            (MemoryType::View(v), Some(ReferenceValue::Holder(key))) => {
+                let typename = view_typename(&v.name());
+                return (typename.clone(), format!("{}({}, {}, static_cast<{}>({}))",
+                    typename,
+                    source,
+                    size,
+                    v.get_index_typename().native_typename(),
+                    key));
+            },
+            (MemoryType::View(v), Some(ReferenceValue::Slot(slot))) => {
+                let typename = view_typename(&v.name());
+                return (typename.clone(), format!("({}_ ? {}({}, {}, *{}_) : {}({}, {}))",
+                    slot, typename, source, size, slot, typename, source, size));
+            },
+            (MemoryType::Struct(s), _) => {
+                let typename = view_typename(&s.borrow().name());
+                return (typename.clone(), format!("{}({}, {}{})", typename, source, size, slot_arguments(m, i, &s.borrow())));
+            },
+            _ => return item_view(&memory.memory, memory.big_endian, &source, &size),
+        }
+    }
+    let count = array_count(m, i);
+    if memory.is_string() {
+        return ("std::string_view".into(), match memory.array_size {
+            ArraySize::Exact(s) => format!("abf::read_string({}, {}, {})", source, size, s),
+            _ => format!("abf::read_dyn_string({}, {}, {})", source, size, count.unwrap()),
+        });
+    }
+    match &memory.memory {
+        MemoryType::Struct(_) | MemoryType::View(_) => {
+            let (item, _) = item_view(&memory.memory, memory.big_endian, &source, &size);
+            let typename = format!("abf::ArrayView<{}>", item);
+            let value = match count {
+                Some(count) => format!("{}({}, {}, {})", typename, source, size, count),
+                None => format!("{}({}, {})", typename, source, size),
+            };
+            (typename, value)
+        },
+        _ => {
+            let typename = format!("abf::NativeArrayView<{}, {}{}>",
+                memory.memory.native_typename(),
+                memory.memory.bytes().unwrap(),
+                endian_argument(memory.big_endian));
+            (typename.clone(), format!("{}({}, {}, {})", typename, source, size, count.unwrap()))
+        },
+    }
+}
+
+fn generate_get_method(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    let (typename, value) = member_view(m, i);
+    writer.write_with_offset(&format!("{} {}() const", typename, sm.name));
+    writer.scope_in();
+    if sm.condition.is_some() {
+        writer.write_with_offset(&format!("if (!has_{}())", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&raise("Absent", &format!("std::runtime_error(\"{}\")", sm.name), &value));
+        writer.scope_out(false);
+    }
+    writer.write_line(&format!("return {};", value));
+    writer.scope_out(false);
+}
+
+fn generate_has_method(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("bool has_{}() const", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("return {};", condition.test(&format!("{}()", condition.flag.name))));
+        writer.scope_out(false);
+    }
+}
+
+fn generate_size(m: &StructMemory, writer: &mut Writer) {
+    if let Some(size) = m.exact_size() {
+        writer.write_with_offset("static constexpr uint32_t _size()");
+        writer.scope_in();
+        writer.write_line(&format!("return {};", size));
+    } else {
+        let last = &m.fields.last().unwrap().name;
+        writer.write_with_offset("uint32_t _size() const");
+        writer.scope_in();
+        writer.write_line(&format!("return _{}_offset() + _{}_size();", last, last));
+    }
+    writer.scope_out(false);
+}
+
+fn generate_verify(m: &StructMemory, writer: &mut Writer) {
+    let checksums = m.get_checksums();
+    if checksums.is_empty() {
+        return
+    }
+    writer.write_with_offset("bool verify() const");
+    writer.scope_in();
+    for c in &checksums {
+        writer.write_line(&format!("uint32_t {}_begin = _{}_offset();", c.origin.name, c.first.name));
+        writer.write_line(&format!("uint32_t {}_end = _{}_offset() + _{}_size();", c.origin.name, c.last.name, c.last.name));
+        writer.write_with_offset(&format!("if ({}_end > size_)", c.origin.name));
+        writer.scope_in();
+        writer.write_line(&raise("BufferTooShort", "abf::BufferTooShort()", "false"));
+        writer.scope_out(false);
+        writer.write_with_offset(&format!("if ({}() != {})",
+            c.origin.name,
+            checksum_call(c,
+                &format!("source_ + {}_begin", c.origin.name),
+                &format!("{}_end - {}_begin", c.origin.name, c.origin.name))));
+        writer.scope_in();
+        writer.write_line("return false;");
+        writer.scope_out(false);
+    }
+    writer.write_line("return true;");
+    writer.scope_out(false);
+}
+
+fn generate_member_offset(m: &StructMemory, group_begin: usize, i: usize, writer: &mut Writer) {
+    let in_group: usize = (group_begin..i)
+        .map(|j| m.fields[j].exact_size().unwrap())
+        .sum();
+    if group_begin == 0 {
+        writer.write_with_offset(&format!("static constexpr uint32_t _{}_offset()", m.fields[i].name));
+        writer.scope_in();
+        writer.write_line(&format!("return {};", in_group));
+    } else {
+        let prev = &m.fields[group_begin - 1].name;
+        writer.write_with_offset(&format!("uint32_t _{}_offset() const", m.fields[i].name));
+        writer.scope_in();
+        writer.write_line(&format!("return _{}_offset() + _{}_size() + {};", prev, prev, in_group));
+    }
+    writer.scope_out(false);
+}
+
+// Bytes of absent optional member are skipped.
+fn generate_member_size(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    if let Some(size) = sm.exact_size() {
+        writer.write_with_offset(&format!("static constexpr uint32_t _{}_size()", sm.name));
+        writer.scope_in();
+        writer.write_line(&format!("return {};", size));
+        writer.scope_out(false);
+        return
+    }
+    let memory = sm.memory.borrow();
+    let size = if let Some(size) = memory.exact_size() {
+        size.to_string()
+    } else if memory.array_size.is_until() {
+        format!("({} + 1) * {}", array_count(m, i).unwrap(), memory.memory.exact_size().unwrap())
+    } else if memory.is_string() {
+        array_count(m, i).unwrap()
+    } else {
+        format!("{}._size()", member_view(m, i).1)
+    };
+    writer.write_with_offset(&format!("uint32_t _{}_size() const", sm.name));
+    writer.scope_in();
+    match &sm.condition {
+        Some(_) => writer.write_line(&format!("return has_{}() ? {} : 0;", sm.variable(), size)),
+        None => writer.write_line(&format!("return {};", size)),
+    }
+    writer.scope_out(false);
+}
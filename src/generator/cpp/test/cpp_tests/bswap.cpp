@@ -25,3 +25,10 @@ UTEST(bswap, u64) {
     uint64_t value = 7845885;
     ASSERT_EQ(abf::bswap64(abf::bswap64(value)), value);
 }
+
+UTEST(bswap, byte_order) {
+    ASSERT_EQ(abf::bswap8(0x12), 0x12);
+    ASSERT_EQ(abf::bswap16(0x1234), 0x3412);
+    ASSERT_EQ(abf::bswap32(0x12345678u), 0x78563412u);
+    ASSERT_EQ(abf::bswap64(0x0102030405060708ull), 0x0807060504030201ull);
+}
@@ -15,3 +15,7 @@ UTEST(enum1, serde) {
     CarDe car_de(buffer);
     ASSERT_EQ(car_de.get_data(), Car::Audi);
 }
+
+UTEST(enum1, compiler_version) {
+    ASSERT_FALSE(abf::COMPILER_VERSION.empty());
+}
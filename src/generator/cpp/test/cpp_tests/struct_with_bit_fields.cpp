@@ -0,0 +1,41 @@
+#include <utest/utest.h>
+#include "struct_with_bit_fields.h"
+
+using namespace struct_with_bit_fields;
+
+UTEST_MAIN();
+
+UTEST(struct_with_bit_fields, serde) {
+    uint8_t buffer[1024];
+    StatusSer status_ser;
+    status_ser.with_mode(5);
+    status_ser.with_enabled(true);
+    status_ser.with_reserved(0xA);
+    status_ser.with_id(0x1234);
+    status_ser.with_level(-3);
+    status_ser.with_counter(0x123);
+    ASSERT_EQ(status_ser.size(), 5);
+    ASSERT_EQ(status_ser.serialize(buffer), 5);
+    // mode, enabled and reserved packed msb first into single byte
+    ASSERT_EQ(buffer[0], 0xBA);
+    ASSERT_EQ(buffer[1], 0x12);
+    ASSERT_EQ(buffer[2], 0x34);
+    // level and counter packed into big endian word
+    ASSERT_EQ(buffer[3], 0xE9);
+    ASSERT_EQ(buffer[4], 0x23);
+
+    StatusDe status_de(buffer);
+    ASSERT_EQ(status_de.mode(), 5);
+    ASSERT_TRUE(status_de.enabled());
+    ASSERT_EQ(status_de.reserved(), 0xA);
+    ASSERT_EQ(status_de.id(), 0x1234);
+    ASSERT_EQ(status_de.level(), -3);
+    ASSERT_EQ(status_de.counter(), 0x123);
+}
+
+UTEST(struct_with_bit_fields, overflow) {
+    StatusSer status_ser;
+    ASSERT_EXCEPTION(status_ser.with_mode(8), std::runtime_error);
+    ASSERT_EXCEPTION(status_ser.with_level(-17), std::runtime_error);
+    ASSERT_EXCEPTION(status_ser.with_level(16), std::runtime_error);
+}
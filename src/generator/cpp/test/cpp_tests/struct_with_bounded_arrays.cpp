@@ -0,0 +1,84 @@
+#include <utest/utest.h>
+#include "struct_with_bounded_arrays.h"
+
+using namespace struct_with_bounded_arrays;
+
+UTEST_MAIN();
+
+// 3 dimension members, 4 u16 values, 8 characters and 3 points at most
+static_assert(TrackSer::MAX_SIZE == 3 + 8 + 8 + 6);
+static_assert(TrackDe::MAX_SIZE == TrackSer::MAX_SIZE);
+
+static void fill_track(TrackSer& track_ser) {
+    track_ser.with_values().get(0).set_data(0x0102);
+    track_ser.with_values().get(1).set_data(0x0304);
+    track_ser.with_label("run");
+    track_ser.with_points().get(0).with_x(1);
+    track_ser.with_points().get(0).with_y(2);
+}
+
+UTEST(struct_with_bounded_arrays, serde) {
+    TrackSer track_ser;
+    fill_track(track_ser);
+    uint8_t buffer[TrackSer::MAX_SIZE];
+    ASSERT_EQ(track_ser.serialize(buffer, sizeof(buffer)), 12u);
+    const uint8_t expected[] = {
+        2, 0x02, 0x01, 0x04, 0x03,
+        3, 'r', 'u', 'n',
+        1, 1, 2
+    };
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    TrackDe track_de(buffer, sizeof(expected));
+    ASSERT_TRUE(track_de.validate().ok());
+    ASSERT_EQ(track_de.count(), 2);
+    ASSERT_EQ(track_de.values().get(1).get_data(), 0x0304);
+    ASSERT_TRUE(track_de.label() == "run");
+    ASSERT_EQ(track_de.points().get(0).y(), 2);
+}
+
+UTEST(struct_with_bounded_arrays, full) {
+    TrackSer track_ser;
+    for (uint32_t i = 0; i < 4; i++) {
+        track_ser.with_values().get(i).set_data(i);
+    }
+    track_ser.with_label("12345678");
+    for (uint32_t i = 0; i < 3; i++) {
+        track_ser.with_points().get(i).with_x(i);
+        track_ser.with_points().get(i).with_y(i);
+    }
+    ASSERT_EQ(track_ser.size(), TrackSer::MAX_SIZE);
+}
+
+UTEST(struct_with_bounded_arrays, over_bound) {
+    TrackSer track_ser;
+    ASSERT_EXCEPTION(track_ser.with_values().get(4), std::runtime_error);
+    ASSERT_EXCEPTION(track_ser.with_label("123456789"), std::runtime_error);
+    ASSERT_EXCEPTION(track_ser.with_points().get(3), std::runtime_error);
+}
+
+UTEST(struct_with_bounded_arrays, inline_storage) {
+    using Values = abf::BoundedArraySerializer<abf::NativeSerializer<uint16_t, 2>, 4>;
+    static_assert(sizeof(Values) >= 4 * sizeof(abf::NativeSerializer<uint16_t, 2>));
+    Values values;
+    values.resize(2);
+    ASSERT_EQ(values.length(), 2u);
+    ASSERT_EQ(values.size(), 4u);
+    ASSERT_EXCEPTION(values.resize(5), std::runtime_error);
+}
+
+UTEST(struct_with_bounded_arrays, bound_exceeded) {
+    // dimension claims more values than bound
+    const uint8_t buffer[] = {
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0,
+        0
+    };
+    TrackDe track_de(buffer, sizeof(buffer));
+    auto error = track_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::BoundExceeded);
+    ASSERT_TRUE(error.path == "values");
+    ASSERT_EXCEPTION(track_de.values().get(4), std::runtime_error);
+}
@@ -0,0 +1,73 @@
+#include <utest/utest.h>
+#include "struct_with_random_access.h"
+
+using namespace struct_with_random_access;
+
+UTEST_MAIN();
+
+static uint32_t serialize_record(uint8_t* buffer) {
+    RecordSer record_ser;
+    record_ser.with_entry().with_name("entry");
+    record_ser.with_entry().with_flags(0x01);
+    record_ser.with_entry().with_extra(0x0102);
+    record_ser.with_entry().with_number().with_u16(0x0304);
+    record_ser.with_entry().with_values().get(0).set_data(0x0506);
+    record_ser.with_entry().with_values().get(1).set_data(0x0708);
+    record_ser.with_entry().with_tail(0x090A0B0C);
+    record_ser.with_trailer(0x0D);
+    return record_ser.serialize(buffer);
+}
+
+UTEST(struct_with_bounds, complete) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_record(buffer);
+
+    RecordDe record_de(buffer, size);
+    ASSERT_EQ(record_de.trailer(), 0x0D);
+    ASSERT_EQ(record_de.entry().values().get(1).get_data(), 0x0708);
+}
+
+UTEST(struct_with_bounds, truncated) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_record(buffer);
+
+    // buffer ends inside second value
+    RecordDe record_de(buffer, size - 7);
+    ASSERT_TRUE(record_de.entry().name() == "entry");
+    ASSERT_EQ(record_de.entry().values().get(0).get_data(), 0x0506);
+    ASSERT_EXCEPTION(record_de.entry().values().get(1).get_data(), abf::BufferTooShort);
+    ASSERT_EXCEPTION(record_de.entry().tail(), abf::BufferTooShort);
+    ASSERT_EXCEPTION(record_de.trailer(), abf::BufferTooShort);
+}
+
+UTEST(struct_with_bounds, corrupted_dimension) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_record(buffer);
+    // count of values
+    buffer[12] = 200;
+
+    RecordDe record_de(buffer, size);
+    ASSERT_EQ(record_de.entry().count(), 200);
+    ASSERT_EXCEPTION(record_de.entry().tail(), abf::BufferTooShort);
+}
+
+UTEST(struct_with_bounds, corrupted_string_dimension) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_record(buffer);
+    buffer[0] = 100;
+
+    RecordDe record_de(buffer, size);
+    ASSERT_EXCEPTION(record_de.entry().name(), abf::BufferTooShort);
+}
+
+UTEST(struct_with_bounds, truncated_view) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_record(buffer);
+
+    const RecordView record(buffer, size - 7);
+    ASSERT_TRUE(record.entry().name() == "entry");
+    ASSERT_EQ(record.entry().values().get(0), 0x0506);
+    ASSERT_EXCEPTION(record.entry().values().get(1), abf::BufferTooShort);
+    ASSERT_EXCEPTION(record.entry().tail(), abf::BufferTooShort);
+    ASSERT_EXCEPTION(record.trailer(), abf::BufferTooShort);
+}
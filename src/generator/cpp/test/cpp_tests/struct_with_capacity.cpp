@@ -0,0 +1,66 @@
+#include <algorithm>
+#include <utest/utest.h>
+#include "struct_with_random_access.h"
+
+using namespace struct_with_random_access;
+
+UTEST_MAIN();
+
+static void fill_record(RecordSer& record_ser) {
+    record_ser.with_entry().with_name("entry");
+    record_ser.with_entry().with_flags(0x01);
+    record_ser.with_entry().with_extra(0x0102);
+    record_ser.with_entry().with_number().with_u16(0x0304);
+    record_ser.with_entry().with_values().get(0).set_data(0x0506);
+    record_ser.with_entry().with_values().get(1).set_data(0x0708);
+    record_ser.with_entry().with_tail(0x090A0B0C);
+    record_ser.with_trailer(0x0D);
+}
+
+UTEST(struct_with_capacity, fits) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    ASSERT_EQ(record_ser.size(), 22u);
+
+    uint8_t buffer[22];
+    ASSERT_EQ(record_ser.serialize(buffer, sizeof(buffer)), 22u);
+
+    RecordDe record_de(buffer, sizeof(buffer));
+    ASSERT_EQ(record_de.entry().number().u16(), 0x0304);
+    ASSERT_EQ(record_de.trailer(), 0x0D);
+}
+
+UTEST(struct_with_capacity, too_small) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+
+    uint8_t buffer[32];
+    std::fill(buffer, buffer + sizeof(buffer), 0xEE);
+    ASSERT_EXCEPTION(record_ser.serialize(buffer, 21), abf::BufferTooSmall);
+    // nothing is written
+    ASSERT_TRUE(std::all_of(buffer, buffer + sizeof(buffer), [](uint8_t b) { return b == 0xEE; }));
+}
+
+UTEST(struct_with_capacity, span) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+
+    std::vector<uint8_t> buffer(64);
+    ASSERT_EQ(record_ser.serialize(std::span<uint8_t>(buffer)), 22u);
+    ASSERT_EQ(buffer[21], 0x0D);
+    ASSERT_EXCEPTION(record_ser.serialize(std::span<uint8_t>(buffer).first(10)), abf::BufferTooSmall);
+}
+
+UTEST(struct_with_capacity, runtime_serializers) {
+    uint8_t buffer[8] = {};
+    abf::NativeSerializer<uint32_t, 3> native;
+    native.set_data(0x010203);
+    ASSERT_EXCEPTION(native.serialize(buffer, 2), abf::BufferTooSmall);
+    ASSERT_EQ(native.serialize(buffer, 3), 3u);
+
+    // member not closing bit field group still writes whole container
+    abf::BitFieldSerializer<uint8_t, uint16_t, 2, 0, 4, false> bit_field;
+    bit_field.set_data(5);
+    ASSERT_EXCEPTION(bit_field.serialize(buffer, 1), abf::BufferTooSmall);
+    ASSERT_EQ(bit_field.serialize(buffer, 2), 0u);
+}
@@ -0,0 +1,41 @@
+#include <utest/utest.h>
+#include "struct_with_checksum.h"
+
+using namespace struct_with_checksum;
+
+UTEST_MAIN();
+
+static const uint8_t expected[] = {
+    0x50, 0xA5, 0x03, 0x01, 0x02, 0x03, 0x06,
+    0x6F, 0xAA,
+    0x34, 0xDC, 0xC8, 0xAA
+};
+
+UTEST(struct_with_checksum, serde) {
+    uint8_t buffer[1024];
+    FrameSer frame_ser;
+    frame_ser.with_header(0xA5);
+    frame_ser.with_payload().get(0).set_data(1);
+    frame_ser.with_payload().get(1).set_data(2);
+    frame_ser.with_payload().get(2).set_data(3);
+    ASSERT_EQ(frame_ser.serialize(buffer), sizeof(expected));
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    FrameDe frame_de(buffer);
+    ASSERT_TRUE(frame_de.verify());
+    ASSERT_EQ(frame_de.header_crc(), 0x50);
+    ASSERT_EQ(frame_de.payload_sum(), 0x06);
+    ASSERT_EQ(frame_de.crc(), 0x6FAA);
+    ASSERT_EQ(frame_de.frame_crc(), 0x34DCC8AA);
+}
+
+UTEST(struct_with_checksum, mismatch) {
+    uint8_t buffer[sizeof(expected)];
+    memcpy(buffer, expected, sizeof(expected));
+    buffer[4] ^= 0x01;
+
+    FrameDe frame_de(buffer);
+    ASSERT_FALSE(frame_de.verify());
+}
@@ -0,0 +1,25 @@
+#include <utest/utest.h>
+#include "struct_with_floats.h"
+
+using namespace struct_with_floats;
+
+UTEST_MAIN();
+
+UTEST(struct_with_floats, serde) {
+    uint8_t buffer[1024];
+    SampleSer sample_ser;
+    sample_ser.with_temperature(21.5f);
+    sample_ser.with_pressure(101325.25);
+    sample_ser.with_id(7);
+    ASSERT_EQ(sample_ser.serialize(buffer), 14);
+    // 21.5f is 0x41AC0000 in big endian
+    ASSERT_EQ(buffer[0], 0x41);
+    ASSERT_EQ(buffer[1], 0xAC);
+    ASSERT_EQ(buffer[2], 0x00);
+    ASSERT_EQ(buffer[3], 0x00);
+
+    SampleDe sample_de(buffer);
+    ASSERT_EQ(sample_de.temperature(), 21.5f);
+    ASSERT_EQ(sample_de.pressure(), 101325.25);
+    ASSERT_EQ(sample_de.id(), 7);
+}
@@ -0,0 +1,36 @@
+#include <utest/utest.h>
+#include "struct_with_import.h"
+
+using namespace struct_with_import;
+
+UTEST_MAIN();
+
+UTEST(struct_with_import, serde) {
+    static const uint8_t expected[] = {0x01, 0x00, 0x04, 0xDE, 0xAD, 0xBE, 0xEF};
+    MessageSer message_ser;
+    message_ser.with_header().with_version(1);
+    message_ser.with_header().with_length(4);
+    message_ser.with_payload(0xDEADBEEF);
+    auto buffer = message_ser.serialize();
+    ASSERT_EQ(buffer.size(), sizeof(expected));
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    MessageDe message_de(buffer.data());
+    ASSERT_EQ(message_de.header().version(), 1);
+    ASSERT_EQ(message_de.header().length(), 4);
+    ASSERT_EQ(message_de.payload(), 0xDEADBEEF);
+}
+
+UTEST(struct_with_import, imported_type) {
+    uint8_t buffer[1024];
+    common_header::HeaderSer header_ser;
+    header_ser.with_version(2);
+    header_ser.with_length(0x0102);
+    header_ser.serialize(buffer);
+
+    common_header::HeaderDe header_de(buffer);
+    ASSERT_EQ(header_de.version(), 2);
+    ASSERT_EQ(header_de.length(), 0x0102);
+}
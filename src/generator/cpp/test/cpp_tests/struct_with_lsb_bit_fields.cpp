@@ -0,0 +1,28 @@
+#include <utest/utest.h>
+#include "struct_with_lsb_bit_fields.h"
+
+using namespace struct_with_lsb_bit_fields;
+
+UTEST_MAIN();
+
+UTEST(struct_with_lsb_bit_fields, serde) {
+    uint8_t buffer[1024];
+    RegisterSer register_ser;
+    register_ser.with_ready(true);
+    register_ser.with_channel(6);
+    register_ser.with_gain(-2);
+    register_ser.with_threshold(0xABC);
+    register_ser.with_mode(0x5);
+    ASSERT_EQ(register_ser.serialize(buffer), 3);
+    // first member takes least significant bits of little endian container
+    ASSERT_EQ(buffer[0], 0xED);
+    ASSERT_EQ(buffer[1], 0xBC);
+    ASSERT_EQ(buffer[2], 0x5A);
+
+    RegisterDe register_de(buffer);
+    ASSERT_TRUE(register_de.ready());
+    ASSERT_EQ(register_de.channel(), 6);
+    ASSERT_EQ(register_de.gain(), -2);
+    ASSERT_EQ(register_de.threshold(), 0xABC);
+    ASSERT_EQ(register_de.mode(), 0x5);
+}
@@ -0,0 +1,79 @@
+#include <utest/utest.h>
+#include "struct_with_message_view.h"
+
+using namespace struct_with_message_view;
+
+UTEST_MAIN();
+
+static_assert(HeaderView::_size() == 6);
+static_assert(PointView::_size() == 4);
+
+static std::vector<uint8_t> serialize_message(bool extra) {
+    MessageSer message_ser;
+    message_ser.with_header().with_version(5);
+    message_ser.with_header().with_flags(0x11);
+    message_ser.with_header().with_kind(Kind::Config);
+    message_ser.with_header().with_name("abc");
+    message_ser.with_options(extra ? 0x01 : 0x00);
+    message_ser.with_label("label");
+    message_ser.with_extra(0x0102);
+    message_ser.with_value().with_point().with_x(-3);
+    message_ser.with_value().with_point().with_y(0x0304);
+    message_ser.with_points().get(0).with_x(1);
+    message_ser.with_points().get(0).with_y(2);
+    message_ser.with_points().get(1).with_x(-1);
+    message_ser.with_points().get(1).with_y(-2);
+    message_ser.with_samples().get(0).set_data(0x0A0B);
+    message_ser.with_samples().get(1).set_data(0x0C0D);
+    message_ser.with_samples().get(2).set_data(0x0E0F);
+    uint8_t buffer[1024];
+    uint32_t size = message_ser.serialize(buffer);
+    return std::vector<uint8_t>(buffer, buffer + size);
+}
+
+UTEST(struct_with_message_view, read) {
+    auto buffer = serialize_message(true);
+
+    const MessageView message(buffer.data(), buffer.size());
+    ASSERT_EQ(message._size(), buffer.size());
+    ASSERT_TRUE(message.verify());
+    ASSERT_EQ(message.header().version(), 5);
+    ASSERT_EQ(message.header().flags(), 0x11);
+    ASSERT_EQ(message.header().kind(), Kind::Config);
+    ASSERT_TRUE(message.header().name() == "abc");
+    ASSERT_TRUE(message.label() == "label");
+    ASSERT_TRUE(message.has_extra());
+    ASSERT_EQ(message.extra(), 0x0102);
+    ASSERT_EQ(message.value_key(), 2);
+    ASSERT_EQ(message.value().point().x(), -3);
+    ASSERT_EQ(message.value().point().y(), 0x0304);
+    ASSERT_EXCEPTION(message.value().u8(), std::runtime_error);
+    ASSERT_EQ(message.count(), 3);
+    ASSERT_EQ(message.samples().count(), 3u);
+    ASSERT_EQ(message.samples().get(2), 0x0E0F);
+    ASSERT_EXCEPTION(message.samples().get(3), std::runtime_error);
+    // points fill the rest of message
+    ASSERT_EQ(message.points().count(), 2u);
+    ASSERT_EQ(message.points().get(1).x(), -1);
+    ASSERT_EQ(message.points().get(1).y(), -2);
+}
+
+// later members are read without reading members before them
+UTEST(struct_with_message_view, random_access) {
+    auto buffer = serialize_message(false);
+
+    MessageView message(buffer.data(), buffer.size());
+    ASSERT_EQ(message.points().get(0).y(), 2);
+    ASSERT_EQ(message.samples().get(0), 0x0A0B);
+    ASSERT_FALSE(message.has_extra());
+    ASSERT_EXCEPTION(message.extra(), std::runtime_error);
+    ASSERT_TRUE(message.verify());
+}
+
+UTEST(struct_with_message_view, mismatch) {
+    auto buffer = serialize_message(true);
+    buffer[8] ^= 0x01;
+
+    MessageView message(buffer.data(), buffer.size());
+    ASSERT_FALSE(message.verify());
+}
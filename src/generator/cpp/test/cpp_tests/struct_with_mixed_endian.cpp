@@ -0,0 +1,57 @@
+#include <utest/utest.h>
+#include "struct_with_mixed_endian.h"
+
+using namespace struct_with_mixed_endian;
+
+UTEST_MAIN();
+
+UTEST(struct_with_mixed_endian, serde) {
+    uint8_t buffer[1024];
+    FrameSer frame_ser;
+    frame_ser.with_id(0x0102);
+    frame_ser.with_descriptor().with_length(0x0304);
+    frame_ser.with_descriptor().with_kind(Kind::Config);
+    frame_ser.with_descriptor().with_vendor(0x050607);
+    frame_ser.with_descriptor().with_product(0x0809);
+    frame_ser.with_crc(0x0A0B0C0D);
+    frame_ser.with_samples().get(0).set_data(-2);
+    frame_ser.with_samples().get(1).set_data(0x0E0F);
+    ASSERT_EQ(frame_ser.serialize(buffer), 21);
+
+    // id follows big endian of schema
+    ASSERT_EQ(buffer[0], 0x01);
+    ASSERT_EQ(buffer[1], 0x02);
+    // descriptor is little endian
+    ASSERT_EQ(buffer[2], 0x04);
+    ASSERT_EQ(buffer[3], 0x03);
+    ASSERT_EQ(buffer[4], 0x02);
+    ASSERT_EQ(buffer[5], 0x00);
+    ASSERT_EQ(buffer[6], 0x07);
+    ASSERT_EQ(buffer[7], 0x06);
+    ASSERT_EQ(buffer[8], 0x05);
+    // except product overridden back to big endian
+    ASSERT_EQ(buffer[9], 0x08);
+    ASSERT_EQ(buffer[10], 0x09);
+    // little endian members of big endian struct
+    ASSERT_EQ(buffer[11], 0x0D);
+    ASSERT_EQ(buffer[12], 0x0C);
+    ASSERT_EQ(buffer[13], 0x0B);
+    ASSERT_EQ(buffer[14], 0x0A);
+    ASSERT_EQ(buffer[15], 0x02);
+    ASSERT_EQ(buffer[16], 0x00);
+    ASSERT_EQ(buffer[17], 0xFE);
+    ASSERT_EQ(buffer[18], 0xFF);
+    ASSERT_EQ(buffer[19], 0x0F);
+    ASSERT_EQ(buffer[20], 0x0E);
+
+    FrameDe frame_de(buffer);
+    ASSERT_EQ(frame_de.id(), 0x0102);
+    ASSERT_EQ(frame_de.descriptor().length(), 0x0304);
+    ASSERT_EQ(frame_de.descriptor().kind(), Kind::Config);
+    ASSERT_EQ(frame_de.descriptor().vendor(), 0x050607u);
+    ASSERT_EQ(frame_de.descriptor().product(), 0x0809);
+    ASSERT_EQ(frame_de.crc(), 0x0A0B0C0Du);
+    ASSERT_EQ(frame_de.count(), 2);
+    ASSERT_EQ(frame_de.samples().get(0).get_data(), -2);
+    ASSERT_EQ(frame_de.samples().get(1).get_data(), 0x0E0F);
+}
@@ -0,0 +1,76 @@
+#include <utest/utest.h>
+#include "struct_with_nested_references.h"
+
+using namespace struct_with_nested_references;
+
+UTEST_MAIN();
+
+static uint32_t serialize_packet(uint8_t* buffer) {
+    PacketSer packet_ser;
+    packet_ser.with_body().with_tag(7);
+    packet_ser.with_body().with_items().get(0).set_data(0xA1);
+    packet_ser.with_body().with_items().get(1).set_data(0xA2);
+    packet_ser.with_body().with_name("hi");
+    packet_ser.with_body().with_extra().with_u16(0x0304);
+    packet_ser.with_value().with_u8(0x55);
+    packet_ser.with_payload().get(0).set_data(0x1122);
+    packet_ser.with_payload().get(1).set_data(0x3344);
+    packet_ser.with_payload().get(2).set_data(0x5566);
+    return packet_ser.serialize(buffer);
+}
+
+UTEST(struct_with_nested_references, serialize) {
+    uint8_t buffer[1024];
+    ASSERT_EQ(serialize_packet(buffer), 22u);
+    const uint8_t expected[] = {
+        2, 2,
+        1, 3, 0, 2, 7, 0,
+        7, 0xA1, 0xA2, 'h', 'i', 0x04, 0x03,
+        0x55,
+        0x22, 0x11, 0x44, 0x33, 0x66, 0x55
+    };
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+}
+
+UTEST(struct_with_nested_references, deserialize) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_packet(buffer);
+
+    PacketDe packet_de(buffer, size);
+    ASSERT_EQ(packet_de.header().kind(), 1);
+    ASSERT_EQ(packet_de.header().payload_len(), 3);
+    ASSERT_EQ(packet_de.header().body_size(), 7);
+    ASSERT_EQ(packet_de.body().items().get(0).get_data(), 0xA1);
+    ASSERT_EQ(packet_de.body().items().get(1).get_data(), 0xA2);
+    ASSERT_EXCEPTION(packet_de.body().items().get(2), std::runtime_error);
+    ASSERT_TRUE(packet_de.body().name() == "hi");
+    ASSERT_EQ(packet_de.body().extra().u16(), 0x0304);
+    ASSERT_EQ(packet_de.value().u8(), 0x55);
+    ASSERT_EQ(packet_de.payload().get(0).get_data(), 0x1122);
+    ASSERT_EQ(packet_de.payload().get(1).get_data(), 0x3344);
+    ASSERT_EQ(packet_de.payload().get(2).get_data(), 0x5566);
+    ASSERT_EXCEPTION(packet_de.payload().get(3), std::runtime_error);
+    ASSERT_TRUE(packet_de.validate().ok());
+
+    PacketDe copy = packet_de;
+    ASSERT_EQ(copy.payload().get(0).get_data(), 0x1122);
+}
+
+UTEST(struct_with_nested_references, view) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_packet(buffer);
+
+    PacketView packet(buffer, size);
+    ASSERT_EQ(packet._size(), size);
+    ASSERT_EQ(packet.body().items().count(), 2u);
+    ASSERT_TRUE(packet.body().name() == "hi");
+    ASSERT_EQ(packet.body().extra().u16(), 0x0304);
+    ASSERT_EQ(packet.value().u8(), 0x55);
+    ASSERT_EQ(packet.payload().get(2), 0x5566);
+
+    // dimensions held by embedding struct are not known to body viewed on its own
+    BodyView body(buffer + 8, size - 8);
+    ASSERT_EXCEPTION(body.items(), std::runtime_error);
+}
@@ -0,0 +1,52 @@
+#include <utest/utest.h>
+#include "struct_with_optional.h"
+
+using namespace struct_with_optional;
+
+UTEST_MAIN();
+
+UTEST(struct_with_optional, present) {
+    static const uint8_t expected[] = {0x08, 0x01, 0x02, 0x03, 0x00, 0xFF};
+    PacketSer packet_ser;
+    packet_ser.with_flags(0x08);
+    packet_ser.with_ext().with_code(1);
+    packet_ser.with_ext().with_value(0x0203);
+    packet_ser.with_extended(false);
+    packet_ser.with_trailer(0xFF);
+    ASSERT_TRUE(packet_ser.has_ext());
+    ASSERT_FALSE(packet_ser.has_tail());
+    auto buffer = packet_ser.serialize();
+    ASSERT_EQ(buffer.size(), sizeof(expected));
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    PacketDe packet_de(buffer.data());
+    ASSERT_EQ(packet_de.flags(), 0x08);
+    ASSERT_TRUE(packet_de.has_ext());
+    ASSERT_EQ(packet_de.ext().code(), 1);
+    ASSERT_EQ(packet_de.ext().value(), 0x0203);
+    ASSERT_FALSE(packet_de.has_tail());
+    ASSERT_EQ(packet_de.trailer(), 0xFF);
+}
+
+UTEST(struct_with_optional, absent) {
+    static const uint8_t expected[] = {0x00, 0x01, 0x04, 0x05, 0xEE};
+    PacketSer packet_ser;
+    packet_ser.with_flags(0x00);
+    packet_ser.with_extended(true);
+    packet_ser.with_tail(0x0405);
+    packet_ser.with_trailer(0xEE);
+    auto buffer = packet_ser.serialize();
+    ASSERT_EQ(buffer.size(), sizeof(expected));
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    PacketDe packet_de(buffer.data());
+    ASSERT_FALSE(packet_de.has_ext());
+    ASSERT_EXCEPTION(packet_de.ext(), std::runtime_error);
+    ASSERT_TRUE(packet_de.has_tail());
+    ASSERT_EQ(packet_de.tail(), 0x0405);
+    ASSERT_EQ(packet_de.trailer(), 0xEE);
+}
@@ -0,0 +1,44 @@
+#include <utest/utest.h>
+#include "struct_with_random_access.h"
+
+using namespace struct_with_random_access;
+
+UTEST_MAIN();
+
+static uint32_t serialize_record(uint8_t* buffer, bool extra) {
+    RecordSer record_ser;
+    record_ser.with_entry().with_name("entry");
+    record_ser.with_entry().with_flags(extra ? 0x01 : 0x00);
+    record_ser.with_entry().with_extra(0x0102);
+    record_ser.with_entry().with_number().with_u16(0x0304);
+    record_ser.with_entry().with_values().get(0).set_data(0x0506);
+    record_ser.with_entry().with_values().get(1).set_data(0x0708);
+    record_ser.with_entry().with_tail(0x090A0B0C);
+    record_ser.with_trailer(0x0D);
+    return record_ser.serialize(buffer);
+}
+
+UTEST(struct_with_random_access, last_member_first) {
+    uint8_t buffer[1024];
+    ASSERT_EQ(serialize_record(buffer, true), 22u);
+
+    RecordDe record_de(buffer);
+    ASSERT_EQ(record_de.trailer(), 0x0D);
+    ASSERT_EQ(record_de.entry().tail(), 0x090A0B0Cu);
+    ASSERT_EQ(record_de.entry().values().get(1).get_data(), 0x0708);
+    ASSERT_EQ(record_de.entry().extra(), 0x0102);
+    ASSERT_TRUE(record_de.entry().name() == "entry");
+    ASSERT_EQ(record_de.entry().number().u16(), 0x0304);
+    ASSERT_EXCEPTION(record_de.entry().number().u8(), std::runtime_error);
+}
+
+UTEST(struct_with_random_access, absent_optional) {
+    uint8_t buffer[1024];
+    ASSERT_EQ(serialize_record(buffer, false), 20u);
+
+    RecordDe record_de(buffer);
+    ASSERT_EQ(record_de.entry().count(), 2);
+    ASSERT_EQ(record_de.trailer(), 0x0D);
+    ASSERT_FALSE(record_de.entry().has_extra());
+    ASSERT_EQ(record_de.entry().number_key(), 1);
+}
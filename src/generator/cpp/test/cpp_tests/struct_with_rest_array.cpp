@@ -0,0 +1,83 @@
+#include <utest/utest.h>
+#include "struct_with_rest_array.h"
+
+using namespace struct_with_rest_array;
+
+UTEST_MAIN();
+
+UTEST(struct_with_rest_array, natives) {
+    FrameSer frame_ser;
+    frame_ser.with_kind(7);
+    frame_ser.with_payload().get(0).set_data(0x0102);
+    frame_ser.with_payload().get(1).set_data(0x0304);
+    frame_ser.with_payload().get(2).set_data(0x0506);
+    uint8_t buffer[16];
+    ASSERT_EQ(frame_ser.serialize(buffer, sizeof(buffer)), 7u);
+    const uint8_t expected[] = {7, 0x02, 0x01, 0x04, 0x03, 0x06, 0x05};
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    FrameDe frame_de(buffer, 7);
+    ASSERT_TRUE(frame_de.validate().ok());
+    ASSERT_EQ(frame_de.kind(), 7);
+    ASSERT_EQ(frame_de.payload().length(), 3u);
+    ASSERT_EQ(frame_de.payload().get(2).get_data(), 0x0506);
+    ASSERT_EXCEPTION(frame_de.payload().get(3), std::runtime_error);
+
+    FrameView frame_view(buffer, 7);
+    ASSERT_EQ(frame_view.payload().count(), 3u);
+    ASSERT_EQ(frame_view.payload().get(1), 0x0304);
+}
+
+UTEST(struct_with_rest_array, empty) {
+    const uint8_t buffer[] = {7};
+    FrameDe frame_de(buffer, sizeof(buffer));
+    ASSERT_TRUE(frame_de.validate().ok());
+    ASSERT_EQ(frame_de.payload().length(), 0u);
+}
+
+UTEST(struct_with_rest_array, partial_item) {
+    const uint8_t buffer[] = {7, 0x02, 0x01, 0x04};
+    FrameDe frame_de(buffer, sizeof(buffer));
+    ASSERT_EQ(frame_de.payload().length(), 1u);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::BufferTooShort);
+    ASSERT_TRUE(error.path == "payload[1]");
+}
+
+UTEST(struct_with_rest_array, length_not_known) {
+    const uint8_t buffer[] = {7, 0x02, 0x01};
+    FrameDe frame_de(buffer);
+    ASSERT_EQ(frame_de.kind(), 7);
+    ASSERT_EXCEPTION(frame_de.payload().length(), std::runtime_error);
+}
+
+UTEST(struct_with_rest_array, structs) {
+    PathSer path_ser;
+    path_ser.with_id(1);
+    path_ser.with_points().get(0).with_x(10);
+    path_ser.with_points().get(0).with_y(20);
+    path_ser.with_points().get(1).with_x(30);
+    path_ser.with_points().get(1).with_y(40);
+    auto buffer = path_ser.serialize();
+    ASSERT_EQ(buffer.size(), 5u);
+
+    PathDe path_de(buffer.data(), buffer.size());
+    ASSERT_TRUE(path_de.validate().ok());
+    ASSERT_EQ(path_de.points().length(), 2u);
+    ASSERT_EQ(path_de.points().get(1).y(), 40);
+}
+
+UTEST(struct_with_rest_array, string) {
+    TextSer text_ser;
+    text_ser.with_id(2);
+    text_ser.with_body("hello");
+    auto buffer = text_ser.serialize();
+    ASSERT_EQ(buffer.size(), 6u);
+
+    TextDe text_de(buffer.data(), buffer.size());
+    ASSERT_TRUE(text_de.validate().ok());
+    ASSERT_TRUE(text_de.body() == "hello");
+    ASSERT_TRUE(TextView(buffer.data(), buffer.size()).body() == "hello");
+}
@@ -0,0 +1,36 @@
+#include <utest/utest.h>
+#include "struct_with_message_view.h"
+
+using namespace struct_with_message_view;
+
+UTEST_MAIN();
+
+template <typename T>
+constexpr bool has_exact_size = requires { T::EXACT_SIZE; };
+
+template <typename T>
+constexpr bool has_max_size = requires { T::MAX_SIZE; };
+
+static_assert(PointSer::EXACT_SIZE == 4);
+static_assert(PointDe::MAX_SIZE == 4);
+static_assert(HeaderSer::EXACT_SIZE == 6);
+static_assert(KindSer<>::EXACT_SIZE == 1);
+// alternatives differ in size, so only maximum is known
+static_assert(!has_exact_size<ValueSer>);
+static_assert(ValueSer::MAX_SIZE == 4);
+// dynamic members have no upper bound
+static_assert(!has_exact_size<MessageSer>);
+static_assert(!has_max_size<MessageDe>);
+
+UTEST(struct_with_size_constants, static_buffer) {
+    PointSer point_ser;
+    point_ser.with_x(1);
+    point_ser.with_y(-1);
+    uint8_t buffer[PointSer::EXACT_SIZE];
+    ASSERT_EQ(point_ser.serialize(buffer), PointSer::EXACT_SIZE);
+
+    ValueSer value_ser;
+    value_ser.with_u32(7);
+    uint8_t value_buffer[ValueSer::MAX_SIZE];
+    ASSERT_LE(value_ser.serialize(value_buffer), ValueSer::MAX_SIZE);
+}
@@ -0,0 +1,35 @@
+#include <utest/utest.h>
+#include "struct_with_strings.h"
+
+using namespace struct_with_strings;
+
+UTEST_MAIN();
+
+UTEST(struct_with_strings, serde) {
+    uint8_t buffer[1024];
+    DeviceSer device_ser;
+    device_ser.with_serial("SN42");
+    device_ser.with_name("sensor");
+    device_ser.with_version(0x0102);
+    ASSERT_EQ(device_ser.size(), 17);
+    ASSERT_EQ(device_ser.serialize(buffer), 17);
+    const uint8_t expected[] = {
+        'S', 'N', '4', '2', 0, 0, 0, 0,
+        6, 's', 'e', 'n', 's', 'o', 'r',
+        0x01, 0x02
+    };
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+
+    DeviceDe device_de(buffer);
+    ASSERT_TRUE(device_de.serial() == "SN42");
+    ASSERT_EQ(device_de.name_len(), 6);
+    ASSERT_TRUE(device_de.name() == "sensor");
+    ASSERT_EQ(device_de.version(), 0x0102);
+}
+
+UTEST(struct_with_strings, too_long) {
+    DeviceSer device_ser;
+    ASSERT_EXCEPTION(device_ser.with_serial("SN4200000"), std::runtime_error);
+}
@@ -0,0 +1,106 @@
+#include <utest/utest.h>
+#include "struct_with_terminated_arrays.h"
+
+using namespace struct_with_terminated_arrays;
+
+UTEST_MAIN();
+
+static RecordSer record_ser() {
+    RecordSer ser;
+    ser.with_id(9);
+    ser.with_name("ab");
+    ser.with_codes().get(0).set_data(0x0102);
+    ser.with_codes().get(1).set_data(0x0304);
+    ser.with_kinds().get(0).set_data(Kind::Move);
+    ser.with_entries().get(0).with_kind(Kind::Line);
+    ser.with_entries().get(0).with_value(0x0506);
+    ser.with_crc(0x77);
+    return ser;
+}
+
+UTEST(struct_with_terminated_arrays, serialize) {
+    auto ser = record_ser();
+    uint8_t buffer[32];
+    ASSERT_EQ(ser.serialize(buffer, sizeof(buffer)), 19u);
+    const uint8_t expected[] = {
+        9,
+        'a', 'b', 0,
+        0x02, 0x01, 0x04, 0x03, 0xFF, 0xFF,
+        1, 0,
+        2, 0x06, 0x05, 0, 0, 0,
+        0x77};
+    for (auto i = 0; i < sizeof(expected); i++) {
+        ASSERT_EQ(buffer[i], expected[i]);
+    }
+}
+
+UTEST(struct_with_terminated_arrays, deserialize) {
+    auto buffer = record_ser().serialize();
+    RecordDe de(buffer.data(), buffer.size());
+    ASSERT_TRUE(de.validate().ok());
+    ASSERT_TRUE(de.name() == "ab");
+    ASSERT_EQ(de.codes().length(), 2u);
+    ASSERT_EQ(de.codes().get(1).get_data(), 0x0304);
+    ASSERT_EXCEPTION(de.codes().get(2), std::runtime_error);
+    ASSERT_EQ(de.kinds().length(), 1u);
+    ASSERT_TRUE(de.kinds().get(0).get_data() == Kind::Move);
+    ASSERT_EQ(de.entries().length(), 1u);
+    ASSERT_EQ(de.entries().get(0).value(), 0x0506);
+    ASSERT_EQ(de.crc(), 0x77);
+}
+
+UTEST(struct_with_terminated_arrays, view) {
+    auto buffer = record_ser().serialize();
+    RecordView view(buffer.data(), buffer.size());
+    ASSERT_TRUE(view.name() == "ab");
+    ASSERT_EQ(view.codes().count(), 2u);
+    ASSERT_EQ(view.codes().get(0), 0x0102);
+    ASSERT_EQ(view.kinds().count(), 1u);
+    ASSERT_EQ(view.entries().count(), 1u);
+    ASSERT_EQ(view.entries().get(0).value(), 0x0506);
+    ASSERT_EQ(view.crc(), 0x77);
+    ASSERT_EQ(view._size(), 19u);
+}
+
+UTEST(struct_with_terminated_arrays, empty) {
+    const uint8_t buffer[] = {9, 0, 0xFF, 0xFF, 0, 0, 0, 0, 0x77};
+    RecordDe de(buffer, sizeof(buffer));
+    ASSERT_TRUE(de.validate().ok());
+    ASSERT_TRUE(de.name().empty());
+    ASSERT_EQ(de.codes().length(), 0u);
+    ASSERT_EQ(de.kinds().length(), 0u);
+    ASSERT_EQ(de.entries().length(), 0u);
+    ASSERT_EQ(de.crc(), 0x77);
+}
+
+UTEST(struct_with_terminated_arrays, missing_terminator) {
+    const uint8_t buffer[] = {9, 0, 0x02, 0x01, 0x04};
+    RecordDe de(buffer, sizeof(buffer));
+    ASSERT_EXCEPTION(de.codes().length(), abf::BufferTooShort);
+    auto error = de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::BufferTooShort);
+    ASSERT_TRUE(error.path == "codes[1]");
+}
+
+UTEST(struct_with_terminated_arrays, item_equals_terminator) {
+    uint8_t buffer[32];
+    auto codes = record_ser();
+    codes.with_codes().get(1).set_data(0xFFFF);
+    ASSERT_EXCEPTION(codes.serialize(buffer, sizeof(buffer)), std::runtime_error);
+    auto kinds = record_ser();
+    kinds.with_kinds().get(1).set_data(Kind::End);
+    ASSERT_EXCEPTION(kinds.serialize(buffer, sizeof(buffer)), std::runtime_error);
+    auto entries = record_ser();
+    entries.with_entries().get(0).with_kind(Kind::End);
+    ASSERT_EXCEPTION(entries.serialize(buffer, sizeof(buffer)), std::runtime_error);
+
+    // item sharing only some bytes with sentinel is kept
+    auto partial = record_ser();
+    partial.with_codes().get(1).set_data(0x00FF);
+    auto serialized = partial.serialize();
+    RecordDe de(serialized.data(), serialized.size());
+    ASSERT_TRUE(de.validate().ok());
+    ASSERT_EQ(de.codes().length(), 2u);
+    ASSERT_EQ(de.codes().get(1).get_data(), 0x00FF);
+    ASSERT_EQ(de.crc(), 0x77);
+}
@@ -0,0 +1,46 @@
+#include <utest/utest.h>
+#include "struct_with_u24.h"
+
+using namespace struct_with_u24;
+
+UTEST_MAIN();
+
+// trailing u24 is read from heap buffer holding exactly its bytes
+template <typename TSer, typename TDe, typename TView>
+static int check_trailing_u24(const uint8_t (&expected)[4]) {
+    std::vector<uint8_t> heap(4);
+    uint8_t* buffer = heap.data();
+    TSer ser;
+    ser.with_a(1);
+    ser.with_b(0x020304);
+    if (ser.serialize(buffer) != 4 || std::memcmp(buffer, expected, 4) != 0) {
+        return 1;
+    }
+    TDe de(buffer, 4);
+    if (de.b() != 0x020304u) {
+        return 2;
+    }
+    TView view(buffer, 4);
+    if (view.b() != 0x020304u) {
+        return 3;
+    }
+    return 0;
+}
+
+UTEST(struct_with_u24, big_endian) {
+    const uint8_t expected[4] = {1, 0x02, 0x03, 0x04};
+    ASSERT_EQ((check_trailing_u24<MsgSer, MsgDe, MsgView>(expected)), 0);
+}
+
+UTEST(struct_with_u24, little_endian) {
+    const uint8_t expected[4] = {1, 0x04, 0x03, 0x02};
+    ASSERT_EQ((check_trailing_u24<LittleMsgSer, LittleMsgDe, LittleMsgView>(expected)), 0);
+}
+
+UTEST(struct_with_u24, truncated) {
+    std::vector<uint8_t> heap(3, 0);
+    MsgDe msg_de(heap.data(), heap.size());
+    ASSERT_EXCEPTION(msg_de.b(), abf::BufferTooShort);
+    MsgView msg_view(heap.data(), heap.size());
+    ASSERT_EXCEPTION(msg_view.b(), abf::BufferTooShort);
+}
@@ -0,0 +1,97 @@
+#include <utest/utest.h>
+#include "struct_with_validation.h"
+
+using namespace struct_with_validation;
+
+UTEST_MAIN();
+
+// magic, info_size, kind, version, number_key, number (2 bytes), count, samples (2 bytes each)
+static uint32_t serialize_frame(uint8_t* buffer) {
+    FrameSer frame_ser;
+    frame_ser.with_info().with_kind(Kind::Small);
+    frame_ser.with_info().with_version(1);
+    frame_ser.with_number().with_u16(0x0102);
+    frame_ser.with_samples().get(0).with_kind(Kind::Large);
+    frame_ser.with_samples().get(0).with_value(3);
+    frame_ser.with_samples().get(1).with_kind(Kind::Small);
+    frame_ser.with_samples().get(1).with_value(4);
+    return frame_ser.serialize(buffer);
+}
+
+UTEST(struct_with_validation, valid) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    ASSERT_EQ(size, 12u);
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.ok());
+    ASSERT_TRUE(error.path.empty());
+    ASSERT_EQ(frame_de.samples().get(1).value(), 4);
+}
+
+UTEST(struct_with_validation, constant_mismatch) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    buffer[0] = 0x5a;
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::ConstantMismatch);
+    ASSERT_STREQ(error.path.c_str(), "magic");
+}
+
+UTEST(struct_with_validation, size_mismatch) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    buffer[1] = 3;
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::SizeMismatch);
+    ASSERT_STREQ(error.path.c_str(), "info_size");
+}
+
+UTEST(struct_with_validation, unknown_enum_value) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    // kind of second sample
+    buffer[10] = 7;
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::UnknownEnumValue);
+    ASSERT_STREQ(error.path.c_str(), "samples[1].kind");
+}
+
+UTEST(struct_with_validation, unknown_view_key) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    buffer[4] = 9;
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::UnknownViewKey);
+    ASSERT_STREQ(error.path.c_str(), "number");
+}
+
+UTEST(struct_with_validation, dimension_past_buffer) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+    buffer[7] = 3;
+
+    FrameDe frame_de(buffer, size);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::BufferTooShort);
+    ASSERT_STREQ(error.path.c_str(), "samples[2].kind");
+}
+
+UTEST(struct_with_validation, truncated) {
+    uint8_t buffer[1024];
+    uint32_t size = serialize_frame(buffer);
+
+    FrameDe frame_de(buffer, 6);
+    auto error = frame_de.validate();
+    ASSERT_TRUE(error.kind == abf::ValidationErrorKind::BufferTooShort);
+    ASSERT_STREQ(error.path.c_str(), "number.u16");
+}
@@ -0,0 +1,106 @@
+#include <utest/utest.h>
+#include "struct_with_random_access.h"
+
+using namespace struct_with_random_access;
+
+UTEST_MAIN();
+
+static void fill_record(RecordSer& record_ser) {
+    record_ser.with_entry().with_name("entry");
+    record_ser.with_entry().with_flags(0x01);
+    record_ser.with_entry().with_extra(0x0102);
+    record_ser.with_entry().with_number().with_u16(0x0304);
+    record_ser.with_entry().with_values().get(0).set_data(0x0506);
+    record_ser.with_entry().with_values().get(1).set_data(0x0708);
+    record_ser.with_entry().with_tail(0x090A0B0C);
+    record_ser.with_trailer(0x0D);
+}
+
+UTEST(struct_without_exceptions, serde) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    uint8_t buffer[1024];
+    auto written = record_ser.serialize(buffer);
+    ASSERT_TRUE(written.has_value());
+    ASSERT_EQ(*written, 22u);
+
+    auto out = record_ser.serialize();
+    ASSERT_TRUE(out.has_value());
+    ASSERT_EQ(out->size(), 22u);
+
+    RecordDe record_de(buffer, *written);
+    auto name = record_de.entry()->name();
+    ASSERT_TRUE(name.has_value());
+    ASSERT_TRUE(*name == "entry");
+    auto tail = record_de.entry()->tail();
+    ASSERT_TRUE(tail.has_value());
+    ASSERT_EQ(*tail, 0x090A0B0Cu);
+    ASSERT_EQ(*record_de.entry()->number()->u16(), 0x0304);
+    ASSERT_EQ(*record_de.trailer(), 0x0D);
+}
+
+UTEST(struct_without_exceptions, not_set) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    record_ser.with_entry().with_values().get(2);
+    uint8_t buffer[1024];
+
+    auto written = record_ser.serialize(buffer);
+    ASSERT_FALSE(written.has_value());
+    ASSERT_TRUE(written.error() == abf::Error::NotSet);
+}
+
+UTEST(struct_without_exceptions, buffer_too_small) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    uint8_t buffer[1024];
+
+    auto written = record_ser.serialize(buffer, 10);
+    ASSERT_TRUE(written.error() == abf::Error::BufferTooSmall);
+    ASSERT_EQ(*record_ser.serialize(buffer, sizeof(buffer)), 22u);
+}
+
+UTEST(struct_without_exceptions, truncated) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    uint8_t buffer[1024];
+    uint32_t size = *record_ser.serialize(buffer);
+
+    RecordDe record_de(buffer, size - 7);
+    ASSERT_EQ(record_de.entry()->values()->get(0).get_data(), 0x0506);
+    // runtime deserializers record their errors
+    abf::clear_error();
+    ASSERT_EQ(record_de.entry()->values()->get(1).get_data(), 0);
+    ASSERT_TRUE(abf::last_error() == abf::Error::BufferTooShort);
+    auto tail = record_de.entry()->tail();
+    ASSERT_FALSE(tail.has_value());
+    ASSERT_TRUE(tail.error() == abf::Error::BufferTooShort);
+    auto trailer = record_de.trailer();
+    ASSERT_TRUE(trailer.error() == abf::Error::BufferTooShort);
+}
+
+UTEST(struct_without_exceptions, absent) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    record_ser.with_entry().with_flags(0x00);
+    uint8_t buffer[1024];
+    uint32_t size = *record_ser.serialize(buffer);
+
+    RecordDe record_de(buffer, size);
+    auto extra = record_de.entry()->extra();
+    ASSERT_TRUE(extra.error() == abf::Error::Absent);
+    ASSERT_EQ(*record_de.entry()->tail(), 0x090A0B0Cu);
+}
+
+UTEST(struct_without_exceptions, wrong_view_type) {
+    RecordSer record_ser;
+    fill_record(record_ser);
+    uint8_t buffer[1024];
+    uint32_t size = *record_ser.serialize(buffer);
+
+    RecordDe record_de(buffer, size);
+    auto number = record_de.entry()->number();
+    ASSERT_TRUE(number.has_value());
+    ASSERT_TRUE(number->u8().error() == abf::Error::WrongViewType);
+    ASSERT_EQ(*number->u16(), 0x0304);
+}
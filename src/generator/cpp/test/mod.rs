@@ -5,14 +5,17 @@ use super::*;
 static TEST_DIR: &str = "src/generator/cpp/test/cpp_tests";
 static THIS_DIR: &str = "src/generator/cpp/test";
 
-fn compile_cpp(cpp_file: &str, object_file: &str, include_dir: &str) {
+fn compile_cpp(cpp_file: &str, object_file: &str, include_dir: &str, exceptions: bool, sanitize: bool) {
     let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let exceptions_flag = String::from(if exceptions { "-fexceptions" } else { "-fno-exceptions" });
     let term_out = std::process::Command::new("g++")
+        .args(sanitize_flags(sanitize))
         .args(&[
             &format!("-I{}", TEST_DIR),
             &format!("-I{}", include_dir),
             &format!("-I{}/{}", pwd, THIS_DIR),
             "-std=c++20",
+            &exceptions_flag,
             "-c",
             &format!("{}/{}", TEST_DIR, cpp_file),
             "-o",
@@ -25,8 +28,31 @@ fn compile_cpp(cpp_file: &str, object_file: &str, include_dir: &str) {
     println!("{}", String::from_utf8(term_out.stderr).unwrap());
 }
 
+fn sanitize_flags(sanitize: bool) -> &'static [&'static str] {
+    if sanitize { &["-fsanitize=address", "-fno-omit-frame-pointer"] } else { &[] }
+}
+
 fn generate_test(buffer_file: &str, test_file: &str, generate: bool, big_endian: bool) {
-    let endian: String = if big_endian { "big".into() } else { "little".into() };
+    generate_test_with_endian(buffer_file, test_file, generate, Some(big_endian));
+}
+
+// Host endian is detected by compiler when not given.
+fn generate_test_with_endian(buffer_file: &str, test_file: &str, generate: bool, big_endian: Option<bool>) {
+    generate_test_with_options(buffer_file, test_file, generate, big_endian, true, false);
+}
+
+// Code generated without exceptions is compiled with them disabled.
+fn generate_test_without_exceptions(buffer_file: &str, test_file: &str, big_endian: bool) {
+    generate_test_with_options(buffer_file, test_file, true, Some(big_endian), false, false);
+}
+
+// Code is built with address sanitizer for host endian, so reads past end of buffer fail the test.
+fn generate_test_with_sanitizer(buffer_file: &str, test_file: &str) {
+    generate_test_with_options(buffer_file, test_file, true, None, true, true);
+}
+
+fn generate_test_with_options(buffer_file: &str, test_file: &str, generate: bool, big_endian: Option<bool>, exceptions: bool, sanitize: bool) {
+    let endian: Option<String> = big_endian.map(|b| if b { "big".into() } else { "little".into() });
     let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
     let test_file_noext = std::path::Path::new(test_file)
         .file_stem()
@@ -34,25 +60,24 @@ fn generate_test(buffer_file: &str, test_file: &str, generate: bool, big_endian:
         .to_str()
         .unwrap();
 
-    let test_out_dir = format!("{}/{}/{}_{}_endian", pwd, TEST_DIR, test_file_noext, endian);
+    let test_out_dir = format!("{}/{}/{}_{}_endian", pwd, TEST_DIR, test_file_noext, endian.as_deref().unwrap_or("host"));
 
     if generate {
         let _ = std::fs::remove_dir_all(&test_out_dir); // try remove folder
     }
 
     let buffer_file_path = format!("{}/{}/{}", pwd, TEST_DIR, buffer_file);
-    let source = std::fs::read_to_string(buffer_file_path)
-        .expect("could not read file");
 
     if generate {
-        generate_cpp(
-            interpet_memory(source)
-                .unwrap_or_else(|e| panic!("interpret failed: {}", e.to_string())),
+        generate_code(
+            interpet_memory(&buffer_file_path)
+                .unwrap_or_else(|e| panic!("interpret failed: {}", render_diagnostics(&e))),
             &Args {
                 protofile: buffer_file.into(),
                 language: Language::Cpp,
-                endian: endian,
+                endian,
                 output_dir: test_out_dir.clone(),
+                no_exceptions: !exceptions,
             },
         );
     }
@@ -62,12 +87,15 @@ fn generate_test(buffer_file: &str, test_file: &str, generate: bool, big_endian:
     compile_cpp(
         &format!("{}.cpp", test_file_noext),
         &object_file,
-        &test_out_dir
+        &test_out_dir,
+        exceptions,
+        sanitize
     );
 
     let out = format!("{}/{}", test_out_dir, test_file_noext);
 
     let res = std::process::Command::new("g++")
+        .args(sanitize_flags(sanitize))
         .args(&[&object_file,
                 "-o",
                 &out])
@@ -140,4 +140,234 @@ fn struct_with_size_view() {
     );
 }
 
+// floats are swapped on little endian host
+#[test]
+fn struct_with_floats() {
+    generate_test(
+        "struct_with_floats.abf", 
+        "struct_with_floats.cpp", 
+        true, 
+        false
+    );
+}
 
+// containers are swapped on little endian host
+#[test]
+fn struct_with_bit_fields() {
+    generate_test(
+        "struct_with_bit_fields.abf", 
+        "struct_with_bit_fields.cpp", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_lsb_bit_fields() {
+    generate_test(
+        "struct_with_lsb_bit_fields.abf", 
+        "struct_with_lsb_bit_fields.cpp", 
+        true, 
+        false
+    );
+}
+
+// u16 member is swapped on little endian host
+#[test]
+fn struct_with_strings() {
+    generate_test(
+        "struct_with_strings.abf", 
+        "struct_with_strings.cpp", 
+        true, 
+        false
+    );
+}
+
+// multibyte checksums are swapped on little endian host
+#[test]
+fn struct_with_checksum() {
+    generate_test(
+        "struct_with_checksum.abf", 
+        "struct_with_checksum.cpp", 
+        true, 
+        false
+    );
+}
+
+// u16 members are swapped on little endian host
+#[test]
+fn struct_with_optional() {
+    generate_test(
+        "struct_with_optional.abf", 
+        "struct_with_optional.cpp", 
+        true, 
+        false
+    );
+}
+
+// u16 and u32 members are swapped on little endian host
+#[test]
+fn struct_with_import() {
+    generate_test(
+        "struct_with_import.abf", 
+        "struct_with_import.cpp", 
+        true, 
+        false
+    );
+}
+
+// only big endian members are swapped on little endian host
+#[test]
+fn struct_with_mixed_endian() {
+    generate_test(
+        "struct_with_mixed_endian.abf", 
+        "struct_with_mixed_endian.cpp", 
+        true, 
+        false
+    );
+}
+
+// endian of host is detected when compiling generated code
+#[test]
+fn struct_with_natives_host_endian() {
+    generate_test_with_endian(
+        "struct_with_natives.abf", 
+        "struct_with_natives.cpp", 
+        true, 
+        None
+    );
+}
+
+#[test]
+fn struct_with_mixed_endian_host_endian() {
+    generate_test_with_endian(
+        "struct_with_mixed_endian.abf", 
+        "struct_with_mixed_endian.cpp", 
+        true, 
+        None
+    );
+}
+
+#[test]
+fn struct_with_message_view() {
+    generate_test(
+        "struct_with_message_view.abf", 
+        "struct_with_message_view.cpp", 
+        true, 
+        false
+    );
+}
+
+// members are resolved on demand, so any may be read first
+#[test]
+fn struct_with_random_access() {
+    generate_test(
+        "struct_with_random_access.abf", 
+        "struct_with_random_access.cpp", 
+        true, 
+        false
+    );
+}
+
+// deserializers given buffer length do not read past its end
+#[test]
+fn struct_with_bounds() {
+    generate_test(
+        "struct_with_random_access.abf", 
+        "struct_with_bounds.cpp", 
+        true, 
+        false
+    );
+}
+
+// validation walks whole message and names the first failing member
+#[test]
+fn struct_with_validation() {
+    generate_test(
+        "struct_with_validation.abf", 
+        "struct_with_validation.cpp", 
+        true, 
+        false
+    );
+}
+
+// errors are returned as values when compiled with -fno-exceptions
+#[test]
+fn struct_without_exceptions() {
+    generate_test_without_exceptions(
+        "struct_with_random_access.abf", 
+        "struct_without_exceptions.cpp", 
+        false
+    );
+}
+
+// sizes known from schema are available at compile time
+#[test]
+fn struct_with_size_constants() {
+    generate_test(
+        "struct_with_message_view.abf", 
+        "struct_with_size_constants.cpp", 
+        true, 
+        false
+    );
+}
+
+// serialization into caller buffer never writes past its capacity
+#[test]
+fn struct_with_capacity() {
+    generate_test(
+        "struct_with_random_access.abf", 
+        "struct_with_capacity.cpp", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_bounded_arrays() {
+    generate_test(
+        "struct_with_bounded_arrays.abf", 
+        "struct_with_bounded_arrays.cpp", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_rest_array() {
+    generate_test(
+        "struct_with_rest_array.abf", 
+        "struct_with_rest_array.cpp", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_terminated_arrays() {
+    generate_test(
+        "struct_with_terminated_arrays.abf", 
+        "struct_with_terminated_arrays.cpp", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn struct_with_nested_references() {
+    generate_test(
+        "struct_with_nested_references.abf", 
+        "struct_with_nested_references.cpp", 
+        true, 
+        false
+    );
+}
+
+// host endian is detected, so u24 is read both swapped and as is
+#[test]
+fn struct_with_u24() {
+    generate_test_with_sanitizer(
+        "struct_with_u24.abf",
+        "struct_with_u24.cpp"
+    );
+}
@@ -4,34 +4,51 @@ pub fn generate_view_deserializer(m: &ViewMemory, writer: &mut Writer) {
     writer.write(&format!("class {}", m.deserializer_typename()));
     writer.scope_in();
     writer.public();
+    generate_size_constants(m, writer);
     generate_ctor(m, writer);
     for i in 0..m.types.len() {
         generate_get_method(m, i, writer);
     }
     generate_deserialized(m, writer);
-    generate_set_source(writer);
+    generate_set_source(m, writer);
     generate_source_set(writer);
     generate_end(m, writer);
     generate_init(writer);
+    generate_set_type_id(m, writer);
+    generate_validate(m, writer);
     writer.private();
     generate_check_deserialize(writer);
     generate_union(m, writer);
-    writer.write_line("uint8_t* source_;");
+    writer.write_line("const uint8_t* source_;");
+    writer.write_line("const uint8_t* limit_;");
     writer.write_line("Types types_;");
     writer.write_line("bool deserialized_;");
     generate_constant(m,  writer);
     writer.scope_out(true);
 }
 
-fn generate_set_source(writer: &mut Writer) {
-    writer.write_with_offset("void _set_source(uint8_t *source)");
+// Type may be given by struct embedding the owning one before source is known.
+fn generate_set_source(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("void _set_source(const uint8_t *source, const uint8_t *limit)");
     writer.scope_in();
     writer.write_line("source_ = source;");
+    writer.write_line("limit_ = limit;");
+    writer.write_with_offset("if (deserialized_)");
+    writer.scope_in();
+    writer.write_with_offset("switch (type_id_)");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("case {}: types_.{}._set_source(source_, limit_); break;",
+            t.constant.get_value(),
+            t.variable()));
+    }
+    writer.scope_out(false);
+    writer.scope_out(false);
     writer.scope_out(false);
 }
 
 fn generate_end(m: &ViewMemory, writer: &mut Writer) {
-    writer.write_with_offset("uint8_t* _end()");
+    writer.write_with_offset("const uint8_t* _end()");
     writer.scope_in();
     writer.write_line("_check_deserialized();");
     writer.write_with_offset("switch (type_id_)");
@@ -51,15 +68,65 @@ fn generate_init(writer: &mut Writer) {
     writer.write_with_offset("void init()");
     writer.scope_in();
     writer.write_line("source_ = nullptr;");
+    writer.write_line("limit_ = nullptr;");
     writer.write_line("deserialized_ = false;");
     writer.write_line("type_id_ = 0;");
     writer.scope_out(false);
 }
 
+// Alternative given by key of owning struct is deserialized without being accessed.
+fn generate_set_type_id(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("void _set_type_id({} type_id)", m.get_index_typename().native_typename()));
+    writer.scope_in();
+    writer.write_line("_check_deserialized();");
+    writer.write_line("if (deserialized_) return;");
+    writer.write_with_offset("switch (type_id)");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("case {}: types_.{}.init(); types_.{}._set_source(source_, limit_); break;",
+            t.constant.get_value(),
+            t.variable(),
+            t.variable()));
+    }
+    writer.write_line("default: return;");
+    writer.scope_out(false);
+    writer.write_line("type_id_ = type_id;");
+    writer.write_line("deserialized_ = true;");
+    writer.scope_out(false);
+}
+
+// Alternative is known once its key is read or it is accessed, key of no alternative is unknown.
+fn generate_validate(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("abf::ValidationError validate()");
+    writer.scope_in();
+    writer.write_line("return _validate();");
+    writer.scope_out(false);
+    writer.write_with_offset("abf::ValidationError _validate()");
+    writer.scope_in();
+    writer.write_line("_check_deserialized();");
+    writer.write_with_offset("if (!deserialized_)");
+    writer.scope_in();
+    writer.write_line("return abf::ValidationError{abf::ValidationErrorKind::Unresolved};");
+    writer.scope_out(false);
+    writer.write_with_offset("switch (type_id_)");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("case {}: return abf::prefix_path(types_.{}._validate(), \"{}\");",
+            t.constant.get_value(),
+            t.variable(),
+            t.variable()));
+    }
+    writer.scope_out(false);
+    writer.write_line("return abf::ValidationError{abf::ValidationErrorKind::UnknownViewKey};");
+    writer.scope_out(false);
+}
+
 fn generate_ctor(m: &ViewMemory, writer: &mut Writer) {
-    writer.write_line(&format!("{}() : source_(nullptr), types_(), deserialized_(false), type_id_(), type_id_deserializer_(nullptr) {{}}", 
+    writer.write_line(&format!("{}() : source_(nullptr), limit_(nullptr), types_(), deserialized_(false), type_id_(), type_id_deserializer_(nullptr) {{}}", 
+        m.deserializer_typename()));
+    writer.write_line(&format!("{}(const uint8_t *source) : source_(source), limit_(nullptr), types_(), deserialized_(false), type_id_(), type_id_deserializer_(nullptr) {{}}", 
         m.deserializer_typename()));
-    writer.write_line(&format!("{}(uint8_t *source) : source_(source), types_(), deserialized_(false), type_id_(), type_id_deserializer_(nullptr) {{}}", 
+    writer.write_line(&format!("{}(const uint8_t *data, size_t len) : source_(data), limit_(data + len), types_(), deserialized_(false), type_id_(), type_id_deserializer_(nullptr) {{}}", 
         m.deserializer_typename()));
 }
 
@@ -118,15 +185,17 @@ fn generate_get_method(m: &ViewMemory, i: usize, writer: &mut Writer) {
     }
 }
 
-fn generate_get_body(m: &ViewMemory, i: usize, writer: &mut Writer) {
+// Value returned on error is given by caller, nested deserializer is returned along with it.
+fn generate_get_body(m: &ViewMemory, i: usize, error_value: &str, writer: &mut Writer) {
     let t = &m.types[i];
+    writer.write_line("ABF_ERROR_SCOPE;");
     writer.write_line("_check_deserialized();");
 
     writer.write_with_offset(&format!("if (deserialized_)"));
     writer.scope_in();
     writer.write_with_offset(&format!("if (type_id_ != {})", m.types[i].constant.get_value()));
     writer.scope_in();
-    writer.write_line(&format!("throw std::runtime_error(\"Already deserialized\");"));
+    writer.write_line(&raise("WrongViewType", "std::runtime_error(\"Already deserialized\")", error_value));
     writer.scope_out(false);
     writer.scope_out(false);
     writer.write_with_offset("else");
@@ -136,28 +205,28 @@ fn generate_get_body(m: &ViewMemory, i: usize, writer: &mut Writer) {
     
     writer.write_line(&format!("type_id_ = {};", t.constant.get_value()));
     writer.write_line("deserialized_ = true;");
-    writer.write_line(&format!("types_.{}._set_source(source_);", t.variable()));
+    writer.write_line(&format!("types_.{}._set_source(source_, limit_);", t.variable()));
 }
 
 fn generate_get_native(m: &ViewMemory, i: usize, writer: &mut Writer) {
     let t = &m.types[i].memory;
-    writer.write_with_offset(&format!("{} {}()",
+    writer.write_with_offset(&format!("ABF_RESULT({}) {}()",
         t.native_typename(),
         t.variable()));
     writer.scope_in();
-    generate_get_body(m, i, writer);
-    writer.write_line(&format!("return types_.{}.get_data();", t.variable()));
+    generate_get_body(m, i, "abf::Error::WrongViewType", writer);
+    writer.write_line(&format!("ABF_RETURN(types_.{}.get_data());", t.variable()));
     writer.scope_out(false);
 }
 
 fn generate_get_non_native(m: &ViewMemory, i: usize, writer: &mut Writer) {
     let t = &m.types[i].memory;
-    writer.write_with_offset(&format!("{}& {}()",
+    writer.write_with_offset(&format!("ABF_RESULT({}&) {}()",
         t.deserializer_typename(),
         t.variable()));
     writer.scope_in();
-    generate_get_body(m, i, writer);
-    writer.write_line(&format!("return types_.{};", t.variable()));
+    generate_get_body(m, i, &format!("{{types_.{}, abf::Error::WrongViewType}}", t.variable()), writer);
+    writer.write_line(&format!("ABF_RETURN(types_.{});", t.variable()));
     writer.scope_out(false);
 }
 
@@ -4,12 +4,14 @@ pub fn generate_view_serializer(m: &ViewMemory, writer: &mut Writer) {
     writer.write(&format!("class {}", m.serializer_typename()));
     writer.scope_in();
     writer.public();
+    generate_size_constants(m, writer);
     generate_ctor(m, writer);
     for i in 0..m.types.len() {
         generate_with_method(m, i, writer);
     }
     generate_serialize(m, writer);
     generate_serialize_into_vector(writer);
+    generate_serialize_with_capacity(writer);
     generate_size(m, writer);
     generate_init(writer);
     generate_set_typypeid_serializer(m, writer);
@@ -59,11 +61,12 @@ fn generate_set_typypeid_serializer(m: &ViewMemory, writer: &mut Writer) {
 }
 
 fn generate_serialize(m: &ViewMemory, writer: &mut Writer) {
-    writer.write_with_offset("uint32_t serialize(uint8_t* dest)");
+    writer.write_with_offset("ABF_RESULT(uint32_t) serialize(uint8_t* dest)");
     writer.scope_in();
+    writer.write_line("ABF_ERROR_SCOPE;");
     writer.write_with_offset("if (!set_)");
     writer.scope_in();
-    writer.write_line("throw std::runtime_error(\"Not set\");");
+    writer.write_line(&raise("NotSet", "std::runtime_error(\"Not set\")", "abf::Error::NotSet"));
     writer.scope_out(false);
 
     writer.write_with_offset("if (type_id_setter_ != nullptr)");
@@ -74,11 +77,11 @@ fn generate_serialize(m: &ViewMemory, writer: &mut Writer) {
     writer.write_with_offset("switch (type_id_)");
     writer.scope_in();
     for t in &m.types {
-        writer.write_line(&format!("case {}: return types_.{}.serialize(dest);", t.constant.get_value(), t.variable()));
+        writer.write_line(&format!("case {}: ABF_RETURN(abf::value_of(types_.{}.serialize(dest)));", t.constant.get_value(), t.variable()));
     }
     writer.scope_out(false);
 
-    writer.write_line("throw std::runtime_error(\"Unknown type id\");");
+    writer.write_line(&raise("UnknownViewType", "std::runtime_error(\"Unknown type id\")", "abf::Error::UnknownViewType"));
     writer.scope_out(false);
 }
 
@@ -87,12 +90,7 @@ fn generate_size(m: &ViewMemory, writer: &mut Writer) {
     writer.scope_in();
     writer.write_with_offset("if (!set_)");
     writer.scope_in();
-    writer.write_line("throw std::runtime_error(\"Not set\");");
-    writer.scope_out(false);
-
-    writer.write_with_offset("if (type_id_setter_ != nullptr)");
-    writer.scope_in();
-    writer.write_line(&format!("type_id_setter_->set_{}(type_id_);", m.get_index_typename().name()));
+    writer.write_line(&raise("NotSet", "std::runtime_error(\"Not set\")", "0"));
     writer.scope_out(false);
 
     writer.write_with_offset("switch (type_id_)");
@@ -102,7 +100,7 @@ fn generate_size(m: &ViewMemory, writer: &mut Writer) {
     }
     writer.scope_out(false);
 
-    writer.write_line("throw std::runtime_error(\"Unknown type id\");");
+    writer.write_line(&raise("UnknownViewType", "std::runtime_error(\"Unknown type id\")", "0"));
     writer.scope_out(false);
 }
 
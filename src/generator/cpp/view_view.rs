@@ -0,0 +1,75 @@
+use super::*;
+use super::struct_view::{item_view, view_typename};
+
+// Type of view is known when its key member is present, otherwise any
+// alternative may be read and size is known only when all have the same.
+pub fn generate_view_view(m: &ViewMemory, writer: &mut Writer) {
+    let typename = view_typename(&m.name());
+    let index_typename = m.get_index_typename().native_typename();
+    writer.write(&format!("class {}", typename));
+    writer.scope_in();
+    writer.public();
+    writer.write_line(&format!("{}(const uint8_t* source, uint32_t size) : source_(source), size_(size), type_id_(), typed_(false) {{}}",
+        typename));
+    writer.write_line(&format!("{}(const uint8_t* source, uint32_t size, {} type_id) : source_(source), size_(size), type_id_(type_id), typed_(true) {{}}",
+        typename,
+        index_typename));
+    for t in &m.types {
+        generate_get_method(t, writer);
+    }
+    generate_size(m, writer);
+    writer.private();
+    generate_check_type(&index_typename, writer);
+    writer.write_line("const uint8_t* source_;");
+    writer.write_line("uint32_t size_;");
+    writer.write_line(&format!("{} type_id_;", index_typename));
+    writer.write_line("bool typed_;");
+    writer.scope_out(true);
+}
+
+fn generate_get_method(t: &ViewPosibilityMemory, writer: &mut Writer) {
+    let (typename, value) = item_view(&t.memory, None, "source_", "size_");
+    writer.write_with_offset(&format!("{} {}() const", typename, t.variable()));
+    writer.scope_in();
+    writer.write_line(&format!("_check_type({});", t.constant.get_value()));
+    writer.write_line(&format!("return {};", value));
+    writer.scope_out(false);
+}
+
+fn generate_size(m: &ViewMemory, writer: &mut Writer) {
+    if let Some(size) = m.exact_size() {
+        writer.write_with_offset("static constexpr uint32_t _size()");
+        writer.scope_in();
+        writer.write_line(&format!("return {};", size));
+        writer.scope_out(false);
+        return
+    }
+    writer.write_with_offset("uint32_t _size() const");
+    writer.scope_in();
+    writer.write_with_offset("if (!typed_)");
+    writer.scope_in();
+    writer.write_line(&raise("UnknownViewType", "std::runtime_error(\"View type unknown\")", "0"));
+    writer.scope_out(false);
+    writer.write_with_offset("switch (type_id_)");
+    writer.scope_in();
+    for t in &m.types {
+        let size = match t.memory.exact_size() {
+            Some(size) => size.to_string(),
+            None => format!("{}._size()", item_view(&t.memory, None, "source_", "size_").1),
+        };
+        writer.write_line(&format!("case {}: return {};", t.constant.get_value(), size));
+    }
+    writer.scope_out(false);
+    writer.write_line(&raise("UnknownViewType", "std::runtime_error(\"Unknown view type\")", "0"));
+    writer.scope_out(false);
+}
+
+fn generate_check_type(index_typename: &str, writer: &mut Writer) {
+    writer.write_with_offset(&format!("void _check_type({} type_id) const", index_typename));
+    writer.scope_in();
+    writer.write_with_offset("if (typed_ && type_id_ != type_id)");
+    writer.scope_in();
+    writer.write_line(&raise("WrongViewType", "std::runtime_error(\"Wrong view type\")", ""));
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
@@ -1,28 +1,121 @@
 use super::*;
 
+mod c;
 mod cpp;
+mod rust;
+mod python;
 mod utils;
 mod to_string;
+mod writer;
+
+pub struct Writer {
+    begin_spaces: usize,
+    buffer: String,
+    filename: String,
+}
 
 pub enum GeneratorError {
     InternalError(String),
+    UnsupportedFeature(String),
 }
 
 pub fn generate(mi: MemoryImage, args: &Args) -> Result<(), GeneratorError> {
-    let big_endian_on_machine = match args.endian.as_str() {
-        "big" => true,
-        "little" => false,
-        _ => panic!("endian can be big or little")
+    let big_endian_on_machine = match args.endian.as_deref() {
+        Some("big") => Some(true),
+        Some("little") => Some(false),
+        Some(_) => panic!("endian can be big or little"),
+        None => None,
     };
-    match args.language {
-        Language::Cpp => cpp::generate(&mi.memory_decl, mi.big_endian != big_endian_on_machine, args),
-        _ => {
-            return Err(GeneratorError::InternalError(format!(
-                "Language {} not supported",
-                args.language.to_string()
-            )))
+    // only C++ serializers are parametrized by endian
+    if !matches!(args.language, Language::Cpp) && has_endian_override(&mi.memory_decl, mi.big_endian) {
+        return Err(GeneratorError::UnsupportedFeature(format!(
+            "Endian of struct or member cannot be overridden in language {}",
+            args.language
+        )));
+    }
+    if !matches!(args.language, Language::Cpp) && has_terminated_array(&mi.memory_decl) {
+        return Err(GeneratorError::UnsupportedFeature(format!(
+            "Terminated arrays are not supported in language {}",
+            args.language
+        )));
+    }
+    // bound is checked only by C++ serializers and deserializers
+    if !matches!(args.language, Language::Cpp) && has_bounded_array(&mi.memory_decl) {
+        return Err(GeneratorError::UnsupportedFeature(format!(
+            "Bounded arrays are not supported in language {}",
+            args.language
+        )));
+    }
+    // C counts items of array running to end of message by bytes left, other languages cannot count them
+    if !matches!(args.language, Language::Cpp) && has_rest_array(&mi.memory_decl, matches!(args.language, Language::C)) {
+        return Err(GeneratorError::UnsupportedFeature(match args.language {
+            Language::C => "Arrays running to end of message must have items of fixed size in language C".into(),
+            _ => format!("Arrays running to end of message are not supported in language {}", args.language),
+        }));
+    }
+    if !matches!(args.language, Language::Cpp) && has_nested_reference(&mi.memory_decl) {
+        return Err(GeneratorError::UnsupportedFeature(format!(
+            "References to members of other structs are not supported in language {}",
+            args.language
+        )));
+    }
+    for module in &mi.modules {
+        match args.language {
+            Language::Cpp => cpp::generate(&mi.memory_decl, module, mi.big_endian, big_endian_on_machine, args),
+            Language::Rust => rust::generate(&mi.memory_decl, module, mi.big_endian, args),
+            Language::Python => python::generate(&mi.memory_decl, module, mi.big_endian, args),
+            Language::C => c::generate(&mi.memory_decl, module, mi.big_endian, args),
+            _ => {
+                return Err(GeneratorError::InternalError(format!(
+                    "Language {} not supported",
+                    args.language
+                )))
+            }
         }
     }
     Ok(())
 }
 
+
+// Endian stated again with value of schema endian is not an override.
+fn has_endian_override(m: &[MemoryDeclaration], big_endian: bool) -> bool {
+    m.iter().any(|md| match &md.memory.memory {
+        MemoryType::Struct(s) => s.borrow().fields.iter().any(|f| {
+            let memory = f.memory.borrow();
+            memory.big_endian.is_some_and(|b| b != big_endian) && !memory.memory.is_struct() && !memory.memory.is_view()
+        }),
+        _ => false,
+    })
+}
+
+fn has_terminated_array(m: &[MemoryDeclaration]) -> bool {
+    m.iter().any(|md| match &md.memory.memory {
+        MemoryType::Struct(s) => s.borrow().fields.iter().any(|f| f.memory.borrow().array_size.is_until()),
+        _ => false,
+    })
+}
+
+fn has_bounded_array(m: &[MemoryDeclaration]) -> bool {
+    m.iter().any(|md| match &md.memory.memory {
+        MemoryType::Struct(s) => s.borrow().fields.iter().any(|f| f.memory.borrow().array_size.is_bounded()),
+        _ => false,
+    })
+}
+
+// Array of fixed size items may be allowed, its length is bytes left divided by item size.
+fn has_rest_array(m: &[MemoryDeclaration], sized_items_allowed: bool) -> bool {
+    m.iter().any(|md| match &md.memory.memory {
+        MemoryType::Struct(s) => s.borrow().fields.iter().any(|f| {
+            let memory = f.memory.borrow();
+            memory.array_size.is_rest() && !(sized_items_allowed && memory.memory.exact_size().is_some())
+        }),
+        _ => false,
+    })
+}
+
+fn has_nested_reference(m: &[MemoryDeclaration]) -> bool {
+    m.iter().any(|md| match &md.memory.memory {
+        MemoryType::Struct(s) => !s.borrow().get_nested_references().is_empty(),
+        _ => false,
+    })
+}
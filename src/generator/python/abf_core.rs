@@ -0,0 +1,576 @@
+pub static SOURCE: &str = "
+import enum
+import struct
+
+BIG_ENDIAN = <<BIG_ENDIAN>>
+# Version of compiler which generated this code.
+COMPILER_VERSION = \"<<COMPILER_VERSION>>\"
+
+_BYTEORDER = 'big' if BIG_ENDIAN else 'little'
+
+
+class Error(Exception):
+    pass
+
+
+class Native:
+    def __init__(self, size, signed=False, boolean=False):
+        self._size = size
+        self._signed = signed
+        self._boolean = boolean
+
+    def default(self):
+        return None
+
+    def size(self, value):
+        return self._size
+
+    def write(self, value):
+        if value is None:
+            raise Error('value not set')
+        try:
+            return int(value).to_bytes(self._size, _BYTEORDER, signed=self._signed)
+        except OverflowError:
+            raise Error(f'value {value} does not fit into {self._size} bytes') from None
+
+    def read(self, data, offset, select=None, reference=None):
+        end = offset + self._size
+        if end > len(data):
+            raise Error('source too short')
+        value = int.from_bytes(data[offset:end], _BYTEORDER, signed=self._signed)
+        if self._boolean:
+            value = value != 0
+        return value, end
+
+
+class Float:
+    def __init__(self, size):
+        self._size = size
+        self._format = ('>' if BIG_ENDIAN else '<') + ('f' if size == 4 else 'd')
+
+    def default(self):
+        return None
+
+    def size(self, value):
+        return self._size
+
+    def write(self, value):
+        if value is None:
+            raise Error('value not set')
+        return struct.pack(self._format, value)
+
+    def read(self, data, offset, select=None, reference=None):
+        end = offset + self._size
+        if end > len(data):
+            raise Error('source too short')
+        return struct.unpack(self._format, data[offset:end])[0], end
+
+
+BOOL = Native(1, boolean=True)
+U8 = Native(1)
+U16 = Native(2)
+U24 = Native(3)
+U32 = Native(4)
+U64 = Native(8)
+I8 = Native(1, signed=True)
+I16 = Native(2, signed=True)
+I32 = Native(4, signed=True)
+I64 = Native(8, signed=True)
+F32 = Float(4)
+F64 = Float(8)
+
+
+class Constant:
+    def __init__(self, native, value):
+        self._native = native
+        self._value = value
+
+    def default(self):
+        return self._value
+
+    def size(self, value):
+        return self._native.size(self._value)
+
+    def write(self, value):
+        return self._native.write(self._value)
+
+    def read(self, data, offset, select=None, reference=None):
+        return self._native.read(data, offset)
+
+
+# Members of bit field group share container, the member closing the group
+# accounts for its bytes. Struct packs values of the group and writes container.
+class Bits:
+    def __init__(self, container, shift, bits, signed=False, boolean=False, closes_group=False):
+        self._container = container
+        self._shift = shift
+        self._bits = bits
+        self._signed = signed
+        self._boolean = boolean
+        self.closes_group = closes_group
+
+    def default(self):
+        return None
+
+    def size(self, value):
+        return self._container.size(None) if self.closes_group else 0
+
+    def pack(self, container, value):
+        if value is None:
+            raise Error('value not set')
+        value = int(value)
+        low, high = (-(1 << (self._bits - 1)), (1 << (self._bits - 1)) - 1) if self._signed else (0, (1 << self._bits) - 1)
+        if not low <= value <= high:
+            raise Error(f'value {value} does not fit into {self._bits} bits')
+        return container | ((value & ((1 << self._bits) - 1)) << self._shift)
+
+    def write(self, container):
+        return self._container.write(container)
+
+    def read(self, data, offset, select=None, reference=None):
+        container, end = self._container.read(data, offset)
+        value = (container >> self._shift) & ((1 << self._bits) - 1)
+        if self._signed and value >> (self._bits - 1):
+            value -= 1 << self._bits
+        if self._boolean:
+            value = value != 0
+        return value, end if self.closes_group else offset
+
+
+# Member whose value is derived from another member of the same struct right before write.
+class Reference:
+    def __init__(self, member, native):
+        self.member = member
+        self._native = native
+
+    def default(self):
+        return None
+
+    def size(self, value):
+        return self._native.size(value)
+
+    def write(self, value):
+        return self._native.write(value)
+
+    def read(self, data, offset, select=None, reference=None):
+        return self._native.read(data, offset)
+
+
+class ViewKey(Reference):
+    def evaluate(self, codec, value):
+        if value.type_id is None:
+            raise Error(f'view {self.member} not set')
+        return value.type_id
+
+
+class Dimension(Reference):
+    def evaluate(self, codec, value):
+        return codec.length(value)
+
+
+class SizeOf(Reference):
+    def evaluate(self, codec, value):
+        return codec.size(value)
+
+
+# CRC-8/SMBUS: poly 0x07, init 0x00.
+def crc8(data):
+    crc = 0
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x07) & 0xFF if crc & 0x80 else (crc << 1) & 0xFF
+    return crc
+
+
+# CRC-16/CCITT-FALSE: poly 0x1021, init 0xFFFF.
+def crc16_ccitt(data):
+    crc = 0xFFFF
+    for byte in data:
+        crc ^= byte << 8
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x1021) & 0xFFFF if crc & 0x8000 else (crc << 1) & 0xFFFF
+    return crc
+
+
+# CRC-32/ISO-HDLC: reflected poly 0xEDB88320, init and final xor 0xFFFFFFFF.
+def crc32(data):
+    crc = 0xFFFFFFFF
+    for byte in data:
+        crc ^= byte
+        for _ in range(8):
+            crc = (crc >> 1) ^ 0xEDB88320 if crc & 1 else crc >> 1
+    return crc ^ 0xFFFFFFFF
+
+
+# Additive sum of bytes, truncated to width of checksum member by codec.
+def checksum_sum(data):
+    return sum(data)
+
+
+# Member computed over bytes of members first..last after they are written.
+class Checksum:
+    def __init__(self, algorithm, first, last, native):
+        self._algorithm = algorithm
+        self.first = first
+        self.last = last
+        self._native = native
+
+    def default(self):
+        return None
+
+    def compute(self, data):
+        return self._algorithm(data) & ((1 << (8 * self._native.size(None))) - 1)
+
+    def size(self, value):
+        return self._native.size(value)
+
+    def write(self, value):
+        return bytes(self._native.size(value))
+
+    def read(self, data, offset, select=None, reference=None):
+        return self._native.read(data, offset)
+
+
+# Member is present only when its flag member, or one bit of it, is set.
+class Optional:
+    def __init__(self, flag, bit, item):
+        self.flag = flag
+        self._bit = bit
+        self._item = item
+
+    def default(self):
+        return None
+
+    def present(self, flag):
+        if self._bit is None:
+            return bool(flag)
+        return bool((flag >> self._bit) & 1)
+
+    def size(self, value):
+        return self._item.size(value)
+
+    def write(self, value):
+        return self._item.write(value)
+
+    def read(self, data, offset, select=None, reference=None):
+        if not self.present(reference):
+            return None, offset
+        return self._item.read(data, offset, select)
+
+
+class Array:
+    def __init__(self, item, length=None):
+        self._item = item
+        self._length = length
+
+    def default(self):
+        return []
+
+    def _check(self, value):
+        if value is None:
+            raise Error('array not set')
+        if self._length is not None and len(value) != self._length:
+            raise Error(f'array expects {self._length} items, got {len(value)}')
+
+    def length(self, value):
+        self._check(value)
+        return len(value)
+
+    def size(self, value):
+        self._check(value)
+        return sum(self._item.size(item) for item in value)
+
+    def write(self, value):
+        self._check(value)
+        return b''.join(self._item.write(item) for item in value)
+
+    # Length comes from the exact size, a dimension member or the number of selected items.
+    def read(self, data, offset, select=None, reference=None):
+        length = self._length
+        if length is None:
+            length = reference
+        if length is None and select is not None:
+            length = len(select)
+        if length is None:
+            raise Error('unknown length of dynamic array')
+        items = []
+        for i in range(length):
+            item, offset = self._item.read(data, offset, select[i] if select else None)
+            items.append(item)
+        return items, offset
+
+
+# Fixed size string is padded with NUL bytes, dynamic string takes exactly its length.
+class String:
+    def __init__(self, length=None):
+        self._length = length
+
+    def default(self):
+        return None
+
+    def _encode(self, value):
+        if value is None:
+            raise Error('string not set')
+        data = value.encode()
+        if self._length is not None and len(data) > self._length:
+            raise Error(f'string of {len(data)} bytes does not fit into {self._length} bytes')
+        return data
+
+    def length(self, value):
+        return len(self._encode(value))
+
+    def size(self, value):
+        return self._length if self._length is not None else len(self._encode(value))
+
+    def write(self, value):
+        return self._encode(value).ljust(self._length or 0, b'\\0')
+
+    def read(self, data, offset, select=None, reference=None):
+        length = self._length if self._length is not None else reference
+        if length is None:
+            raise Error('unknown length of dynamic string')
+        end = offset + length
+        if end > len(data):
+            raise Error('source too short')
+        value = data[offset:end]
+        if self._length is not None:
+            value = value.split(b'\\0', 1)[0]
+        try:
+            return value.decode(), end
+        except UnicodeDecodeError:
+            raise Error('invalid string') from None
+
+
+class Type:
+    def to_bytes(self):
+        return type(self).write(self)
+
+    @classmethod
+    def from_bytes(cls, data, select=None):
+        value, _ = cls.read(bytes(data), 0, select)
+        return value
+
+
+# Structs and views are values compared by their members.
+class Compound(Type):
+    @classmethod
+    def default(cls):
+        return cls()
+
+    def __eq__(self, other):
+        return type(self) is type(other) and vars(self) == vars(other)
+
+
+class Struct(Compound):
+    MEMBERS = ()
+    # Checksum members in order of computation, covered checksums first.
+    CHECKSUMS = ()
+
+    def __init__(self, **values):
+        for name, codec in self.MEMBERS:
+            setattr(self, name, codec.default())
+        for name, value in values.items():
+            if name not in self._names():
+                raise TypeError(f'{type(self).__name__} has no member {name}')
+            setattr(self, name, value)
+
+    def __repr__(self):
+        members = ', '.join(f'{name}={getattr(self, name)!r}' for name, _ in self.MEMBERS)
+        return f'{type(self).__name__}({members})'
+
+    @classmethod
+    def _names(cls):
+        return [name for name, _ in cls.MEMBERS]
+
+    @classmethod
+    def _codec(cls, name):
+        return dict(cls.MEMBERS)[name]
+
+    # Fills key, dimension and size members from the members they refer to.
+    def update_references(self):
+        for name, codec in self.MEMBERS:
+            if isinstance(codec, Reference):
+                value = codec.evaluate(self._codec(codec.member), getattr(self, codec.member))
+                setattr(self, name, value)
+
+    # Optional member takes no bytes unless its flag is set.
+    @classmethod
+    def _present(cls, value, codec):
+        return not isinstance(codec, Optional) or codec.present(getattr(value, codec.flag))
+
+    @classmethod
+    def size(cls, value):
+        return sum(codec.size(getattr(value, name)) for name, codec in cls.MEMBERS if cls._present(value, codec))
+
+    @classmethod
+    def write(cls, value):
+        value.update_references()
+        chunks = []
+        container = 0
+        for name, codec in cls.MEMBERS:
+            if not cls._present(value, codec):
+                continue
+            try:
+                if isinstance(codec, Bits):
+                    container = codec.pack(container, getattr(value, name))
+                    if codec.closes_group:
+                        chunks.append(codec.write(container))
+                        container = 0
+                else:
+                    chunks.append(codec.write(getattr(value, name)))
+            except Error as e:
+                raise Error(f'{cls.__name__}.{name}: {e}') from None
+        data = bytearray(b''.join(chunks))
+        spans = cls._spans(value)
+        for name in cls.CHECKSUMS:
+            codec = cls._codec(name)
+            checksum = codec.compute(data[spans[codec.first][0]:spans[codec.last][1]])
+            start, end = spans[name]
+            data[start:end] = codec._native.write(checksum)
+            setattr(value, name, checksum)
+        return bytes(data)
+
+    # Fails when any checksum member does not match bytes it covers.
+    @classmethod
+    def verify(cls, data):
+        data = bytes(data)
+        value, _ = cls.read(data, 0)
+        spans = cls._spans(value)
+        for name in cls.CHECKSUMS:
+            codec = cls._codec(name)
+            if codec.compute(data[spans[codec.first][0]:spans[codec.last][1]]) != getattr(value, name):
+                raise Error(f'{cls.__name__}.{name}: checksum mismatch')
+
+    # Byte range (start, end) of each member, bit fields span their whole group.
+    @classmethod
+    def _spans(cls, value):
+        spans = {}
+        offset = 0
+        for name, codec in cls.MEMBERS:
+            size = codec.size(getattr(value, name)) if cls._present(value, codec) else 0
+            spans[name] = (offset, offset + size)
+            offset += size
+        return spans
+
+    @classmethod
+    def read(cls, data, offset, select=None, reference=None):
+        select = select or {}
+        values = {}
+        for name, codec in cls.MEMBERS:
+            try:
+                value, offset = codec.read(data, offset, select.get(name), cls._reference(name, values))
+            except Error as e:
+                raise Error(f'{cls.__name__}.{name}: {e}') from None
+            values[name] = value
+        return cls(**values), offset
+
+    # View key or array dimension of member, when its reference member precedes it,
+    # flag value of optional member.
+    @classmethod
+    def _reference(cls, name, values):
+        if isinstance(cls._codec(name), Optional):
+            return values[cls._codec(name).flag]
+        for other, codec in cls.MEMBERS:
+            if isinstance(codec, (ViewKey, Dimension)) and codec.member == name and other in values:
+                return values[other]
+        return None
+
+
+class View(Compound):
+    TYPES = ()
+
+    def __init__(self, **value):
+        self.type_id = None
+        self.value = None
+        if len(value) > 1:
+            raise TypeError(f'{type(self).__name__} holds only one alternative')
+        for name, v in value.items():
+            self.type_id = self._alternative(name=name)[0]
+            self.value = v
+
+    def __getattr__(self, name):
+        if name.startswith('_') or name not in [n for _, n, _ in self.TYPES]:
+            raise AttributeError(name)
+        if self.alternative != name:
+            raise Error(f'{type(self).__name__} holds {self.alternative}, not {name}')
+        return self.value
+
+    def __repr__(self):
+        if self.type_id is None:
+            return f'{type(self).__name__}()'
+        return f'{type(self).__name__}({self.alternative}={self.value!r})'
+
+    @property
+    def alternative(self):
+        if self.type_id is None:
+            return None
+        return self._alternative(type_id=self.type_id)[1]
+
+    @classmethod
+    def _alternative(cls, type_id=None, name=None):
+        for alternative in cls.TYPES:
+            if alternative[0] == type_id or alternative[1] == name:
+                return alternative
+        if name is not None:
+            raise TypeError(f'{cls.__name__} has no alternative {name}')
+        raise Error(f'unknown type id {type_id} of {cls.__name__}')
+
+    @classmethod
+    def _active(cls, value):
+        if value.type_id is None:
+            raise Error(f'{cls.__name__} not set')
+        return cls._alternative(type_id=value.type_id)
+
+    @classmethod
+    def size(cls, value):
+        _, _, codec = cls._active(value)
+        return codec.size(value.value)
+
+    @classmethod
+    def write(cls, value):
+        _, _, codec = cls._active(value)
+        return codec.write(value.value)
+
+    # Alternative is given by key member (reference) or selected by name,
+    # select is either alternative name or tuple of name and its own select.
+    @classmethod
+    def read(cls, data, offset, select=None, reference=None):
+        name, inner = select if isinstance(select, tuple) else (select, None)
+        if reference is not None:
+            _, name, codec = cls._alternative(type_id=reference)
+        elif name is not None:
+            _, name, codec = cls._alternative(name=name)
+        else:
+            raise Error(f'alternative of {cls.__name__} not selected')
+        value, offset = codec.read(data, offset, inner)
+        return cls(**{name: value}), offset
+
+
+class Enum(Type, enum.IntEnum):
+    @staticmethod
+    def native():
+        raise NotImplementedError
+
+    @classmethod
+    def default(cls):
+        return None
+
+    @classmethod
+    def size(cls, value):
+        return cls.native().size(value)
+
+    @classmethod
+    def write(cls, value):
+        if value is None:
+            raise Error('value not set')
+        return cls.native().write(cls(value))
+
+    @classmethod
+    def read(cls, data, offset, select=None, reference=None):
+        value, offset = cls.native().read(data, offset)
+        try:
+            return cls(value), offset
+        except ValueError:
+            raise Error(f'unknown {cls.__name__} value {value}') from None
+";
@@ -0,0 +1,16 @@
+use super::*;
+
+pub fn generate_enum_type(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_line(&format!("class {}(abf.Enum):", m.name));
+    writer.indent();
+    for c in &m.constants {
+        writer.write_line(&format!("{} = {}", c.name, c.value));
+    }
+    writer.write("\n");
+    writer.write_line("@staticmethod");
+    writer.write_line("def native():");
+    writer.indent();
+    writer.write_line(&format!("return {}", m.underlaying_type.codec()));
+    writer.dedent();
+    writer.dedent();
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use super::*;
+
+mod python_memory_detail;
+mod enum_type;
+mod struct_type;
+mod view_type;
+mod abf_core;
+#[cfg(test)]
+mod test;
+
+trait PythonMemoryDetail {
+    fn name(&self) -> String;
+    fn codec(&self) -> String;
+
+    fn variable(&self) -> String {
+        utils::to_snake_case(&self.name())
+    }
+}
+
+pub fn generate(m: &[MemoryDeclaration], module: &Module, big_endian: bool, args: &Args) {
+    let output_module = module.name.as_str();
+
+    std::fs::create_dir_all(&args.output_dir)
+        .expect("could not create output directory");
+
+    let abf_file = format!("{}/abf.py", args.output_dir);
+    let mut f = std::fs::File::create(&abf_file).expect("could not create abf.py");
+    let abf_source_code = abf_core::SOURCE.replace("<<BIG_ENDIAN>>", if big_endian { "True" } else { "False" })
+        .replace("<<COMPILER_VERSION>>", env!("CARGO_PKG_VERSION"));
+    f.write_all(abf_source_code.as_bytes()).expect("write abf.py failed");
+
+    let mut writer = Writer::new(&format!("{}/{}.py", args.output_dir, output_module));
+    writer.write_line("import abf");
+    for import in &module.imports {
+        writer.write_line(&format!("from {} import *", import));
+    }
+    for md in m.iter().filter(|md| md.module == module.name) {
+        writer.write_line("");
+        writer.write_line("");
+        match &md.memory.memory {
+            MemoryType::Native(_) => panic!("Unexpected"),
+            MemoryType::Struct(s) => struct_type::generate_struct_type(&s.borrow(), &mut writer),
+            MemoryType::View(v) => view_type::generate_view_type(v, &mut writer),
+            MemoryType::Enum(e) => enum_type::generate_enum_type(e, &mut writer),
+        }
+    }
+}
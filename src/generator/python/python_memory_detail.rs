@@ -0,0 +1,176 @@
+use super::*;
+
+impl PythonMemoryDetail for Memory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+
+    fn codec(&self) -> String {
+        match self.array_size {
+            ArraySize::No => self.memory.codec(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest if self.is_string() => "abf.String()".into(),
+            ArraySize::Exact(s) if self.is_string() => format!("abf.String({})", s),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => format!("abf.Array({})", self.memory.codec()),
+            ArraySize::Exact(s) => format!("abf.Array({}, {})", self.memory.codec(), s),
+            ArraySize::Until(_) => panic!("terminated array not supported"),
+        }
+    }
+}
+
+impl PythonMemoryDetail for MemoryType {
+    fn name(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.name(),
+            MemoryType::Struct(m) => m.borrow().name(),
+            MemoryType::View(m) => m.name(),
+            MemoryType::Enum(m) => m.name(),
+        }
+    }
+
+    fn codec(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.codec(),
+            MemoryType::Struct(m) => m.borrow().codec(),
+            MemoryType::View(m) => m.codec(),
+            MemoryType::Enum(m) => m.codec(),
+        }
+    }
+}
+
+impl PythonMemoryDetail for NativeType {
+    fn name(&self) -> String {
+        match self {
+            NativeType::Bool => "b".into(),
+            NativeType::U8 => "u8".into(),
+            NativeType::U16 => "u16".into(),
+            NativeType::U24 => "u24".into(),
+            NativeType::U32 => "u32".into(),
+            NativeType::U64 => "u64".into(),
+            NativeType::ConstU8(_) => "cu8".into(),
+            NativeType::ConstU16(_) => "cu16".into(),
+            NativeType::ConstU24(_) => "cu24".into(),
+            NativeType::ConstU32(_) => "cu32".into(),
+            NativeType::ConstU64(_) => "cu64".into(),
+            NativeType::I8 => "i8".into(),
+            NativeType::I16 => "i16".into(),
+            NativeType::I32 => "i32".into(),
+            NativeType::I64 => "i64".into(),
+            NativeType::F32 => "f32".into(),
+            NativeType::F64 => "f64".into(),
+            NativeType::Char => "string".into(),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.name(),
+            NativeType::ArrayDimensionReference(r) => r.origin.as_ref().name(),
+            NativeType::StructMemberSize(m) => m.native.name(),
+            NativeType::Checksum(c) => c.native.name(),
+            NativeType::BitField(bf) => bf.native.name(),
+        }
+    }
+
+    fn codec(&self) -> String {
+        match self {
+            NativeType::Bool => "abf.BOOL".into(),
+            NativeType::U8 => "abf.U8".into(),
+            NativeType::U16 => "abf.U16".into(),
+            NativeType::U24 => "abf.U24".into(),
+            NativeType::U32 => "abf.U32".into(),
+            NativeType::U64 => "abf.U64".into(),
+            NativeType::ConstU8(v) => format!("abf.Constant(abf.U8, {})", v),
+            NativeType::ConstU16(v) => format!("abf.Constant(abf.U16, {})", v),
+            NativeType::ConstU24(v) => format!("abf.Constant(abf.U24, {})", v),
+            NativeType::ConstU32(v) => format!("abf.Constant(abf.U32, {})", v),
+            NativeType::ConstU64(v) => format!("abf.Constant(abf.U64, {})", v),
+            NativeType::I8 => "abf.I8".into(),
+            NativeType::I16 => "abf.I16".into(),
+            NativeType::I32 => "abf.I32".into(),
+            NativeType::I64 => "abf.I64".into(),
+            NativeType::F32 => "abf.F32".into(),
+            NativeType::F64 => "abf.F64".into(),
+            NativeType::Char => panic!("character outside of string"),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) =>
+                format!("abf.ViewKey('{}', {})", m.view.name, m.native_key.codec()),
+            NativeType::ArrayDimensionReference(r) =>
+                format!("abf.Dimension('{}', {})", r.array.name, r.origin.codec()),
+            NativeType::StructMemberSize(m) =>
+                format!("abf.SizeOf('{}', {})", m.member.name, m.native.codec()),
+            NativeType::Checksum(c) => {
+                let algorithm = match c.algorithm {
+                    ChecksumAlgorithm::Sum => "checksum_sum",
+                    _ => c.algorithm.name(),
+                };
+                format!("abf.Checksum(abf.{}, '{}', '{}', {})", algorithm, c.first.name, c.last.name, c.native.codec())
+            },
+            NativeType::BitField(bf) => {
+                let mut codec = format!("abf.Bits({}, {}, {}", bf.container.codec(), bf.shift, bf.bits);
+                if bf.is_signed() {
+                    codec.push_str(", signed=True");
+                }
+                if bf.native.is_bool() {
+                    codec.push_str(", boolean=True");
+                }
+                if bf.closes_group {
+                    codec.push_str(", closes_group=True");
+                }
+                codec.push(')');
+                codec
+            },
+        }
+    }
+}
+
+impl PythonMemoryDetail for StructMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn codec(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl PythonMemoryDetail for ViewMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn codec(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl PythonMemoryDetail for EnumMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn codec(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl PythonMemoryDetail for StructMemberMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn codec(&self) -> String {
+        match &self.condition {
+            Some(condition) => format!("abf.Optional('{}', {}, {})",
+                condition.flag.name,
+                condition.bit.map_or("None".to_string(), |bit| bit.to_string()),
+                self.memory.borrow().codec()),
+            None => self.memory.borrow().codec(),
+        }
+    }
+}
+
+impl PythonMemoryDetail for ViewPosibilityMemory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+
+    fn codec(&self) -> String {
+        self.memory.codec()
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+pub fn generate_struct_type(m: &StructMemory, writer: &mut Writer) {
+    writer.write_line(&format!("class {}(abf.Struct):", m.name));
+    writer.indent();
+    if m.fields.is_empty() {
+        writer.write_line("pass");
+    } else {
+        writer.write_line("MEMBERS = (");
+        writer.indent();
+        for sm in &m.fields {
+            writer.write_line(&format!("('{}', {}),", sm.name, sm.as_ref().codec()));
+        }
+        writer.dedent();
+        writer.write_line(")");
+        let checksums = m.get_checksums();
+        if !checksums.is_empty() {
+            let names = checksums
+                .iter()
+                .map(|c| format!("'{}', ", c.origin.name))
+                .collect::<String>();
+            writer.write_line(&format!("CHECKSUMS = ({})", names.trim_end()));
+        }
+    }
+    writer.dedent();
+}
@@ -0,0 +1,10 @@
+import unittest
+
+from empty_struct import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        empty = Empty()
+        self.assertEqual(empty.to_bytes(), b'')
+        self.assertEqual(Empty.from_bytes(bytes(1024)), empty)
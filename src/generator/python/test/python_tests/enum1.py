@@ -0,0 +1,18 @@
+import unittest
+
+import abf
+from enum1 import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        buffer = Car.Audi.to_bytes()
+        self.assertEqual(buffer, bytes([10]))
+        self.assertEqual(Car.from_bytes(buffer), Car.Audi)
+
+    def test_unknown_value(self):
+        with self.assertRaises(abf.Error):
+            Car.from_bytes(bytes([1]))
+
+    def test_compiler_version(self):
+        self.assertTrue(abf.COMPILER_VERSION)
@@ -0,0 +1,16 @@
+import unittest
+
+import abf
+from struct_with_array_of_natives import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        dim = Dimension(whl=[10, 20, 30])
+        buffer = dim.to_bytes()
+        self.assertEqual(buffer, bytes([0, 10, 0, 20, 0, 30]))
+        self.assertEqual(Dimension.from_bytes(buffer).whl, [10, 20, 30])
+
+    def test_wrong_length(self):
+        with self.assertRaises(abf.Error):
+            Dimension(whl=[10, 20]).to_bytes()
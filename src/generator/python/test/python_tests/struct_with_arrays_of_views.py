@@ -0,0 +1,27 @@
+import unittest
+
+import abf
+from struct_with_arrays_of_views import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        numbers = NumbersSeq(
+            num0=[Numbers(u8=1), Numbers(u16=2)],
+            num1=[Numbers(u32=3), Numbers(u8=4)],
+            num2=[Numbers(u16=5), Numbers(u32=6)])
+        buffer = numbers.to_bytes()
+        self.assertEqual(len(buffer), 14)
+
+        # views without key member are resolved by caller
+        decoded = NumbersSeq.from_bytes(buffer, {
+            'num0': ['u8', 'u16'],
+            'num1': ['u32', 'u8'],
+            'num2': ['u16', 'u32'],
+        })
+        self.assertEqual(decoded, numbers)
+        self.assertEqual(decoded.num1[0].u32, 3)
+
+    def test_not_selected(self):
+        with self.assertRaises(abf.Error):
+            NumbersSeq.from_bytes(bytes(14))
@@ -0,0 +1,23 @@
+import unittest
+
+import abf
+from struct_with_bit_fields import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        status = Status(mode=5, enabled=True, reserved=0xA, id=0x1234, level=-3, counter=0x123)
+        buffer = status.to_bytes()
+        self.assertEqual(Status.size(status), 5)
+        self.assertEqual(buffer, bytes([0xBA, 0x12, 0x34, 0xE9, 0x23]))
+
+        self.assertEqual(Status.from_bytes(buffer), status)
+
+    def test_overflow(self):
+        status = Status(mode=8, enabled=True, reserved=0, id=0, level=0, counter=0)
+        with self.assertRaises(abf.Error):
+            status.to_bytes()
+        status.mode = 0
+        status.level = -17
+        with self.assertRaises(abf.Error):
+            status.to_bytes()
@@ -0,0 +1,31 @@
+import unittest
+
+import abf
+from struct_with_checksum import *
+
+EXPECTED = bytes([
+    0x50, 0xA5, 0x03, 0x01, 0x02, 0x03, 0x06,
+    0x6F, 0xAA,
+    0x34, 0xDC, 0xC8, 0xAA
+])
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        frame = Frame(header=0xA5, payload=[1, 2, 3])
+        buffer = frame.to_bytes()
+        self.assertEqual(buffer, EXPECTED)
+        self.assertEqual(frame.crc, 0x6FAA)
+
+        Frame.verify(buffer)
+        frame_de = Frame.from_bytes(buffer)
+        self.assertEqual(frame_de.header_crc, 0x50)
+        self.assertEqual(frame_de.payload_sum, 0x06)
+        self.assertEqual(frame_de.crc, 0x6FAA)
+        self.assertEqual(frame_de.frame_crc, 0x34DCC8AA)
+
+    def test_mismatch(self):
+        buffer = bytearray(EXPECTED)
+        buffer[4] ^= 0x01
+        with self.assertRaises(abf.Error):
+            Frame.verify(buffer)
@@ -0,0 +1,15 @@
+import unittest
+
+from struct_with_constant import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        msg = Message(value=288)
+        buffer = msg.to_bytes()
+        self.assertEqual(buffer, bytes([224, 3, 1, 32]))
+
+        decoded = Message.from_bytes(buffer)
+        self.assertEqual(decoded.byte0, 224)
+        self.assertEqual(decoded.byte1, 3)
+        self.assertEqual(decoded.value, 288)
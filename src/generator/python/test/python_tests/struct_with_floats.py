@@ -0,0 +1,13 @@
+import unittest
+
+from struct_with_floats import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        sample = Sample(temperature=21.5, pressure=101325.25, id=7)
+        buffer = sample.to_bytes()
+        self.assertEqual(len(buffer), 14)
+        self.assertEqual(buffer[:4], bytes([0x41, 0xAC, 0x00, 0x00]))
+
+        self.assertEqual(Sample.from_bytes(buffer), sample)
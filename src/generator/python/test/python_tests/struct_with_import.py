@@ -0,0 +1,21 @@
+import unittest
+
+import abf
+import common_header
+from struct_with_import import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        message = Message(header=Header(version=1, length=4), payload=0xDEADBEEF)
+        buffer = message.to_bytes()
+        self.assertEqual(buffer, bytes([0x01, 0x00, 0x04, 0xDE, 0xAD, 0xBE, 0xEF]))
+
+        message_de = Message.from_bytes(buffer)
+        self.assertEqual(message_de.header.version, 1)
+        self.assertEqual(message_de.header.length, 4)
+        self.assertEqual(message_de.payload, 0xDEADBEEF)
+
+    def test_imported_type(self):
+        header = common_header.Header(version=2, length=0x0102)
+        self.assertEqual(header.to_bytes(), bytes([0x02, 0x01, 0x02]))
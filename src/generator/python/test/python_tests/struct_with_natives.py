@@ -0,0 +1,30 @@
+import unittest
+
+import abf
+from struct_with_natives import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        xmass = Date(year=2023, month=12, day=24)
+        buffer = xmass.to_bytes()
+        self.assertEqual(buffer, bytes([0x07, 0xE7, 24, 12]))
+        self.assertEqual(Date.size(xmass), 4)
+
+        decoded = Date.from_bytes(buffer)
+        self.assertEqual(decoded.day, 24)
+        self.assertEqual(decoded.month, 12)
+        self.assertEqual(decoded.year, 2023)
+        self.assertEqual(decoded, xmass)
+
+    def test_not_set(self):
+        with self.assertRaises(abf.Error):
+            Date(year=2023).to_bytes()
+
+    def test_overflow(self):
+        with self.assertRaises(abf.Error):
+            Date(year=2023, month=256, day=24).to_bytes()
+
+    def test_source_too_short(self):
+        with self.assertRaises(abf.Error):
+            Date.from_bytes(bytes(3))
@@ -0,0 +1,18 @@
+import unittest
+
+import abf
+from struct_with_nostd_values import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        buffer = NoStdValues(a=120951).to_bytes()
+        self.assertEqual(len(buffer), 6)
+        if abf.BIG_ENDIAN:
+            self.assertEqual(buffer[:3], bytes([0x01, 0xD8, 0x77]))
+        else:
+            self.assertEqual(buffer[:3], bytes([0x77, 0xD8, 0x01]))
+
+        decoded = NoStdValues.from_bytes(buffer)
+        self.assertEqual(decoded.a, 120951)
+        self.assertEqual(decoded.b, 1234)
@@ -0,0 +1,28 @@
+import unittest
+
+import abf
+from struct_with_optional import *
+
+
+class Serde(unittest.TestCase):
+    def test_present(self):
+        packet = Packet(flags=0x08, ext=Ext(code=1, value=0x0203), extended=False, trailer=0xFF)
+        buffer = packet.to_bytes()
+        self.assertEqual(buffer, bytes([0x08, 0x01, 0x02, 0x03, 0x00, 0xFF]))
+
+        packet_de = Packet.from_bytes(buffer)
+        self.assertEqual(packet_de.flags, 0x08)
+        self.assertEqual(packet_de.ext.code, 1)
+        self.assertEqual(packet_de.ext.value, 0x0203)
+        self.assertIsNone(packet_de.tail)
+        self.assertEqual(packet_de.trailer, 0xFF)
+
+    def test_absent(self):
+        packet = Packet(flags=0x00, extended=True, tail=0x0405, trailer=0xEE)
+        buffer = packet.to_bytes()
+        self.assertEqual(buffer, bytes([0x00, 0x01, 0x04, 0x05, 0xEE]))
+
+        packet_de = Packet.from_bytes(buffer)
+        self.assertIsNone(packet_de.ext)
+        self.assertEqual(packet_de.tail, 0x0405)
+        self.assertEqual(packet_de.trailer, 0xEE)
@@ -0,0 +1,23 @@
+import unittest
+
+import abf
+from struct_with_reference_view import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        known_number = KnownNumber(number=ANumber(u16=12500))
+        buffer = known_number.to_bytes()
+        self.assertEqual(buffer, bytes([1, 0x30, 0xD4]))
+
+        decoded = KnownNumber.from_bytes(buffer + bytes(10))
+        self.assertEqual(decoded.number_key, 1)
+        self.assertEqual(decoded.number.type_id, 1)
+        self.assertEqual(decoded.number.alternative, 'u16')
+        self.assertEqual(decoded.number.u16, 12500)
+        with self.assertRaises(abf.Error):
+            decoded.number.u8
+
+    def test_unknown_key(self):
+        with self.assertRaises(abf.Error):
+            KnownNumber.from_bytes(bytes([7, 0, 0, 0, 0]))
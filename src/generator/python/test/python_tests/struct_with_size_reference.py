@@ -0,0 +1,16 @@
+import unittest
+
+from struct_with_size_reference import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        main = Main(volume=Volume(w=10, h=20, l=30))
+        buffer = main.to_bytes()
+        self.assertEqual(len(buffer), 8)
+
+        decoded = Main.from_bytes(buffer)
+        self.assertEqual(decoded.volume_size, 7)
+        self.assertEqual(decoded.volume.w, 10)
+        self.assertEqual(decoded.volume.h, 20)
+        self.assertEqual(decoded.volume.l, 30)
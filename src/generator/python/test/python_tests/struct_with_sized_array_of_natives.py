@@ -0,0 +1,15 @@
+import unittest
+
+from struct_with_sized_array_of_natives import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        dim = Dimension(whl=[57, 58])
+        buffer = dim.to_bytes()
+        self.assertEqual(buffer, bytes([2, 0, 57, 0, 58]))
+
+        decoded, end = Dimension.read(buffer + bytes(10), 0)
+        self.assertEqual(decoded.whl_dim, 2)
+        self.assertEqual(decoded.whl, [57, 58])
+        self.assertEqual(end, 5)
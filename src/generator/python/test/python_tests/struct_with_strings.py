@@ -0,0 +1,22 @@
+import unittest
+
+import abf
+from struct_with_strings import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        device = Device(serial='SN42', name='sensor', version=0x0102)
+        buffer = device.to_bytes()
+        self.assertEqual(buffer, b'SN42\0\0\0\0\x06sensor\x01\x02')
+
+        device_de = Device.from_bytes(buffer)
+        self.assertEqual(device_de.serial, 'SN42')
+        self.assertEqual(device_de.name_len, 6)
+        self.assertEqual(device_de.name, 'sensor')
+        self.assertEqual(device_de.version, 0x0102)
+
+    def test_too_long(self):
+        device = Device(serial='SN4200000', name='', version=0)
+        with self.assertRaises(abf.Error):
+            device.to_bytes()
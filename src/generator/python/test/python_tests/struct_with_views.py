@@ -0,0 +1,15 @@
+import unittest
+
+from struct_with_views import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        number_seq = NumberSeq(num0=Numbers(u8=1), num1=Numbers(u16=20000), num2=Numbers(u32=3000000))
+        buffer = number_seq.to_bytes()
+        self.assertEqual(len(buffer), 7)
+
+        decoded = NumberSeq.from_bytes(buffer, {'num0': 'u8', 'num1': 'u16', 'num2': 'u32'})
+        self.assertEqual(decoded.num0.u8, 1)
+        self.assertEqual(decoded.num1.u16, 20000)
+        self.assertEqual(decoded.num2.u32, 3000000)
@@ -0,0 +1,21 @@
+import unittest
+
+import abf
+from view_with_natives import *
+
+
+class Serde(unittest.TestCase):
+    def test_serde(self):
+        buffer = Numbers(u16=60000).to_bytes()
+        self.assertEqual(buffer, bytes([0x60, 0xEA]))
+
+        numbers = Numbers.from_bytes(buffer, 'u16')
+        self.assertEqual(numbers.u16, 60000)
+        with self.assertRaises(abf.Error):
+            numbers.u8
+        with self.assertRaises(abf.Error):
+            numbers.u32
+
+    def test_not_set(self):
+        with self.assertRaises(abf.Error):
+            Numbers().to_bytes()
@@ -0,0 +1,207 @@
+use super::*;
+
+#[test]
+fn view_with_natives() {
+    generate_test(
+        "view_with_natives.abf", 
+        "view_with_natives.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_natives() {
+    generate_test(
+        "struct_with_natives.abf", 
+        "struct_with_natives.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_views() {
+    generate_test(
+        "struct_with_views.abf", 
+        "struct_with_views.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_array_of_natives() {
+    generate_test(
+        "struct_with_array_of_natives.abf", 
+        "struct_with_array_of_natives.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_arrays_of_views() {
+    generate_test(
+        "struct_with_arrays_of_views.abf", 
+        "struct_with_arrays_of_views.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_sized_array_of_natives() {
+    generate_test(
+        "struct_with_sized_array_of_natives.abf", 
+        "struct_with_sized_array_of_natives.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_reference_view() {
+    generate_test(
+        "struct_with_reference_view.abf", 
+        "struct_with_reference_view.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_constant() {
+    generate_test(
+        "struct_with_constant.abf", 
+        "struct_with_constant.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_big_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_little_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.py", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn enum1() {
+    generate_test(
+        "enum1.abf", 
+        "enum1.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn empty_struct() {
+    generate_test(
+        "empty_struct.abf", 
+        "empty_struct.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_size_view() {
+    generate_test(
+        "struct_with_size_reference.abf", 
+        "struct_with_size_reference.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_floats() {
+    generate_test(
+        "struct_with_floats.abf", 
+        "struct_with_floats.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_bit_fields() {
+    generate_test(
+        "struct_with_bit_fields.abf", 
+        "struct_with_bit_fields.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_strings() {
+    generate_test(
+        "struct_with_strings.abf", 
+        "struct_with_strings.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_checksum() {
+    generate_test(
+        "struct_with_checksum.abf", 
+        "struct_with_checksum.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_optional() {
+    generate_test(
+        "struct_with_optional.abf", 
+        "struct_with_optional.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_import() {
+    generate_test(
+        "struct_with_import.abf", 
+        "struct_with_import.py", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_rest_array() {
+    assert_eq!(
+        unsupported_feature("struct_with_rest_array.abf"),
+        "Unsupported feature: Arrays running to end of message are not supported in language Python"
+    );
+}
+
+#[test]
+fn struct_with_bounded_arrays() {
+    assert_eq!(
+        unsupported_feature("struct_with_bounded_arrays.abf"),
+        "Unsupported feature: Bounded arrays are not supported in language Python"
+    );
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+pub fn generate_view_type(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_line(&format!("class {}(abf.View):", m.name));
+    writer.indent();
+    writer.write_line("TYPES = (");
+    writer.indent();
+    for t in &m.types {
+        writer.write_line(&format!("({}, '{}', {}),",
+            t.constant.get_value(),
+            t.variable(),
+            t.codec()));
+    }
+    writer.dedent();
+    writer.write_line(")");
+    writer.dedent();
+}
@@ -0,0 +1,772 @@
+pub static SOURCE: &str = "
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+pub const BIG_ENDIAN: bool = <<BIG_ENDIAN>>;
+// Version of compiler which generated this code.
+pub const COMPILER_VERSION: &str = \"<<COMPILER_VERSION>>\";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    NotSet,
+    BufferTooSmall,
+    SourceTooShort,
+    SourceNotSet,
+    NotDeserialized,
+    OutOfRange,
+    Overflow,
+    UnknownTypeId,
+    TypeMismatch,
+    UnknownEnumValue,
+    InvalidString,
+    ChecksumMismatch,
+    NotPresent,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, \"{:?}\", self)
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub trait Native: Copy {
+    const SIGNED: bool = false;
+
+    fn to_bits(self) -> u64;
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_native {
+    ($($t:ty => $signed:expr),*) => {
+        $(
+            impl Native for $t {
+                const SIGNED: bool = $signed;
+
+                fn to_bits(self) -> u64 {
+                    self as u64
+                }
+
+                fn from_bits(bits: u64) -> Self {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_native!(
+    u8 => false, u16 => false, u32 => false, u64 => false,
+    i8 => true, i16 => true, i32 => true, i64 => true
+);
+
+impl Native for bool {
+    fn to_bits(self) -> u64 {
+        self as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+impl Native for f32 {
+    fn to_bits(self) -> u64 {
+        f32::to_bits(self) as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl Native for f64 {
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+pub fn write_bits(dest: &mut [u8], bits: u64, size: usize) -> Result<usize, Error> {
+    if dest.len() < size {
+        return Err(Error::BufferTooSmall);
+    }
+    for (i, byte) in dest[..size].iter_mut().enumerate() {
+        let shift = if BIG_ENDIAN { 8 * (size - 1 - i) } else { 8 * i };
+        *byte = (bits >> shift) as u8;
+    }
+    Ok(size)
+}
+
+pub fn read_bits(source: &[u8], size: usize) -> Result<u64, Error> {
+    if source.len() < size {
+        return Err(Error::SourceTooShort);
+    }
+    let mut bits = 0u64;
+    for (i, byte) in source[..size].iter().enumerate() {
+        let shift = if BIG_ENDIAN { 8 * (size - 1 - i) } else { 8 * i };
+        bits |= (*byte as u64) << shift;
+    }
+    Ok(bits)
+}
+
+// Bit field occupies `bits` bits of container starting at `shift`.
+fn bit_field_mask(shift: usize, bits: usize) -> u64 {
+    ((1u64 << bits) - 1) << shift
+}
+
+fn extract_bit_field<T: Native>(container: u64, shift: usize, bits: usize) -> T {
+    let mut value = (container >> shift) & bit_field_mask(0, bits);
+    if T::SIGNED && (value >> (bits - 1)) & 1 == 1 {
+        value |= !bit_field_mask(0, bits);
+    }
+    T::from_bits(value)
+}
+
+// Converts length, size or view key into native type of referencing member.
+pub fn fit<T: Native>(value: usize, size: usize) -> Result<T, Error> {
+    if size < 8 && (value as u64) >> (8 * size) != 0 {
+        return Err(Error::Overflow);
+    }
+    Ok(T::from_bits(value as u64))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// CHECKSUM
+////////////////////////////////////////////////////////////////////////////////
+
+// CRC-8/SMBUS: poly 0x07, init 0x00.
+pub fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+// CRC-16/CCITT-FALSE: poly 0x1021, init 0xFFFF.
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+// CRC-32/ISO-HDLC: reflected poly 0xEDB88320, init and final xor 0xFFFFFFFF.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+// Additive sum of bytes, caller truncates it to width of checksum member.
+pub fn sum(data: &[u8]) -> u64 {
+    data.iter().fold(0u64, |sum, byte| sum.wrapping_add(*byte as u64))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SERIALIZER
+////////////////////////////////////////////////////////////////////////////////
+
+pub trait Serializer: Default {
+    fn size(&self) -> Result<usize, Error>;
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error>;
+    fn init(&mut self);
+
+    fn serialize_to_vec(&mut self) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; self.size()?];
+        self.serialize(&mut out)?;
+        Ok(out)
+    }
+}
+
+pub struct NativeSerializer<T: Native, const SIZE: usize> {
+    data: Option<T>,
+}
+
+impl<T: Native, const SIZE: usize> Default for NativeSerializer<T, SIZE> {
+    fn default() -> Self {
+        Self { data: None }
+    }
+}
+
+impl<T: Native, const SIZE: usize> NativeSerializer<T, SIZE> {
+    pub fn set_data(&mut self, data: T) {
+        self.data = Some(data);
+    }
+
+    pub fn get_data(&self) -> Result<T, Error> {
+        self.data.ok_or(Error::NotSet)
+    }
+}
+
+impl<T: Native, const SIZE: usize> Serializer for NativeSerializer<T, SIZE> {
+    fn size(&self) -> Result<usize, Error> {
+        Ok(SIZE)
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        let data = self.data.ok_or(Error::NotSet)?;
+        write_bits(dest, data.to_bits(), SIZE)
+    }
+
+    fn init(&mut self) {
+        self.data = None;
+    }
+}
+
+// Members of bit field group share container, the member closing the group
+// accounts for its bytes.
+pub struct BitFieldSerializer<T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> {
+    data: Option<T>,
+}
+
+impl<T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> Default
+    for BitFieldSerializer<T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    fn default() -> Self {
+        Self { data: None }
+    }
+}
+
+impl<T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool>
+    BitFieldSerializer<T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    pub fn set_data(&mut self, data: T) {
+        self.data = Some(data);
+    }
+
+    pub fn get_data(&self) -> Result<T, Error> {
+        self.data.ok_or(Error::NotSet)
+    }
+}
+
+impl<T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> Serializer
+    for BitFieldSerializer<T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    fn size(&self) -> Result<usize, Error> {
+        Ok(if CLOSES_GROUP { SIZE } else { 0 })
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        let bits = self.data.ok_or(Error::NotSet)?.to_bits();
+        let mask = bit_field_mask(SHIFT, BITS);
+        if extract_bit_field::<T>((bits << SHIFT) & mask, SHIFT, BITS).to_bits() != bits {
+            return Err(Error::Overflow);
+        }
+        if dest.len() < SIZE {
+            return Err(Error::BufferTooSmall);
+        }
+        let container = read_bits(dest, SIZE)?;
+        write_bits(dest, (container & !mask) | ((bits << SHIFT) & mask), SIZE)?;
+        self.size()
+    }
+
+    fn init(&mut self) {
+        self.data = None;
+    }
+}
+
+#[derive(Default)]
+pub struct ConstantSerializer<const SIZE: usize, const VALUE: u64> {}
+
+impl<const SIZE: usize, const VALUE: u64> Serializer for ConstantSerializer<SIZE, VALUE> {
+    fn size(&self) -> Result<usize, Error> {
+        Ok(SIZE)
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        write_bits(dest, VALUE, SIZE)
+    }
+
+    fn init(&mut self) {}
+}
+
+pub struct ArraySerializer<S: Serializer, const N: usize> {
+    items: [S; N],
+}
+
+impl<S: Serializer, const N: usize> Default for ArraySerializer<S, N> {
+    fn default() -> Self {
+        Self { items: std::array::from_fn(|_| S::default()) }
+    }
+}
+
+impl<S: Serializer, const N: usize> ArraySerializer<S, N> {
+    pub fn get(&mut self, i: usize) -> &mut S {
+        &mut self.items[i]
+    }
+
+    pub fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<S: Serializer, const N: usize> Serializer for ArraySerializer<S, N> {
+    fn size(&self) -> Result<usize, Error> {
+        self.items.iter().map(|i| i.size()).sum()
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        let mut offset = 0;
+        for item in self.items.iter_mut() {
+            offset += item.serialize(&mut dest[offset..])?;
+        }
+        Ok(offset)
+    }
+
+    fn init(&mut self) {
+        for item in self.items.iter_mut() {
+            item.init();
+        }
+    }
+}
+
+pub struct DynArraySerializer<S: Serializer> {
+    items: Vec<S>,
+}
+
+impl<S: Serializer> Default for DynArraySerializer<S> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<S: Serializer> DynArraySerializer<S> {
+    pub fn get(&mut self, i: usize) -> &mut S {
+        while self.items.len() <= i {
+            self.items.push(S::default());
+        }
+        &mut self.items[i]
+    }
+
+    pub fn length(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<S: Serializer> Serializer for DynArraySerializer<S> {
+    fn size(&self) -> Result<usize, Error> {
+        self.items.iter().map(|i| i.size()).sum()
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        let mut offset = 0;
+        for item in self.items.iter_mut() {
+            offset += item.serialize(&mut dest[offset..])?;
+        }
+        Ok(offset)
+    }
+
+    fn init(&mut self) {
+        self.items.clear();
+    }
+}
+
+// Fixed size string is padded with NUL bytes.
+pub struct StringSerializer<const N: usize> {
+    data: Option<Vec<u8>>,
+}
+
+impl<const N: usize> Default for StringSerializer<N> {
+    fn default() -> Self {
+        Self { data: None }
+    }
+}
+
+impl<const N: usize> StringSerializer<N> {
+    pub fn set_data(&mut self, data: &str) {
+        self.data = Some(data.as_bytes().to_vec());
+    }
+
+    pub fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Serializer for StringSerializer<N> {
+    fn size(&self) -> Result<usize, Error> {
+        Ok(N)
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        let data = self.data.as_ref().ok_or(Error::NotSet)?;
+        if data.len() > N {
+            return Err(Error::Overflow);
+        }
+        if dest.len() < N {
+            return Err(Error::BufferTooSmall);
+        }
+        dest[..data.len()].copy_from_slice(data);
+        dest[data.len()..N].fill(0);
+        Ok(N)
+    }
+
+    fn init(&mut self) {
+        self.data = None;
+    }
+}
+
+// Dynamic string takes exactly length of data.
+#[derive(Default)]
+pub struct DynStringSerializer {
+    data: Vec<u8>,
+}
+
+impl DynStringSerializer {
+    pub fn set_data(&mut self, data: &str) {
+        self.data = data.as_bytes().to_vec();
+    }
+
+    pub fn length(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl Serializer for DynStringSerializer {
+    fn size(&self) -> Result<usize, Error> {
+        Ok(self.data.len())
+    }
+
+    fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, Error> {
+        if dest.len() < self.data.len() {
+            return Err(Error::BufferTooSmall);
+        }
+        dest[..self.data.len()].copy_from_slice(&self.data);
+        Ok(self.data.len())
+    }
+
+    fn init(&mut self) {
+        self.data.clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// DESERIALIZER
+////////////////////////////////////////////////////////////////////////////////
+
+pub trait Deserializer<'a>: Default {
+    fn set_source(&mut self, source: &'a [u8]);
+    fn source_set(&self) -> bool;
+    fn end(&mut self) -> Result<&'a [u8], Error>;
+    fn init(&mut self);
+
+    fn new(source: &'a [u8]) -> Self {
+        let mut deserializer = Self::default();
+        deserializer.set_source(source);
+        deserializer
+    }
+}
+
+pub struct NativeDeserializer<'a, T: Native, const SIZE: usize> {
+    source: Option<&'a [u8]>,
+    data: PhantomData<T>,
+}
+
+impl<'a, T: Native, const SIZE: usize> Default for NativeDeserializer<'a, T, SIZE> {
+    fn default() -> Self {
+        Self { source: None, data: PhantomData }
+    }
+}
+
+impl<'a, T: Native, const SIZE: usize> NativeDeserializer<'a, T, SIZE> {
+    pub fn get_data(&self) -> Result<T, Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        Ok(T::from_bits(read_bits(source, SIZE)?))
+    }
+}
+
+impl<'a, T: Native, const SIZE: usize> Deserializer<'a> for NativeDeserializer<'a, T, SIZE> {
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        source.get(SIZE..).ok_or(Error::SourceTooShort)
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+    }
+}
+
+pub struct BitFieldDeserializer<'a, T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> {
+    source: Option<&'a [u8]>,
+    data: PhantomData<T>,
+}
+
+impl<'a, T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> Default
+    for BitFieldDeserializer<'a, T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    fn default() -> Self {
+        Self { source: None, data: PhantomData }
+    }
+}
+
+impl<'a, T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool>
+    BitFieldDeserializer<'a, T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    pub fn get_data(&self) -> Result<T, Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        Ok(extract_bit_field(read_bits(source, SIZE)?, SHIFT, BITS))
+    }
+}
+
+impl<'a, T: Native, const SIZE: usize, const SHIFT: usize, const BITS: usize, const CLOSES_GROUP: bool> Deserializer<'a>
+    for BitFieldDeserializer<'a, T, SIZE, SHIFT, BITS, CLOSES_GROUP>
+{
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        if CLOSES_GROUP {
+            source.get(SIZE..).ok_or(Error::SourceTooShort)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+    }
+}
+
+// Sets source of items[i] by walking from the last item whose source is known.
+fn resolve_item<'a, D: Deserializer<'a>>(
+    items: &mut [D],
+    source: Option<&'a [u8]>,
+    i: usize,
+) -> Result<(), Error> {
+    let mut j = i;
+    while j > 0 && !items[j].source_set() {
+        j -= 1;
+    }
+    if !items[j].source_set() {
+        items[j].set_source(source.ok_or(Error::SourceNotSet)?);
+    }
+    while j < i {
+        let end = items[j].end()?;
+        items[j + 1].set_source(end);
+        j += 1;
+    }
+    Ok(())
+}
+
+pub struct ArrayDeserializer<'a, D: Deserializer<'a>, const N: usize> {
+    source: Option<&'a [u8]>,
+    items: [D; N],
+}
+
+impl<'a, D: Deserializer<'a>, const N: usize> Default for ArrayDeserializer<'a, D, N> {
+    fn default() -> Self {
+        Self { source: None, items: std::array::from_fn(|_| D::default()) }
+    }
+}
+
+impl<'a, D: Deserializer<'a>, const N: usize> ArrayDeserializer<'a, D, N> {
+    pub fn get(&mut self, i: usize) -> Result<&mut D, Error> {
+        if i >= N {
+            return Err(Error::OutOfRange);
+        }
+        resolve_item(&mut self.items, self.source, i)?;
+        Ok(&mut self.items[i])
+    }
+
+    pub fn length(&self) -> usize {
+        N
+    }
+}
+
+impl<'a, D: Deserializer<'a>, const N: usize> Deserializer<'a> for ArrayDeserializer<'a, D, N> {
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.init();
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        if N == 0 {
+            return self.source.ok_or(Error::SourceNotSet);
+        }
+        resolve_item(&mut self.items, self.source, N - 1)?;
+        self.items[N - 1].end()
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+        for item in self.items.iter_mut() {
+            item.init();
+        }
+    }
+}
+
+pub struct DynArrayDeserializer<'a, D: Deserializer<'a>> {
+    source: Option<&'a [u8]>,
+    length: Option<usize>,
+    items: Vec<D>,
+}
+
+impl<'a, D: Deserializer<'a>> Default for DynArrayDeserializer<'a, D> {
+    fn default() -> Self {
+        Self { source: None, length: None, items: Vec::new() }
+    }
+}
+
+impl<'a, D: Deserializer<'a>> DynArrayDeserializer<'a, D> {
+    pub fn get(&mut self, i: usize) -> Result<&mut D, Error> {
+        if let Some(length) = self.length {
+            if i >= length {
+                return Err(Error::OutOfRange);
+            }
+        }
+        while self.items.len() <= i {
+            self.items.push(D::default());
+        }
+        resolve_item(&mut self.items, self.source, i)?;
+        Ok(&mut self.items[i])
+    }
+
+    // Length is known only when array is referenced by dimension member.
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+
+    pub fn set_length(&mut self, length: usize) {
+        self.length = Some(length);
+    }
+}
+
+impl<'a, D: Deserializer<'a>> Deserializer<'a> for DynArrayDeserializer<'a, D> {
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.init();
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        let last = match self.length {
+            Some(0) => return self.source.ok_or(Error::SourceNotSet),
+            Some(length) => length - 1,
+            None if self.items.is_empty() => return self.source.ok_or(Error::SourceNotSet),
+            None => self.items.len() - 1,
+        };
+        self.get(last)?.end()
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+        self.length = None;
+        self.items.clear();
+    }
+}
+
+// Fixed size string ends at first NUL byte.
+#[derive(Default)]
+pub struct StringDeserializer<'a, const N: usize> {
+    source: Option<&'a [u8]>,
+}
+
+impl<'a, const N: usize> StringDeserializer<'a, N> {
+    pub fn get_data(&self) -> Result<&'a str, Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        let data = source.get(..N).ok_or(Error::SourceTooShort)?;
+        let length = data.iter().position(|b| *b == 0).unwrap_or(N);
+        std::str::from_utf8(&data[..length]).map_err(|_| Error::InvalidString)
+    }
+}
+
+impl<'a, const N: usize> Deserializer<'a> for StringDeserializer<'a, N> {
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        source.get(N..).ok_or(Error::SourceTooShort)
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+    }
+}
+
+// Length of dynamic string is given by its dimension member.
+#[derive(Default)]
+pub struct DynStringDeserializer<'a> {
+    source: Option<&'a [u8]>,
+    length: Option<usize>,
+}
+
+impl<'a> DynStringDeserializer<'a> {
+    pub fn get_data(&self) -> Result<&'a str, Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        let length = self.length.ok_or(Error::NotDeserialized)?;
+        let data = source.get(..length).ok_or(Error::SourceTooShort)?;
+        std::str::from_utf8(data).map_err(|_| Error::InvalidString)
+    }
+
+    pub fn set_length(&mut self, length: usize) {
+        self.length = Some(length);
+    }
+}
+
+impl<'a> Deserializer<'a> for DynStringDeserializer<'a> {
+    fn set_source(&mut self, source: &'a [u8]) {
+        self.init();
+        self.source = Some(source);
+    }
+
+    fn source_set(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn end(&mut self) -> Result<&'a [u8], Error> {
+        let source = self.source.ok_or(Error::SourceNotSet)?;
+        let length = self.length.ok_or(Error::NotDeserialized)?;
+        source.get(length..).ok_or(Error::SourceTooShort)
+    }
+
+    fn init(&mut self) {
+        self.source = None;
+        self.length = None;
+    }
+}
+";
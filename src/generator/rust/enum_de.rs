@@ -0,0 +1,63 @@
+use super::*;
+
+pub fn generate_enum_deserializer(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.deserializer_typename()));
+    writer.scope_in();
+    writer.write_line(&format!("native_: {},", m.underlaying_type.deserializer_typename()));
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> {}", m.deserializer_typename()));
+    writer.scope_in();
+    generate_get(m, writer);
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> abf::Deserializer<'a> for {}", m.deserializer_typename()));
+    writer.scope_in();
+    generate_set_source(writer);
+    generate_source_set(writer);
+    generate_end(writer);
+    generate_init(writer);
+    writer.scope_out(false);
+}
+
+fn generate_get(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("pub fn get_data(&self) -> Result<{}, abf::Error>", m.name));
+    writer.scope_in();
+    writer.write_with_offset("match self.native_.get_data()?");
+    writer.scope_in();
+    for c in &m.constants {
+        writer.write_line(&format!("{} => Ok({}::{}),", c.value, m.name, c.name));
+    }
+    writer.write_line("_ => Err(abf::Error::UnknownEnumValue),");
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
+
+fn generate_set_source(writer: &mut Writer) {
+    writer.write_with_offset("fn set_source(&mut self, source: &'a [u8])");
+    writer.scope_in();
+    writer.write_line("self.native_.set_source(source);");
+    writer.scope_out(false);
+}
+
+fn generate_source_set(writer: &mut Writer) {
+    writer.write_with_offset("fn source_set(&self) -> bool");
+    writer.scope_in();
+    writer.write_line("self.native_.source_set()");
+    writer.scope_out(false);
+}
+
+fn generate_end(writer: &mut Writer) {
+    writer.write_with_offset("fn end(&mut self) -> Result<&'a [u8], abf::Error>");
+    writer.scope_in();
+    writer.write_line("self.native_.end()");
+    writer.scope_out(false);
+}
+
+fn generate_init(writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    writer.write_line("self.native_.init();");
+    writer.scope_out(false);
+}
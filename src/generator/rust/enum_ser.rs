@@ -0,0 +1,49 @@
+use super::*;
+
+pub fn generate_enum_serializer(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.serializer_typename()));
+    writer.scope_in();
+    writer.write_line(&format!("native_: {},", m.underlaying_type.serializer_typename()));
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl {}", m.serializer_typename()));
+    writer.scope_in();
+    generate_with_method(m, writer);
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl abf::Serializer for {}", m.serializer_typename()));
+    writer.scope_in();
+    generate_size(writer);
+    generate_serialize(writer);
+    generate_init(writer);
+    writer.scope_out(false);
+}
+
+fn generate_with_method(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_with_offset(&format!("pub fn set_data(&mut self, value: {})", m.native_typename()));
+    writer.scope_in();
+    writer.write_line(&format!("self.native_.set_data(value as {});", m.underlaying_type.native_typename()));
+    writer.scope_out(false);
+}
+
+fn generate_size(writer: &mut Writer) {
+    writer.write_with_offset("fn size(&self) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    writer.write_line("self.native_.size()");
+    writer.scope_out(false);
+}
+
+fn generate_serialize(writer: &mut Writer) {
+    writer.write_with_offset("fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    writer.write_line("self.native_.serialize(dest)");
+    writer.scope_out(false);
+}
+
+fn generate_init(writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    writer.write_line("self.native_.init();");
+    writer.scope_out(false);
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+pub fn generate_enum_type(m: &EnumMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Debug, Clone, Copy, PartialEq, Eq)]");
+    writer.write_line(&format!("#[repr({})]", m.underlaying_type.native_typename()));
+    writer.write(&format!("pub enum {}", m.name));
+    writer.scope_in();
+    for c in &m.constants {
+        writer.write_line(&format!("{} = {},", c.name, c.value))
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+}
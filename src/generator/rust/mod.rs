@@ -0,0 +1,79 @@
+use std::io::Write;
+
+use super::*;
+
+mod rust_memory_detail;
+mod enum_type;
+mod enum_ser;
+mod enum_de;
+mod struct_ser;
+mod struct_de;
+mod view_ser;
+mod view_de;
+mod abf_core;
+#[cfg(test)]
+mod test;
+
+trait RustMemoryDetail {
+    fn name(&self) -> String;
+    fn user_value_serializable(&self) -> bool;
+    fn directly_deserializable(&self) -> bool;
+    fn serializer_typename(&self) -> String;
+    fn deserializer_typename(&self) -> String;
+    fn native_typename(&self) -> String;
+
+    fn variable(&self) -> String {
+        utils::to_snake_case(&self.name())
+    }
+}
+
+fn checksum_call(c: &ChecksumReference, data: &str) -> String {
+    match c.algorithm {
+        ChecksumAlgorithm::Sum => format!("abf::sum({}) as {}", data, c.native.native_typename()),
+        _ => format!("abf::{}({})", c.algorithm.name(), data),
+    }
+}
+
+pub fn generate(m: &[MemoryDeclaration], module: &Module, big_endian: bool, args: &Args) {
+    let output_module = module.name.as_str();
+
+    std::fs::create_dir_all(&args.output_dir)
+        .expect("could not create output directory");
+
+    let abf_file = format!("{}/abf.rs", args.output_dir);
+    let mut f = std::fs::File::create(&abf_file).expect("could not create abf.rs");
+    let abf_source_code = abf_core::SOURCE.replace("<<BIG_ENDIAN>>", &big_endian.to_string())
+        .replace("<<COMPILER_VERSION>>", env!("CARGO_PKG_VERSION"));
+    f.write_all(abf_source_code.as_bytes()).expect("write abf.rs failed");
+
+    let mut writer = Writer::new(&format!("{}/{}.rs", args.output_dir, output_module));
+    writer.write_line("#![allow(dead_code, unused_imports, non_snake_case, non_camel_case_types)]");
+    writer.write_line("");
+    writer.write_line("use super::abf;");
+    writer.write_line("pub use super::abf::{Deserializer, Error, Serializer};");
+    for import in &module.imports {
+        writer.write_line(&format!("use super::{}::*;", import));
+    }
+    for md in m.iter().filter(|md| md.module == module.name) {
+        writer.write_line("");
+        match &md.memory.memory {
+            MemoryType::Native(_) => panic!("Unexpected"),
+            MemoryType::Struct(s) => {
+                struct_ser::generate_struct_serializer(&s.borrow(), &mut writer);
+                writer.write_line("");
+                struct_de::generate_struct_deserializer(&s.borrow(), &mut writer);
+            },
+            MemoryType::View(v) => {
+                view_ser::generate_view_serializer(v, &mut writer);
+                writer.write_line("");
+                view_de::generate_view_deserializer(v, &mut writer);
+            },
+            MemoryType::Enum(e) => {
+                enum_type::generate_enum_type(e, &mut writer);
+                enum_ser::generate_enum_serializer(e, &mut writer);
+                writer.write_line("");
+                enum_de::generate_enum_deserializer(e, &mut writer);
+            },
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use super::*;
+
+impl RustMemoryDetail for Memory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+
+    fn user_value_serializable(&self) -> bool {
+        match self.array_size {
+            ArraySize::No => self.memory.user_value_serializable(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => true,
+            ArraySize::Exact(_) => true,
+            ArraySize::Until(_) => panic!("terminated array not supported"),
+        }
+    }
+
+    fn directly_deserializable(&self) -> bool {
+        match self.array_size {
+            ArraySize::No => self.memory.directly_deserializable(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => self.is_string(),
+            ArraySize::Exact(_) => self.is_string(),
+            ArraySize::Until(_) => panic!("terminated array not supported"),
+        }
+    }
+
+    fn serializer_typename(&self) -> String {
+        match self.array_size {
+            ArraySize::No => self.memory.serializer_typename(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest if self.is_string() => "abf::DynStringSerializer".into(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => format!("abf::DynArraySerializer<{}>", self.memory.serializer_typename()),
+            ArraySize::Exact(s) if self.is_string() => format!("abf::StringSerializer<{}>", s),
+            ArraySize::Exact(s) => format!("abf::ArraySerializer<{}, {}>", self.memory.serializer_typename(), s),
+            ArraySize::Until(_) => panic!("terminated array not supported"),
+        }
+    }
+
+    fn deserializer_typename(&self) -> String {
+        match self.array_size {
+            ArraySize::No => self.memory.deserializer_typename(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest if self.is_string() => "abf::DynStringDeserializer<'a>".into(),
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest => format!("abf::DynArrayDeserializer<'a, {}>", self.memory.deserializer_typename()),
+            ArraySize::Exact(s) if self.is_string() => format!("abf::StringDeserializer<'a, {}>", s),
+            ArraySize::Exact(s) => format!("abf::ArrayDeserializer<'a, {}, {}>", self.memory.deserializer_typename(), s),
+            ArraySize::Until(_) => panic!("terminated array not supported"),
+        }
+    }
+
+    fn native_typename(&self) -> String {
+        if self.is_string() {
+            "&str".into()
+        } else {
+            self.memory.native_typename()
+        }
+    }
+}
+
+impl RustMemoryDetail for MemoryType {
+    fn name(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.name(),
+            MemoryType::Struct(m) => m.borrow().name(),
+            MemoryType::View(m) => m.name(),
+            MemoryType::Enum(m) => m.name(),
+        }
+    }
+
+    fn user_value_serializable(&self) -> bool {
+        match &self {
+            MemoryType::Native(m) => m.user_value_serializable(),
+            MemoryType::Struct(m) => m.borrow().user_value_serializable(),
+            MemoryType::View(m) => m.user_value_serializable(),
+            MemoryType::Enum(m) => m.user_value_serializable(),
+        }
+    }
+
+    fn directly_deserializable(&self) -> bool {
+        match &self {
+            MemoryType::Native(m) => m.directly_deserializable(),
+            MemoryType::Struct(m) => m.borrow().directly_deserializable(),
+            MemoryType::View(m) => m.directly_deserializable(),
+            MemoryType::Enum(m) => m.directly_deserializable(),
+        }
+    }
+
+    fn serializer_typename(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.serializer_typename(),
+            MemoryType::Struct(m) => m.borrow().serializer_typename(),
+            MemoryType::View(m) => m.serializer_typename(),
+            MemoryType::Enum(m) => m.serializer_typename(),
+        }
+    }
+
+    fn deserializer_typename(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.deserializer_typename(),
+            MemoryType::Struct(m) => m.borrow().deserializer_typename(),
+            MemoryType::View(m) => m.deserializer_typename(),
+            MemoryType::Enum(m) => m.deserializer_typename(),
+        }
+    }
+
+    fn native_typename(&self) -> String {
+        match &self {
+            MemoryType::Native(m) => m.native_typename(),
+            MemoryType::Struct(m) => m.borrow().native_typename(),
+            MemoryType::View(m) => m.native_typename(),
+            MemoryType::Enum(m) => m.native_typename(),
+        }
+    }
+}
+
+impl RustMemoryDetail for NativeType {
+    fn name(&self) -> String {
+        match self {
+            NativeType::Bool => "b".into(),
+            NativeType::U8 => "u8".into(),
+            NativeType::U16 => "u16".into(),
+            NativeType::U24 => "u24".into(),
+            NativeType::U32 => "u32".into(),
+            NativeType::U64 => "u64".into(),
+            NativeType::ConstU8(_) => "cu8".into(),
+            NativeType::ConstU16(_) => "cu16".into(),
+            NativeType::ConstU24(_) => "cu24".into(),
+            NativeType::ConstU32(_) => "cu32".into(),
+            NativeType::ConstU64(_) => "cu64".into(),
+            NativeType::I8 => "i8".into(),
+            NativeType::I16 => "i16".into(),
+            NativeType::I32 => "i32".into(),
+            NativeType::I64 => "i64".into(),
+            NativeType::F32 => "f32".into(),
+            NativeType::F64 => "f64".into(),
+            NativeType::Char => "string".into(),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.name(),
+            NativeType::ArrayDimensionReference(r) => r.origin.as_ref().name(),
+            NativeType::StructMemberSize(m) => m.native.name(),
+            NativeType::Checksum(c) => c.native.name(),
+            NativeType::BitField(bf) => bf.native.name(),
+        }
+    }
+    fn user_value_serializable(&self) -> bool {
+        match self {
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(_) => false,
+            NativeType::ArrayDimensionReference(_) => false,
+            NativeType::ConstU8(_) => false,
+            NativeType::ConstU16(_) => false,
+            NativeType::ConstU24(_) => false,
+            NativeType::ConstU32(_) => false,
+            NativeType::ConstU64(_) => false,
+            NativeType::StructMemberSize(_) => false,
+            NativeType::Checksum(_) => false,
+            _ => true
+        }
+    }
+    fn directly_deserializable(&self) -> bool {
+        true
+    }
+    fn serializer_typename(&self) -> String {
+        match self {
+            NativeType::ConstU8(v) => format!("abf::ConstantSerializer<1, {}>", v),
+            NativeType::ConstU16(v) => format!("abf::ConstantSerializer<2, {}>", v),
+            NativeType::ConstU24(v) => format!("abf::ConstantSerializer<3, {}>", v),
+            NativeType::ConstU32(v) => format!("abf::ConstantSerializer<4, {}>", v),
+            NativeType::ConstU64(v) => format!("abf::ConstantSerializer<8, {}>", v),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.serializer_typename(),
+            NativeType::ArrayDimensionReference(r) => r.origin.serializer_typename(),
+            NativeType::StructMemberSize(m) => m.native.serializer_typename(),
+            NativeType::Checksum(c) => c.native.serializer_typename(),
+            NativeType::BitField(bf) => format!("abf::BitFieldSerializer<{}, {}, {}, {}, {}>",
+                bf.native.native_typename(),
+                bf.container.size(),
+                bf.shift,
+                bf.bits,
+                bf.closes_group),
+            _ => format!("abf::NativeSerializer<{}, {}>", self.native_typename(), self.size()),
+        }
+    }
+    fn deserializer_typename(&self) -> String {
+        match self {
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.deserializer_typename(),
+            NativeType::ArrayDimensionReference(r) => r.origin.deserializer_typename(),
+            NativeType::StructMemberSize(m) => m.native.deserializer_typename(),
+            NativeType::Checksum(c) => c.native.deserializer_typename(),
+            NativeType::BitField(bf) => format!("abf::BitFieldDeserializer<'a, {}, {}, {}, {}, {}>",
+                bf.native.native_typename(),
+                bf.container.size(),
+                bf.shift,
+                bf.bits,
+                bf.closes_group),
+            _ => format!("abf::NativeDeserializer<'a, {}, {}>", self.native_typename(), self.size()),
+        }
+    }
+    fn native_typename(&self) -> String {
+        match self {
+            NativeType::Bool => "bool".into(),
+            NativeType::U8 => "u8".into(),
+            NativeType::U16 => "u16".into(),
+            NativeType::U24 => "u32".into(),
+            NativeType::U32 => "u32".into(),
+            NativeType::U64 => "u64".into(),
+            NativeType::ConstU8(_) => "u8".into(),
+            NativeType::ConstU16(_) => "u16".into(),
+            NativeType::ConstU24(_) => "u32".into(),
+            NativeType::ConstU32(_) => "u32".into(),
+            NativeType::ConstU64(_) => "u64".into(),
+            NativeType::I8 => "i8".into(),
+            NativeType::I16 => "i16".into(),
+            NativeType::I32 => "i32".into(),
+            NativeType::I64 => "i64".into(),
+            NativeType::F32 => "f32".into(),
+            NativeType::F64 => "f64".into(),
+            NativeType::Char => "u8".into(),
+            NativeType::Unknown => panic!("unknown type"),
+            NativeType::ViewKeyReference(m) => m.native_key.native_typename(),
+            NativeType::ArrayDimensionReference(r) => r.origin.native_typename(),
+            NativeType::StructMemberSize(m) => m.native.native_typename(),
+            NativeType::Checksum(c) => c.native.native_typename(),
+            NativeType::BitField(bf) => bf.native.native_typename(),
+        }
+    }
+}
+
+impl RustMemoryDetail for StructMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn user_value_serializable(&self) -> bool {
+        true
+    }
+    fn directly_deserializable(&self) -> bool {
+        false
+    }
+    fn serializer_typename(&self) -> String {
+        format!("{}Ser", self.name())
+    }
+    fn deserializer_typename(&self) -> String {
+        format!("{}De<'a>", self.name())
+    }
+    fn native_typename(&self) -> String {
+        panic!("native struct not supported yet")
+    }
+}
+
+impl RustMemoryDetail for ViewMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn user_value_serializable(&self) -> bool {
+        true
+    }
+    fn directly_deserializable(&self) -> bool {
+        false
+    }
+    fn serializer_typename(&self) -> String {
+        format!("{}Ser", self.name())
+    }
+    fn deserializer_typename(&self) -> String {
+        format!("{}De<'a>", self.name())
+    }
+    fn native_typename(&self) -> String {
+        panic!("native view not supported yet")
+    }
+}
+
+impl RustMemoryDetail for EnumMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn user_value_serializable(&self) -> bool {
+        true
+    }
+    fn directly_deserializable(&self) -> bool {
+        true
+    }
+    fn serializer_typename(&self) -> String {
+        format!("{}Ser", self.name)
+    }
+    fn deserializer_typename(&self) -> String {
+        format!("{}De<'a>", self.name)
+    }
+    fn native_typename(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl RustMemoryDetail for StructMemberMemory {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn user_value_serializable(&self) -> bool {
+        self.memory.borrow().user_value_serializable()
+    }
+    fn directly_deserializable(&self) -> bool {
+        self.memory.borrow().directly_deserializable()
+    }
+    fn serializer_typename(&self) -> String {
+        self.memory.borrow().serializer_typename()
+    }
+    fn deserializer_typename(&self) -> String {
+        self.memory.borrow().deserializer_typename()
+    }
+    fn native_typename(&self) -> String {
+        self.memory.borrow().native_typename()
+    }
+}
+
+impl RustMemoryDetail for ViewPosibilityMemory {
+    fn name(&self) -> String {
+        self.memory.name()
+    }
+    fn user_value_serializable(&self) -> bool {
+        self.memory.user_value_serializable()
+    }
+    fn directly_deserializable(&self) -> bool {
+        self.memory.directly_deserializable()
+    }
+    fn serializer_typename(&self) -> String {
+        self.memory.serializer_typename()
+    }
+    fn deserializer_typename(&self) -> String {
+        self.memory.deserializer_typename()
+    }
+    fn native_typename(&self) -> String {
+        self.memory.native_typename()
+    }
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+pub fn generate_struct_deserializer(m: &StructMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.deserializer_typename()));
+    writer.scope_in();
+    writer.write_line("source_: Option<&'a [u8]>,");
+    for i in 0..m.fields.len() {
+        generate_member_deserialzier(m, i, writer);
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> {}", m.deserializer_typename()));
+    writer.scope_in();
+    for i in 0..m.fields.len() {
+        generate_deserialize(m, i, writer);
+    }
+    for sm in &m.fields {
+        generate_has_method(sm, writer);
+    }
+    generate_verify(m, writer);
+    for i in 0..m.fields.len() {
+        generate_resolve(m, i, writer);
+    }
+    for i in 0..m.fields.len() {
+        generate_optional_end(m, i, writer);
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> abf::Deserializer<'a> for {}", m.deserializer_typename()));
+    writer.scope_in();
+    generate_set_source(writer);
+    generate_source_set(writer);
+    generate_end(m, writer);
+    generate_init(m, writer);
+    writer.scope_out(false);
+}
+
+fn generate_member_deserialzier(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    writer.write_line(&format!("{}_: {},", sm.name, sm.deserializer_typename()));
+}
+
+fn generate_deserialize(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    if sm.directly_deserializable() {
+        writer.write_with_offset(&format!("pub fn {}(&mut self) -> Result<{}, abf::Error>",
+            sm.name,
+            sm.native_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("self.resolve_{}()?;", sm.name));
+        generate_if_absent_error(sm, writer);
+        writer.write_line(&format!("self.{}_.get_data()", sm.name));
+        writer.scope_out(false);
+    } else {
+        writer.write_with_offset(&format!("pub fn {}(&mut self) -> Result<&mut {}, abf::Error>",
+            sm.name,
+            sm.deserializer_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("self.resolve_{}()?;", sm.name));
+        generate_if_absent_error(sm, writer);
+        writer.write_line(&format!("Ok(&mut self.{}_)", sm.name));
+        writer.scope_out(false);
+    }
+}
+
+fn generate_if_absent_error(sm: &StructMemberMemory, writer: &mut Writer) {
+    if sm.condition.is_some() {
+        writer.write_with_offset(&format!("if !self.has_{}()?", sm.variable()));
+        writer.scope_in();
+        writer.write_line("return Err(abf::Error::NotPresent);");
+        writer.scope_out(false);
+    }
+}
+
+fn generate_has_method(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("pub fn has_{}(&mut self) -> Result<bool, abf::Error>", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("let flag = abf::Native::to_bits(self.{}()?);", condition.flag.name));
+        writer.write_line(&format!("Ok({})", condition.test("flag")));
+        writer.scope_out(false);
+    }
+}
+
+// Bytes of absent optional member are skipped, it ends where it begins.
+fn generate_optional_end(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    if sm.condition.is_some() {
+        writer.write_with_offset(&format!("fn end_of_{}(&mut self) -> Result<&'a [u8], abf::Error>", sm.name));
+        writer.scope_in();
+        writer.write_line(&format!("self.resolve_{}()?;", sm.name));
+        writer.write_with_offset(&format!("if self.has_{}()?", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("return self.{}_.end();", sm.name));
+        writer.scope_out(false);
+        writer.write_line(&member_end(m, i - 1));
+        writer.scope_out(false);
+    }
+}
+
+fn member_end(m: &StructMemory, i: usize) -> String {
+    match &m.fields[i].condition {
+        Some(_) => format!("self.end_of_{}()", m.fields[i].name),
+        None => format!("self.{}_.end()", m.fields[i].name),
+    }
+}
+
+// Covered bytes are the difference between sources of first member and member after last one.
+fn generate_verify(m: &StructMemory, writer: &mut Writer) {
+    let checksums = m.get_checksums();
+    if checksums.is_empty() {
+        return
+    }
+    writer.write_with_offset("pub fn verify(&mut self) -> Result<(), abf::Error>");
+    writer.scope_in();
+    let resolved = checksums.iter().map(|c| c.last.index.max(c.origin.index)).max().unwrap();
+    writer.write_line(&format!("self.resolve_{}()?;", m.fields[resolved].name));
+    for c in &checksums {
+        if c.first.index == 0 {
+            writer.write_line(&format!("let {}_begin = self.source_.ok_or(abf::Error::SourceNotSet)?;", c.origin.name));
+        } else {
+            writer.write_line(&format!("let {}_begin = {}?;", c.origin.name, member_end(m, c.first.index - 1)));
+        }
+        writer.write_line(&format!("let {}_end = {}?;", c.origin.name, member_end(m, c.last.index)));
+        writer.write_line(&format!("let {}_covered = &{}_begin[..{}_begin.len() - {}_end.len()];",
+            c.origin.name, c.origin.name, c.origin.name, c.origin.name));
+        writer.write_with_offset(&format!("if self.{}()? != {}",
+            c.origin.name,
+            checksum_call(c, &format!("{}_covered", c.origin.name))));
+        writer.scope_in();
+        writer.write_line("return Err(abf::Error::ChecksumMismatch);");
+        writer.scope_out(false);
+    }
+    writer.write_line("Ok(())");
+    writer.scope_out(false);
+}
+
+// Source of member is end of previous member, members are resolved on demand.
+fn generate_resolve(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    writer.write_with_offset(&format!("fn resolve_{}(&mut self) -> Result<(), abf::Error>", sm.name));
+    writer.scope_in();
+    writer.write_with_offset(&format!("if !self.{}_.source_set()", sm.name));
+    writer.scope_in();
+    if i == 0 {
+        writer.write_line("let source = self.source_.ok_or(abf::Error::SourceNotSet)?;");
+    } else {
+        writer.write_line(&format!("self.resolve_{}()?;", m.fields[i - 1].name));
+        writer.write_line(&format!("let source = {}?;", member_end(m, i - 1)));
+    }
+    writer.write_line(&format!("self.{}_.set_source(source);", sm.name));
+    if let Some(dimension) = sm.get_array_size_reference() {
+        if dimension.index < i {
+            writer.write_line(&format!("let length = abf::Native::to_bits(self.{}()?) as usize;", dimension.name));
+            writer.write_line(&format!("self.{}_.set_length(length);", sm.name));
+        }
+    }
+    if let Some(key) = m.get_view_key_reference_member_index(i) {
+        if key < i {
+            writer.write_line(&format!("let type_id = abf::Native::to_bits(self.{}()?) as usize;", m.fields[key].name));
+            writer.write_line(&format!("self.{}_.set_type_id(type_id)?;", sm.name));
+        }
+    }
+    writer.scope_out(false);
+    writer.write_line("Ok(())");
+    writer.scope_out(false);
+}
+
+fn generate_set_source(writer: &mut Writer) {
+    writer.write_with_offset("fn set_source(&mut self, source: &'a [u8])");
+    writer.scope_in();
+    writer.write_line("self.init();");
+    writer.write_line("self.source_ = Some(source);");
+    writer.scope_out(false);
+}
+
+fn generate_source_set(writer: &mut Writer) {
+    writer.write_with_offset("fn source_set(&self) -> bool");
+    writer.scope_in();
+    writer.write_line("self.source_.is_some()");
+    writer.scope_out(false);
+}
+
+fn generate_end(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn end(&mut self) -> Result<&'a [u8], abf::Error>");
+    writer.scope_in();
+    if let Some(last) = m.fields.last() {
+        writer.write_line(&format!("self.resolve_{}()?;", last.name));
+        writer.write_line(&member_end(m, last.index));
+    } else {
+        writer.write_line("self.source_.ok_or(abf::Error::SourceNotSet)");
+    }
+    writer.scope_out(false);
+}
+
+fn generate_init(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    writer.write_line("self.source_ = None;");
+    for f in &m.fields {
+        writer.write_line(&format!("self.{}_.init();", f.name));
+    }
+    writer.scope_out(false);
+}
@@ -0,0 +1,151 @@
+use super::*;
+
+pub fn generate_struct_serializer(m: &StructMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.serializer_typename()));
+    writer.scope_in();
+    for i in 0..m.fields.len() {
+        generate_member_serialzier(m, i, writer);
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl {}", m.serializer_typename()));
+    writer.scope_in();
+    for i in 0..m.fields.len() {
+        if m.fields[i].user_value_serializable() {
+            generate_with_method(m, i, writer);
+        }
+    }
+    for sm in &m.fields {
+        generate_has_method(sm, writer);
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl abf::Serializer for {}", m.serializer_typename()));
+    writer.scope_in();
+    generate_size(m, writer);
+    generate_serialize(m, writer);
+    generate_init(m, writer);
+    writer.scope_out(false);
+}
+
+fn generate_member_serialzier(m: &StructMemory, i: usize, writer: &mut Writer) {
+    writer.write_line(&format!("{}_: {},", m.fields[i].name, m.fields[i].as_ref().serializer_typename()));
+}
+
+fn generate_with_method(m: &StructMemory, i: usize, writer: &mut Writer) {
+    let sm = m.fields[i].as_ref();
+    if sm.directly_deserializable() {
+        writer.write_with_offset(&format!("pub fn with_{}(&mut self, value: {})",
+            sm.variable(),
+            sm.native_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("self.{}_.set_data(value);", sm.name));
+        writer.scope_out(false);
+    } else {
+        writer.write_with_offset(&format!("pub fn with_{}(&mut self) -> &mut {}",
+            sm.variable(),
+            sm.serializer_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("&mut self.{}_", sm.name));
+        writer.scope_out(false);
+    }
+}
+
+// Optional member is written only when its flag, set by user, says so.
+fn generate_has_method(sm: &StructMemberMemory, writer: &mut Writer) {
+    if let Some(condition) = &sm.condition {
+        writer.write_with_offset(&format!("pub fn has_{}(&self) -> Result<bool, abf::Error>", sm.variable()));
+        writer.scope_in();
+        writer.write_line(&format!("let flag = abf::Native::to_bits(self.{}_.get_data()?);", condition.flag.name));
+        writer.write_line(&format!("Ok({})", condition.test("flag")));
+        writer.scope_out(false);
+    }
+}
+
+fn generate_size(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn size(&self) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    if m.fields.is_empty() {
+        writer.write_line("Ok(0)");
+    } else {
+        writer.write_line("let mut size = 0;");
+        for sm in &m.fields {
+            match &sm.condition {
+                Some(_) => writer.write_line(&format!("if self.has_{}()? {{ size += self.{}_.size()?; }}", sm.variable(), sm.name)),
+                None => writer.write_line(&format!("size += self.{}_.size()?;", sm.name)),
+            }
+        }
+        writer.write_line("Ok(size)");
+    }
+    writer.scope_out(false);
+}
+
+fn generate_serialize(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    if m.fields.is_empty() {
+        writer.write_line("let _ = dest;");
+        writer.write_line("Ok(0)");
+        writer.scope_out(false);
+        return
+    }
+    for sm in &m.fields {
+        generate_reference_setter(sm, writer);
+    }
+    writer.write_line("let mut offset = 0;");
+    let checksums = m.get_checksums();
+    for sm in &m.fields {
+        for c in checksums.iter().filter(|c| c.first.index == sm.index) {
+            writer.write_line(&format!("let {}_begin = offset;", c.origin.name));
+        }
+        if checksums.iter().any(|c| c.origin.index == sm.index) {
+            writer.write_line(&format!("let {}_offset = offset;", sm.name));
+        }
+        match &sm.condition {
+            Some(_) => writer.write_line(&format!("if self.has_{}()? {{ offset += self.{}_.serialize(&mut dest[offset..])?; }}", sm.variable(), sm.name)),
+            None => writer.write_line(&format!("offset += self.{}_.serialize(&mut dest[offset..])?;", sm.name)),
+        }
+        for c in checksums.iter().filter(|c| c.last.index == sm.index) {
+            writer.write_line(&format!("let {}_end = offset;", c.origin.name));
+        }
+    }
+    // checksum is written again once covered bytes are in place
+    for c in &checksums {
+        writer.write_line(&format!("self.{}_.set_data({});",
+            c.origin.name,
+            checksum_call(c, &format!("&dest[{}_begin..{}_end]", c.origin.name, c.origin.name))));
+        writer.write_line(&format!("self.{}_.serialize(&mut dest[{}_offset..])?;", c.origin.name, c.origin.name));
+    }
+    writer.write_line("Ok(offset)");
+    writer.scope_out(false);
+}
+
+// Reference members (.key, .dimension, .size) are filled from referenced member right before write.
+fn generate_reference_setter(sm: &StructMemberMemory, writer: &mut Writer) {
+    let memory = sm.memory.borrow();
+    let (value, native) = match &memory.memory {
+        MemoryType::Native(NativeType::Checksum(_)) => {
+            // placeholder until covered members are written
+            writer.write_line(&format!("self.{}_.set_data(0);", sm.name));
+            return
+        },
+        MemoryType::Native(NativeType::ViewKeyReference(vkr)) =>
+            (format!("self.{}_.type_id()?", vkr.view.name), vkr.native_key.clone()),
+        MemoryType::Native(NativeType::ArrayDimensionReference(adr)) =>
+            (format!("self.{}_.length()", adr.array.name), adr.origin.clone()),
+        MemoryType::Native(NativeType::StructMemberSize(sms)) =>
+            (format!("self.{}_.size()?", sms.member.name), sms.native.clone()),
+        _ => return,
+    };
+    writer.write_line(&format!("self.{}_.set_data(abf::fit({}, {})?);", sm.name, value, native.size()));
+}
+
+fn generate_init(m: &StructMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    for sm in &m.fields {
+        writer.write_line(&format!("self.{}_.init();", sm.name));
+    }
+    writer.scope_out(false);
+}
@@ -0,0 +1,100 @@
+mod tests;
+
+use super::*;
+
+static ABF_DIR: &str = "src/generator/cpp/test/cpp_tests";
+static TEST_DIR: &str = "src/generator/rust/test/rust_tests";
+
+fn compile_rust(main_file: &str, out: &str) {
+    let term_out = std::process::Command::new("rustc")
+        .args([
+            "--edition",
+            "2021",
+            "--test",
+            main_file,
+            "-o",
+            out,
+        ])
+        .output()
+        .expect("compilation failed");
+
+    println!("{}", String::from_utf8(term_out.stderr).unwrap());
+}
+
+fn generate_test(buffer_file: &str, test_file: &str, generate: bool, big_endian: bool) {
+    let endian: String = if big_endian { "big".into() } else { "little".into() };
+    let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let test_file_noext = std::path::Path::new(test_file)
+        .file_stem()
+        .expect("could not extract stem")
+        .to_str()
+        .unwrap();
+
+    let test_out_dir = format!("{}/{}/{}_{}_endian", pwd, TEST_DIR, test_file_noext, endian);
+
+    if generate {
+        let _ = std::fs::remove_dir_all(&test_out_dir); // try remove folder
+    }
+
+    let buffer_file_path = format!("{}/{}/{}", pwd, ABF_DIR, buffer_file);
+
+    if generate {
+        generate_code(
+            interpet_memory(&buffer_file_path)
+                .unwrap_or_else(|e| panic!("interpret failed: {}", render_diagnostics(&e))),
+            &Args {
+                protofile: buffer_file.into(),
+                language: Language::Rust,
+                endian: Some(endian),
+                output_dir: test_out_dir.clone(),
+                no_exceptions: false,
+            },
+        );
+    }
+
+    // test is crate root, generated modules are its siblings
+    let main_file = format!("{}/main.rs", test_out_dir);
+    std::fs::copy(format!("{}/{}/{}", pwd, TEST_DIR, test_file), &main_file)
+        .expect("could not copy test");
+
+    let out = format!("{}/{}", test_out_dir, test_file_noext);
+
+    compile_rust(&main_file, &out);
+
+    let result = std::process::Command::new(&out)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()
+        .expect("test failed");
+
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    let stderr = String::from_utf8(result.stderr).unwrap();
+
+    let status = result.status.success();
+
+    println!("{}", stdout);
+    println!("{}", stderr);
+
+    assert!(status);
+}
+
+// Generator refuses schema before any file is written.
+fn unsupported_feature(buffer_file: &str) -> String {
+    let pwd = std::env::current_dir().unwrap().to_string_lossy().to_string();
+    let buffer_file_path = format!("{}/{}/{}", pwd, ABF_DIR, buffer_file);
+    let result = crate::generator::generate(
+        interpet_memory(&buffer_file_path)
+            .unwrap_or_else(|e| panic!("interpret failed: {}", render_diagnostics(&e))),
+        &Args {
+            protofile: buffer_file.into(),
+            language: Language::Rust,
+            endian: Some("big".into()),
+            output_dir: format!("{}/{}/unsupported", pwd, TEST_DIR),
+            no_exceptions: false,
+        },
+    );
+    match result {
+        Ok(()) => panic!("generation succeeded"),
+        Err(e) => e.to_string(),
+    }
+}
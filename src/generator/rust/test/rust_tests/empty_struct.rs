@@ -0,0 +1,15 @@
+mod abf;
+mod empty_struct;
+
+use empty_struct::*;
+
+#[test]
+fn serde() {
+    let buffer = [0u8; 1024];
+    let empty_ser = EmptySer::default();
+    assert_eq!(empty_ser.size().unwrap(), 0);
+
+    let mut empty_de = EmptyDe::new(&buffer);
+    assert!(empty_de.source_set());
+    assert_eq!(empty_de.end().unwrap().len(), 1024);
+}
@@ -0,0 +1,27 @@
+mod abf;
+mod enum1;
+
+use enum1::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut car_ser = CarSer::default();
+    car_ser.set_data(Car::Audi);
+    assert_eq!(car_ser.serialize(&mut buffer).unwrap(), 1);
+
+    let car_de = CarDe::new(&buffer);
+    assert_eq!(car_de.get_data().unwrap(), Car::Audi);
+}
+
+#[test]
+fn unknown_value() {
+    let buffer = [1u8];
+    let car_de = CarDe::new(&buffer);
+    assert_eq!(car_de.get_data().err(), Some(Error::UnknownEnumValue));
+}
+
+#[test]
+fn compiler_version() {
+    assert!(!abf::COMPILER_VERSION.is_empty());
+}
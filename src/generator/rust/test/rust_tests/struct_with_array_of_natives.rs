@@ -0,0 +1,20 @@
+mod abf;
+mod struct_with_array_of_natives;
+
+use struct_with_array_of_natives::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut dim_ser = DimensionSer::default();
+    dim_ser.with_whl().get(0).set_data(10);
+    dim_ser.with_whl().get(1).set_data(20);
+    dim_ser.with_whl().get(2).set_data(30);
+    assert_eq!(dim_ser.serialize(&mut buffer).unwrap(), 6);
+
+    let mut dim_de = DimensionDe::new(&buffer);
+    assert_eq!(dim_de.whl().unwrap().get(2).unwrap().get_data().unwrap(), 30);
+    assert_eq!(dim_de.whl().unwrap().get(0).unwrap().get_data().unwrap(), 10);
+    assert_eq!(dim_de.whl().unwrap().get(1).unwrap().get_data().unwrap(), 20);
+    assert_eq!(dim_de.whl().unwrap().get(3).err(), Some(Error::OutOfRange));
+}
@@ -0,0 +1,25 @@
+mod abf;
+mod struct_with_arrays_of_views;
+
+use struct_with_arrays_of_views::*;
+
+#[test]
+fn serde() {
+    let mut numbers_ser = NumbersSeqSer::default();
+    numbers_ser.with_num0().get(0).with_u8(1);
+    numbers_ser.with_num0().get(1).with_u16(2);
+    numbers_ser.with_num1().get(0).with_u32(3);
+    numbers_ser.with_num1().get(1).with_u8(4);
+    numbers_ser.with_num2().get(0).with_u16(5);
+    numbers_ser.with_num2().get(1).with_u32(6);
+    let buffer = numbers_ser.serialize_to_vec().unwrap();
+    assert_eq!(buffer.len(), 14);
+
+    let mut numbers_de = NumbersSeqDe::new(&buffer);
+    assert_eq!(numbers_de.num0().unwrap().get(0).unwrap().u8().unwrap(), 1);
+    assert_eq!(numbers_de.num0().unwrap().get(1).unwrap().u16().unwrap(), 2);
+    assert_eq!(numbers_de.num1().unwrap().get(0).unwrap().u32().unwrap(), 3);
+    assert_eq!(numbers_de.num1().unwrap().get(1).unwrap().u8().unwrap(), 4);
+    assert_eq!(numbers_de.num2().unwrap().get(0).unwrap().u16().unwrap(), 5);
+    assert_eq!(numbers_de.num2().unwrap().get(1).unwrap().u32().unwrap(), 6);
+}
@@ -0,0 +1,43 @@
+mod abf;
+mod struct_with_bit_fields;
+
+use struct_with_bit_fields::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut status_ser = StatusSer::default();
+    status_ser.with_mode(5);
+    status_ser.with_enabled(true);
+    status_ser.with_reserved(0xA);
+    status_ser.with_id(0x1234);
+    status_ser.with_level(-3);
+    status_ser.with_counter(0x123);
+    assert_eq!(status_ser.size().unwrap(), 5);
+    assert_eq!(status_ser.serialize(&mut buffer).unwrap(), 5);
+    assert_eq!(buffer[..5], [0xBA, 0x12, 0x34, 0xE9, 0x23]);
+
+    let mut status_de = StatusDe::new(&buffer);
+    assert_eq!(status_de.mode().unwrap(), 5);
+    assert_eq!(status_de.enabled().unwrap(), true);
+    assert_eq!(status_de.reserved().unwrap(), 0xA);
+    assert_eq!(status_de.id().unwrap(), 0x1234);
+    assert_eq!(status_de.level().unwrap(), -3);
+    assert_eq!(status_de.counter().unwrap(), 0x123);
+}
+
+#[test]
+fn overflow() {
+    let mut buffer = [0u8; 1024];
+    let mut status_ser = StatusSer::default();
+    status_ser.with_mode(8);
+    status_ser.with_enabled(true);
+    status_ser.with_reserved(0);
+    status_ser.with_id(0);
+    status_ser.with_level(0);
+    status_ser.with_counter(0);
+    assert_eq!(status_ser.serialize(&mut buffer), Err(Error::Overflow));
+    status_ser.with_mode(0);
+    status_ser.with_level(-17);
+    assert_eq!(status_ser.serialize(&mut buffer), Err(Error::Overflow));
+}
@@ -0,0 +1,38 @@
+mod abf;
+mod struct_with_checksum;
+
+use struct_with_checksum::*;
+
+const EXPECTED: [u8; 13] = [
+    0x50, 0xA5, 0x03, 0x01, 0x02, 0x03, 0x06,
+    0x6F, 0xAA,
+    0x34, 0xDC, 0xC8, 0xAA
+];
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut frame_ser = FrameSer::default();
+    frame_ser.with_header(0xA5);
+    frame_ser.with_payload().get(0).set_data(1);
+    frame_ser.with_payload().get(1).set_data(2);
+    frame_ser.with_payload().get(2).set_data(3);
+    assert_eq!(frame_ser.serialize(&mut buffer).unwrap(), EXPECTED.len());
+    assert_eq!(buffer[..EXPECTED.len()], EXPECTED);
+
+    let mut frame_de = FrameDe::new(&buffer);
+    assert_eq!(frame_de.verify(), Ok(()));
+    assert_eq!(frame_de.header_crc().unwrap(), 0x50);
+    assert_eq!(frame_de.payload_sum().unwrap(), 0x06);
+    assert_eq!(frame_de.crc().unwrap(), 0x6FAA);
+    assert_eq!(frame_de.frame_crc().unwrap(), 0x34DCC8AA);
+}
+
+#[test]
+fn mismatch() {
+    let mut buffer = EXPECTED;
+    buffer[4] ^= 0x01;
+
+    let mut frame_de = FrameDe::new(&buffer);
+    assert_eq!(frame_de.verify(), Err(Error::ChecksumMismatch));
+}
@@ -0,0 +1,17 @@
+mod abf;
+mod struct_with_constant;
+
+use struct_with_constant::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut msg_ser = MessageSer::default();
+    msg_ser.with_value(288);
+    assert_eq!(msg_ser.serialize(&mut buffer).unwrap(), 4);
+
+    let mut msg_de = MessageDe::new(&buffer);
+    assert_eq!(msg_de.byte0().unwrap(), 224);
+    assert_eq!(msg_de.byte1().unwrap(), 3);
+    assert_eq!(msg_de.value().unwrap(), 288);
+}
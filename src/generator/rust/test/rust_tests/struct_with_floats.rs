@@ -0,0 +1,20 @@
+mod abf;
+mod struct_with_floats;
+
+use struct_with_floats::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut sample_ser = SampleSer::default();
+    sample_ser.with_temperature(21.5);
+    sample_ser.with_pressure(101325.25);
+    sample_ser.with_id(7);
+    assert_eq!(sample_ser.serialize(&mut buffer).unwrap(), 14);
+    assert_eq!(buffer[..4], [0x41, 0xAC, 0x00, 0x00]);
+
+    let mut sample_de = SampleDe::new(&buffer);
+    assert_eq!(sample_de.temperature().unwrap(), 21.5);
+    assert_eq!(sample_de.pressure().unwrap(), 101325.25);
+    assert_eq!(sample_de.id().unwrap(), 7);
+}
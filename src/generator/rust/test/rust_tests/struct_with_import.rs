@@ -0,0 +1,31 @@
+mod abf;
+mod common_header;
+mod struct_with_import;
+
+use struct_with_import::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut message_ser = MessageSer::default();
+    message_ser.with_header().with_version(1);
+    message_ser.with_header().with_length(4);
+    message_ser.with_payload(0xDEADBEEF);
+    assert_eq!(message_ser.serialize(&mut buffer).unwrap(), 7);
+    assert_eq!(buffer[..7], [0x01, 0x00, 0x04, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+    let mut message_de = MessageDe::new(&buffer);
+    assert_eq!(message_de.header().unwrap().version().unwrap(), 1);
+    assert_eq!(message_de.header().unwrap().length().unwrap(), 4);
+    assert_eq!(message_de.payload().unwrap(), 0xDEADBEEF);
+}
+
+#[test]
+fn imported_type() {
+    let mut buffer = [0u8; 1024];
+    let mut header_ser = common_header::HeaderSer::default();
+    header_ser.with_version(2);
+    header_ser.with_length(0x0102);
+    assert_eq!(header_ser.serialize(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer[..3], [0x02, 0x01, 0x02]);
+}
@@ -0,0 +1,41 @@
+mod abf;
+mod struct_with_natives;
+
+use struct_with_natives::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut xmass_ser = DateSer::default();
+    xmass_ser.with_year(2023);
+    xmass_ser.with_month(12);
+    xmass_ser.with_day(24);
+    assert_eq!(xmass_ser.serialize(&mut buffer).unwrap(), 4);
+
+    let mut xmass_de = DateDe::new(&buffer);
+    assert_eq!(xmass_de.day().unwrap(), 24);
+    assert_eq!(xmass_de.month().unwrap(), 12);
+    assert_eq!(xmass_de.year().unwrap(), 2023);
+}
+
+#[test]
+fn not_set() {
+    let mut buffer = [0u8; 1024];
+    let mut xmass_ser = DateSer::default();
+    xmass_ser.with_year(2023);
+    assert_eq!(xmass_ser.serialize(&mut buffer).err(), Some(Error::NotSet));
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut buffer = [0u8; 3];
+    let mut xmass_ser = DateSer::default();
+    xmass_ser.with_year(2023);
+    xmass_ser.with_month(12);
+    xmass_ser.with_day(24);
+    assert_eq!(xmass_ser.serialize(&mut buffer).err(), Some(Error::BufferTooSmall));
+
+    let mut xmass_de = DateDe::new(&buffer);
+    assert_eq!(xmass_de.day().unwrap(), 24);
+    assert_eq!(xmass_de.month().err(), Some(Error::SourceTooShort));
+}
@@ -0,0 +1,21 @@
+mod abf;
+mod struct_with_nostd_values;
+
+use struct_with_nostd_values::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut nostd_ser = NoStdValuesSer::default();
+    nostd_ser.with_a(120951);
+    assert_eq!(nostd_ser.serialize(&mut buffer).unwrap(), 6);
+    if abf::BIG_ENDIAN {
+        assert_eq!(buffer[..3], [0x01, 0xD8, 0x77]);
+    } else {
+        assert_eq!(buffer[..3], [0x77, 0xD8, 0x01]);
+    }
+
+    let mut nostd_de = NoStdValuesDe::new(&buffer);
+    assert_eq!(nostd_de.a().unwrap(), 120951);
+    assert_eq!(nostd_de.b().unwrap(), 1234);
+}
@@ -0,0 +1,46 @@
+mod abf;
+mod struct_with_optional;
+
+use struct_with_optional::*;
+
+#[test]
+fn present() {
+    let mut buffer = [0u8; 1024];
+    let mut packet_ser = PacketSer::default();
+    packet_ser.with_flags(0x08);
+    packet_ser.with_ext().with_code(1);
+    packet_ser.with_ext().with_value(0x0203);
+    packet_ser.with_extended(false);
+    packet_ser.with_trailer(0xFF);
+    assert_eq!(packet_ser.has_ext(), Ok(true));
+    assert_eq!(packet_ser.has_tail(), Ok(false));
+    assert_eq!(packet_ser.serialize(&mut buffer).unwrap(), 6);
+    assert_eq!(buffer[..6], [0x08, 0x01, 0x02, 0x03, 0x00, 0xFF]);
+
+    let mut packet_de = PacketDe::new(&buffer);
+    assert_eq!(packet_de.flags().unwrap(), 0x08);
+    assert_eq!(packet_de.has_ext(), Ok(true));
+    assert_eq!(packet_de.ext().unwrap().code().unwrap(), 1);
+    assert_eq!(packet_de.ext().unwrap().value().unwrap(), 0x0203);
+    assert_eq!(packet_de.has_tail(), Ok(false));
+    assert_eq!(packet_de.trailer().unwrap(), 0xFF);
+}
+
+#[test]
+fn absent() {
+    let mut buffer = [0u8; 1024];
+    let mut packet_ser = PacketSer::default();
+    packet_ser.with_flags(0x00);
+    packet_ser.with_extended(true);
+    packet_ser.with_tail(0x0405);
+    packet_ser.with_trailer(0xEE);
+    assert_eq!(packet_ser.serialize(&mut buffer).unwrap(), 5);
+    assert_eq!(buffer[..5], [0x00, 0x01, 0x04, 0x05, 0xEE]);
+
+    let mut packet_de = PacketDe::new(&buffer);
+    assert_eq!(packet_de.has_ext(), Ok(false));
+    assert_eq!(packet_de.ext().err(), Some(Error::NotPresent));
+    assert_eq!(packet_de.has_tail(), Ok(true));
+    assert_eq!(packet_de.tail().unwrap(), 0x0405);
+    assert_eq!(packet_de.trailer().unwrap(), 0xEE);
+}
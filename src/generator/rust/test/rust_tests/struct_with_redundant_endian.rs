@@ -0,0 +1,18 @@
+mod abf;
+mod struct_with_redundant_endian;
+
+use struct_with_redundant_endian::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut header_ser = HeaderSer::default();
+    header_ser.with_length(0x0102);
+    header_ser.with_id(0x03040506);
+    assert_eq!(header_ser.serialize(&mut buffer).unwrap(), 6);
+    assert_eq!(buffer[..6], [1, 2, 3, 4, 5, 6]);
+
+    let mut header_de = HeaderDe::new(&buffer);
+    assert_eq!(header_de.length().unwrap(), 0x0102);
+    assert_eq!(header_de.id().unwrap(), 0x03040506);
+}
@@ -0,0 +1,19 @@
+mod abf;
+mod struct_with_reference_view;
+
+use struct_with_reference_view::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut known_number_ser = KnownNumberSer::default();
+    known_number_ser.with_number().with_u16(12500);
+    assert_eq!(known_number_ser.serialize(&mut buffer).unwrap(), 3);
+
+    let mut known_number_de = KnownNumberDe::new(&buffer);
+    assert_eq!(known_number_de.number_key().unwrap(), 1);
+    assert_eq!(known_number_de.number().unwrap().type_id(), Some(1));
+    assert_eq!(known_number_de.number().unwrap().u8().err(), Some(Error::TypeMismatch));
+    assert_eq!(known_number_de.number().unwrap().u16().unwrap(), 12500);
+    assert_eq!(known_number_de.end().unwrap().len(), 1024 - 3);
+}
@@ -0,0 +1,19 @@
+mod abf;
+mod struct_with_size_reference;
+
+use struct_with_size_reference::*;
+
+#[test]
+fn serde() {
+    let mut main_ser = MainSer::default();
+    main_ser.with_volume().with_w(10);
+    main_ser.with_volume().with_h(20);
+    main_ser.with_volume().with_l(30);
+    let buffer = main_ser.serialize_to_vec().unwrap();
+
+    let mut main_de = MainDe::new(&buffer);
+    assert_eq!(main_de.volume_size().unwrap(), 7);
+    assert_eq!(main_de.volume().unwrap().w().unwrap(), 10);
+    assert_eq!(main_de.volume().unwrap().h().unwrap(), 20);
+    assert_eq!(main_de.volume().unwrap().l().unwrap(), 30);
+}
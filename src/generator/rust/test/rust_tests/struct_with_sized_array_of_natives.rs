@@ -0,0 +1,21 @@
+mod abf;
+mod struct_with_sized_array_of_natives;
+
+use struct_with_sized_array_of_natives::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut dim_ser = DimensionSer::default();
+    dim_ser.with_whl().get(0).set_data(57);
+    dim_ser.with_whl().get(1).set_data(58);
+    assert_eq!(dim_ser.serialize(&mut buffer).unwrap(), 5);
+
+    let mut dim_de = DimensionDe::new(&buffer);
+    assert_eq!(dim_de.whl_dim().unwrap(), 2);
+    assert_eq!(dim_de.whl().unwrap().length(), Some(2));
+    assert_eq!(dim_de.whl().unwrap().get(0).unwrap().get_data().unwrap(), 57);
+    assert_eq!(dim_de.whl().unwrap().get(1).unwrap().get_data().unwrap(), 58);
+    assert_eq!(dim_de.whl().unwrap().get(2).err(), Some(Error::OutOfRange));
+    assert_eq!(dim_de.end().unwrap().len(), 1024 - 5);
+}
@@ -0,0 +1,32 @@
+mod abf;
+mod struct_with_strings;
+
+use struct_with_strings::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut device_ser = DeviceSer::default();
+    device_ser.with_serial("SN42");
+    device_ser.with_name("sensor");
+    device_ser.with_version(0x0102);
+    assert_eq!(device_ser.size().unwrap(), 17);
+    assert_eq!(device_ser.serialize(&mut buffer).unwrap(), 17);
+    assert_eq!(buffer[..17], *b"SN42\0\0\0\0\x06sensor\x01\x02");
+
+    let mut device_de = DeviceDe::new(&buffer);
+    assert_eq!(device_de.serial().unwrap(), "SN42");
+    assert_eq!(device_de.name_len().unwrap(), 6);
+    assert_eq!(device_de.name().unwrap(), "sensor");
+    assert_eq!(device_de.version().unwrap(), 0x0102);
+}
+
+#[test]
+fn too_long() {
+    let mut buffer = [0u8; 1024];
+    let mut device_ser = DeviceSer::default();
+    device_ser.with_serial("SN4200000");
+    device_ser.with_name("");
+    device_ser.with_version(0);
+    assert_eq!(device_ser.serialize(&mut buffer), Err(Error::Overflow));
+}
@@ -0,0 +1,20 @@
+mod abf;
+mod struct_with_views;
+
+use struct_with_views::*;
+
+#[test]
+fn serde() {
+    let mut buffer = [0u8; 1024];
+    let mut number_seq_ser = NumberSeqSer::default();
+    number_seq_ser.with_num0().with_u8(1);
+    number_seq_ser.with_num1().with_u16(20000);
+    number_seq_ser.with_num2().with_u32(3000000);
+    assert_eq!(number_seq_ser.serialize(&mut buffer).unwrap(), 7);
+
+    let mut number_seq_de = NumberSeqDe::new(&buffer);
+    assert_eq!(number_seq_de.num0().unwrap().u8().unwrap(), 1);
+    assert_eq!(number_seq_de.num2().err(), Some(Error::NotDeserialized));
+    assert_eq!(number_seq_de.num1().unwrap().u16().unwrap(), 20000);
+    assert_eq!(number_seq_de.num2().unwrap().u32().unwrap(), 3000000);
+}
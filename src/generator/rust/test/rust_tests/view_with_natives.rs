@@ -0,0 +1,19 @@
+mod abf;
+mod view_with_natives;
+
+use view_with_natives::*;
+
+#[test]
+fn serde() {
+    let mut numbers_ser = NumbersSer::default();
+    numbers_ser.with_u16(60000);
+    let buffer = numbers_ser.serialize_to_vec().unwrap();
+    assert_eq!(buffer, vec![0x60, 0xEA]);
+
+    let mut numbers_de = NumbersDe::new(&buffer);
+    assert_eq!(numbers_de.u16().unwrap(), 60000);
+
+    assert_eq!(numbers_de.u8().err(), Some(Error::TypeMismatch));
+    assert_eq!(numbers_de.u32().err(), Some(Error::TypeMismatch));
+    assert_eq!(numbers_de.u16().unwrap(), 60000);
+}
@@ -0,0 +1,217 @@
+use super::*;
+
+#[test]
+fn view_with_natives() {
+    generate_test(
+        "view_with_natives.abf", 
+        "view_with_natives.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_natives() {
+    generate_test(
+        "struct_with_natives.abf", 
+        "struct_with_natives.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_views() {
+    generate_test(
+        "struct_with_views.abf", 
+        "struct_with_views.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_array_of_natives() {
+    generate_test(
+        "struct_with_array_of_natives.abf", 
+        "struct_with_array_of_natives.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_arrays_of_views() {
+    generate_test(
+        "struct_with_arrays_of_views.abf", 
+        "struct_with_arrays_of_views.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_sized_array_of_natives() {
+    generate_test(
+        "struct_with_sized_array_of_natives.abf", 
+        "struct_with_sized_array_of_natives.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_reference_view() {
+    generate_test(
+        "struct_with_reference_view.abf", 
+        "struct_with_reference_view.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_constant() {
+    generate_test(
+        "struct_with_constant.abf", 
+        "struct_with_constant.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_big_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_nostd_values_little_endian() {
+    generate_test(
+        "struct_with_nostd_values.abf", 
+        "struct_with_nostd_values.rs", 
+        true, 
+        false
+    );
+}
+
+#[test]
+fn enum1() {
+    generate_test(
+        "enum1.abf", 
+        "enum1.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn empty_struct() {
+    generate_test(
+        "empty_struct.abf", 
+        "empty_struct.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_size_view() {
+    generate_test(
+        "struct_with_size_reference.abf", 
+        "struct_with_size_reference.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_floats() {
+    generate_test(
+        "struct_with_floats.abf", 
+        "struct_with_floats.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_bit_fields() {
+    generate_test(
+        "struct_with_bit_fields.abf", 
+        "struct_with_bit_fields.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_strings() {
+    generate_test(
+        "struct_with_strings.abf", 
+        "struct_with_strings.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_checksum() {
+    generate_test(
+        "struct_with_checksum.abf", 
+        "struct_with_checksum.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_optional() {
+    generate_test(
+        "struct_with_optional.abf", 
+        "struct_with_optional.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_import() {
+    generate_test(
+        "struct_with_import.abf", 
+        "struct_with_import.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_redundant_endian() {
+    generate_test(
+        "struct_with_redundant_endian.abf", 
+        "struct_with_redundant_endian.rs", 
+        true, 
+        true
+    );
+}
+
+#[test]
+fn struct_with_rest_array() {
+    assert_eq!(
+        unsupported_feature("struct_with_rest_array.abf"),
+        "Unsupported feature: Arrays running to end of message are not supported in language Rust"
+    );
+}
+
+#[test]
+fn struct_with_bounded_arrays() {
+    assert_eq!(
+        unsupported_feature("struct_with_bounded_arrays.abf"),
+        "Unsupported feature: Bounded arrays are not supported in language Rust"
+    );
+}
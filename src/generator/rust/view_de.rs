@@ -0,0 +1,141 @@
+use super::*;
+
+pub fn generate_view_deserializer(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.deserializer_typename()));
+    writer.scope_in();
+    writer.write_line("source_: Option<&'a [u8]>,");
+    writer.write_line("type_id_: Option<usize>,");
+    for t in &m.types {
+        writer.write_line(&format!("{}_: {},", t.variable(), t.deserializer_typename()));
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> {}", m.deserializer_typename()));
+    writer.scope_in();
+    for i in 0..m.types.len() {
+        generate_get_method(m, i, writer);
+    }
+    generate_type_id(writer);
+    generate_set_type_id(m, writer);
+    generate_select(m, writer);
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl<'a> abf::Deserializer<'a> for {}", m.deserializer_typename()));
+    writer.scope_in();
+    generate_set_source(writer);
+    generate_source_set(writer);
+    generate_end(m, writer);
+    generate_init(m, writer);
+    writer.scope_out(false);
+}
+
+fn generate_get_method(m: &ViewMemory, i: usize, writer: &mut Writer) {
+    let t = &m.types[i].memory;
+    if t.directly_deserializable() {
+        writer.write_with_offset(&format!("pub fn {}(&mut self) -> Result<{}, abf::Error>",
+            t.variable(),
+            t.native_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("self.select({})?;", m.types[i].constant.get_value()));
+        writer.write_line(&format!("self.{}_.get_data()", t.variable()));
+        writer.scope_out(false);
+    } else {
+        writer.write_with_offset(&format!("pub fn {}(&mut self) -> Result<&mut {}, abf::Error>",
+            t.variable(),
+            t.deserializer_typename()));
+        writer.scope_in();
+        writer.write_line(&format!("self.select({})?;", m.types[i].constant.get_value()));
+        writer.write_line(&format!("Ok(&mut self.{}_)", t.variable()));
+        writer.scope_out(false);
+    }
+}
+
+fn generate_type_id(writer: &mut Writer) {
+    writer.write_with_offset("pub fn type_id(&self) -> Option<usize>");
+    writer.scope_in();
+    writer.write_line("self.type_id_");
+    writer.scope_out(false);
+}
+
+// Called by owning struct when view key was read from referencing member.
+fn generate_set_type_id(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("pub fn set_type_id(&mut self, type_id: usize) -> Result<(), abf::Error>");
+    writer.scope_in();
+    writer.write_with_offset("match type_id");
+    writer.scope_in();
+    let keys = m.types
+        .iter()
+        .map(|t| t.constant.get_value().to_string())
+        .collect::<Vec<String>>()
+        .join(" | ");
+    writer.write_line(&format!("{} => self.type_id_ = Some(type_id),", keys));
+    writer.write_line("_ => return Err(abf::Error::UnknownTypeId),");
+    writer.scope_out(false);
+    writer.write_line("Ok(())");
+    writer.scope_out(false);
+}
+
+fn generate_select(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn select(&mut self, type_id: usize) -> Result<(), abf::Error>");
+    writer.scope_in();
+    writer.write_line("let source = self.source_.ok_or(abf::Error::SourceNotSet)?;");
+    writer.write_with_offset("if self.type_id_.is_some() && self.type_id_ != Some(type_id)");
+    writer.scope_in();
+    writer.write_line("return Err(abf::Error::TypeMismatch);");
+    writer.scope_out(false);
+    writer.write_line("self.type_id_ = Some(type_id);");
+    writer.write_with_offset("match type_id");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("{} => if !self.{}_.source_set() {{ self.{}_.set_source(source) }},",
+            t.constant.get_value(),
+            t.variable(),
+            t.variable()));
+    }
+    writer.write_line("_ => return Err(abf::Error::UnknownTypeId),");
+    writer.scope_out(false);
+    writer.write_line("Ok(())");
+    writer.scope_out(false);
+}
+
+fn generate_set_source(writer: &mut Writer) {
+    writer.write_with_offset("fn set_source(&mut self, source: &'a [u8])");
+    writer.scope_in();
+    writer.write_line("self.init();");
+    writer.write_line("self.source_ = Some(source);");
+    writer.scope_out(false);
+}
+
+fn generate_source_set(writer: &mut Writer) {
+    writer.write_with_offset("fn source_set(&self) -> bool");
+    writer.scope_in();
+    writer.write_line("self.source_.is_some()");
+    writer.scope_out(false);
+}
+
+fn generate_end(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn end(&mut self) -> Result<&'a [u8], abf::Error>");
+    writer.scope_in();
+    writer.write_line("let type_id = self.type_id_.ok_or(abf::Error::NotDeserialized)?;");
+    writer.write_line("self.select(type_id)?;");
+    writer.write_with_offset("match type_id");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("{} => self.{}_.end(),", t.constant.get_value(), t.variable()));
+    }
+    writer.write_line("_ => Err(abf::Error::UnknownTypeId),");
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
+
+fn generate_init(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    writer.write_line("self.source_ = None;");
+    writer.write_line("self.type_id_ = None;");
+    for t in &m.types {
+        writer.write_line(&format!("self.{}_.init();", t.variable()));
+    }
+    writer.scope_out(false);
+}
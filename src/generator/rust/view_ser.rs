@@ -0,0 +1,102 @@
+use super::*;
+
+pub fn generate_view_serializer(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_line("#[derive(Default)]");
+    writer.write(&format!("pub struct {}", m.serializer_typename()));
+    writer.scope_in();
+    writer.write_line("type_id_: Option<usize>,");
+    for t in &m.types {
+        writer.write_line(&format!("{}_: {},", t.variable(), t.serializer_typename()));
+    }
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl {}", m.serializer_typename()));
+    writer.scope_in();
+    for i in 0..m.types.len() {
+        generate_with_method(m, i, writer);
+    }
+    generate_type_id(writer);
+    writer.scope_out(false);
+    writer.write_line("");
+    writer.write(&format!("impl abf::Serializer for {}", m.serializer_typename()));
+    writer.scope_in();
+    generate_size(m, writer);
+    generate_serialize(m, writer);
+    generate_init(writer);
+    writer.scope_out(false);
+}
+
+fn generate_type_id(writer: &mut Writer) {
+    writer.write_with_offset("pub fn type_id(&self) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    writer.write_line("self.type_id_.ok_or(abf::Error::NotSet)");
+    writer.scope_out(false);
+}
+
+fn generate_serialize(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn serialize(&mut self, dest: &mut [u8]) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    writer.write_with_offset("match self.type_id_");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("Some({}) => self.{}_.serialize(dest),", t.constant.get_value(), t.variable()));
+    }
+    writer.write_line("_ => Err(abf::Error::NotSet),");
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
+
+fn generate_size(m: &ViewMemory, writer: &mut Writer) {
+    writer.write_with_offset("fn size(&self) -> Result<usize, abf::Error>");
+    writer.scope_in();
+    writer.write_with_offset("match self.type_id_");
+    writer.scope_in();
+    for t in &m.types {
+        writer.write_line(&format!("Some({}) => self.{}_.size(),", t.constant.get_value(), t.variable()));
+    }
+    writer.write_line("_ => Err(abf::Error::NotSet),");
+    writer.scope_out(false);
+    writer.scope_out(false);
+}
+
+fn generate_init(writer: &mut Writer) {
+    writer.write_with_offset("fn init(&mut self)");
+    writer.scope_in();
+    writer.write_line("self.type_id_ = None;");
+    writer.scope_out(false);
+}
+
+fn generate_with_method(m: &ViewMemory, i: usize, writer: &mut Writer) {
+    match &m.types[i].memory {
+        MemoryType::Native(_) => generate_with_native(m, i, writer),
+        MemoryType::Struct(_) => generate_with_non_native(m, i, writer),
+        MemoryType::View(_) => generate_with_non_native(m, i, writer),
+        MemoryType::Enum(_) => generate_with_non_native(m, i, writer),
+    }
+}
+
+fn generate_with_native(m: &ViewMemory, i: usize, writer: &mut Writer) {
+    let t = &m.types[i].memory;
+    writer.write_with_offset(&format!("pub fn with_{}(&mut self, value: {})",
+        t.variable(),
+        t.native_typename()));
+    writer.scope_in();
+    writer.write_line(&format!("self.{}_.set_data(value);", t.variable()));
+    writer.write_line(&format!("self.type_id_ = Some({});", m.types[i].constant.get_value()));
+    writer.scope_out(false);
+}
+
+fn generate_with_non_native(m: &ViewMemory, i: usize, writer: &mut Writer) {
+    let t = &m.types[i].memory;
+    writer.write_with_offset(&format!("pub fn with_{}(&mut self) -> &mut {}",
+        t.variable(),
+        t.serializer_typename()));
+    writer.scope_in();
+    writer.write_with_offset(&format!("if self.type_id_ != Some({})", m.types[i].constant.get_value()));
+    writer.scope_in();
+    writer.write_line(&format!("self.{}_.init();", t.variable()));
+    writer.write_line(&format!("self.type_id_ = Some({});", m.types[i].constant.get_value()));
+    writer.scope_out(false);
+    writer.write_line(&format!("&mut self.{}_", t.variable()));
+    writer.scope_out(false);
+}
@@ -4,6 +4,7 @@ impl ToString for GeneratorError {
     fn to_string(&self) -> String {
         match self {
             GeneratorError::InternalError(e) => format!("Internal error: {}", e),
+            GeneratorError::UnsupportedFeature(e) => format!("Unsupported feature: {}", e),
         }
     }
 }
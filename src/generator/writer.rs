@@ -50,6 +50,15 @@ impl Writer {
         }
     }
 
+    // Brace-less scopes for indentation based languages (python).
+    pub fn indent(&mut self) {
+        self.begin_spaces += 4;
+    }
+
+    pub fn dedent(&mut self) {
+        self.begin_spaces -= 4;
+    }
+
     fn put_line_offset(&mut self) {
         self.put_blank_spaces(self.begin_spaces);
     }
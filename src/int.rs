@@ -15,17 +15,22 @@ impl Int {
         true
     }
 
+    // Widths without whole byte native type are packed as bit fields.
+    pub fn is_bit_field(&self) -> bool {
+        let native_widths: &[u8] = if self.signed {
+            &[8, 16, 32, 64]
+        } else {
+            &[8, 16, 24, 32, 64]
+        };
+        self.bytes > 0 && self.bytes < 64 && !native_widths.contains(&self.bytes)
+    }
+
+    // Width is kept in bits.
     pub fn max_value(&self) -> usize {
-        match self {
-            Self { signed: false, bytes: 1 } => u8::MAX as usize,
-            Self { signed: false, bytes: 2 } => u16::MAX as usize,
-            Self { signed: false, bytes: 4 } => u32::MAX as usize,
-            Self { signed: false, bytes: 8 } => u64::MAX as usize,
-            Self { signed: true, bytes: 1 } => i8::MAX as usize,
-            Self { signed: true, bytes: 2 } => i16::MAX as usize,
-            Self { signed: true, bytes: 4 } => i32::MAX as usize,
-            Self { signed: true, bytes: 8 } => i64::MAX as usize,
-            _ => unreachable!(),
+        let bits = if self.signed { self.bytes - 1 } else { self.bytes };
+        if bits >= 64 {
+            return usize::MAX;
         }
+        (1usize << bits) - 1
     }
 }
@@ -6,29 +6,123 @@ impl<TData: AsMemory + Default + Clone> AsMemory for DataView<TData> {
     }
 }
 
+impl Struct {
+    // Places bit field members into container of their group, first member takes
+    // most significant bits unless bit order is lsb.
+    fn bit_field_memories(&self) -> Vec<Option<BitField>> {
+        let mut bit_fields = vec![None; self.members.len()];
+        for (first, last) in self.bit_field_groups() {
+            let group = &self.members[first..=last];
+            let group_bits = group.iter().map(|m| m.bit_field_width().unwrap()).sum::<u8>();
+            let container = Rc::new(NativeType::unsigned_from_size(group_bits as usize / 8).unwrap());
+            let mut offset = 0;
+            for (i, member) in group.iter().enumerate() {
+                let bits = member.bit_field_width().unwrap();
+                bit_fields[first + i] = Some(BitField {
+                    native: Rc::new(bit_field_native(&member.typ.typ, bits)),
+                    container: container.clone(),
+                    bits,
+                    shift: if self.lsb_first { offset } else { group_bits - offset - bits },
+                    opens_group: i == 0,
+                    closes_group: first + i == last,
+                });
+                offset += bits;
+            }
+        }
+        bit_fields
+    }
+}
+
+// Native of member holding key, dimension or size of target member.
+fn reference_native(
+    constant: &StructMemberConstant,
+    native: Rc<NativeType>,
+    holder: Rc<StructMemberMemory>,
+    target: Rc<StructMemberMemory>,
+) -> NativeType {
+    match constant {
+        StructMemberConstant::ViewReferenceKey(_) => NativeType::ViewKeyReference(ViewKeyReference {
+            native_key: native,
+            key: holder,
+            view: target
+        }),
+        StructMemberConstant::ArrayDimension(_) => NativeType::ArrayDimensionReference(ArrayDimensionReference {
+            origin: native,
+            size: holder,
+            array: target
+        }),
+        StructMemberConstant::Size(_) => NativeType::StructMemberSize(StructMemberSizeReference {
+            native,
+            origin: holder,
+            member: target
+        }),
+        _ => panic!("not a member reference"),
+    }
+}
+
+// Smallest native type holding value of bit field.
+fn bit_field_native(typ: &TypeVariant, bits: u8) -> NativeType {
+    let signed = match typ {
+        TypeVariant::Bool(_) => return NativeType::Bool,
+        TypeVariant::Int(int) => int.signed,
+        _ => panic!("not a bit field"),
+    };
+    match (signed, bits) {
+        (false, 1..=8) => NativeType::U8,
+        (false, 9..=16) => NativeType::U16,
+        (false, 17..=32) => NativeType::U32,
+        (false, _) => NativeType::U64,
+        (true, 1..=8) => NativeType::I8,
+        (true, 9..=16) => NativeType::I16,
+        (true, 17..=32) => NativeType::I32,
+        (true, _) => NativeType::I64,
+    }
+}
+
 impl AsMemory for Struct {
     fn as_memory(&self, others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
         let mut structure = Rc::new(RefCell::new(StructMemory {
             name: self.name.data.clone(),
             fields: Vec::new(),
         }));
+        let bit_fields = self.bit_field_memories();
         for member in &self.members {
+            if let Some(bit_field) = &bit_fields[member.index] {
+                structure.borrow_mut().fields.push(Rc::new(StructMemberMemory {
+                    name: member.name.data.clone(),
+                    index: member.index,
+                    memory: RefCell::new(MemoryType::Native(NativeType::BitField(bit_field.clone())).non_array_memory()),
+                    condition: None,
+                    structure: structure.clone(),
+                    outer_references: RefCell::default()
+                }));
+                continue
+            }
             if let Some(c) = &member.constant {
                 if !c.is_usize() {
                     structure.borrow_mut().fields.push(Rc::new(StructMemberMemory {
                         name: member.name.data.clone(),
                         index: member.index,
                         memory: RefCell::new(MemoryType::Native(NativeType::Unknown).non_array_memory()),
-                        structure: structure.clone()
+                        condition: None,
+                        structure: structure.clone(),
+                        outer_references: RefCell::default()
                     }));
                     continue
                 }
             }
+            // flag precedes optional member, so it is already pushed
+            let condition = member.condition.as_ref().map(|c| PresenceCondition {
+                flag: structure.borrow().fields[self.get_member_index_by_name(&c.member_name.data).unwrap()].clone(),
+                bit: c.bit.as_ref().map(|b| b.data),
+            });
             structure.borrow_mut().fields.push(Rc::new(StructMemberMemory {
                 name: member.name.data.clone(),
                 index: member.index,
                 memory: RefCell::new(member.typ.as_memory(others)?),
-                structure: structure.clone()
+                condition,
+                structure: structure.clone(),
+                outer_references: RefCell::default()
             }));
         }
         // resolve view reference keys
@@ -36,47 +130,49 @@ impl AsMemory for Struct {
             if let Some(c) = &self.members[i].constant {
                 match c {
                     StructMemberConstant::Usize(value) => if let Some(nm) = f.memory.borrow_mut().memory.as_native_mut() {
-                        nm.make_const(*value).map_err(|e| InterpretError::GenericError(e))?
+                        nm.make_const(*value).map_err(|e| InterpretError::GenericWithPosError(self.members[i].name.code_view.clone(), e))?
                     } else {
                         return Err(InterpretError::CannotAsignUsizeCstToNonUnsignedMemory(*value))
                     },
-                    StructMemberConstant::ViewReferenceKey(mr) => {
-                        let index = self.get_member_index_by_name(&mr.member_name.data).unwrap();
-                        let native_key = Rc::new(self.members[i].typ.as_memory(others)?.memory.as_native().unwrap().clone());
-                        *f.memory.borrow_mut() = MemoryType::Native(NativeType::ViewKeyReference(
-                                ViewKeyReference {
-                                    native_key: native_key,
-                                    key: f.clone(),
-                                    view: structure.borrow().fields[index].clone()
-                                }
-                            )).non_array_memory();
-        
-                    },
-                    StructMemberConstant::ArrayDimension(mr) => {
-                        let index = self.get_member_index_by_name(&mr.member_name.data).unwrap();
+                    StructMemberConstant::ViewReferenceKey(mr)
+                    | StructMemberConstant::ArrayDimension(mr)
+                    | StructMemberConstant::Size(mr) => {
+                        // reference leaving the struct is resolved where the struct is embedded
+                        let target = match structure.borrow().member_at_path(&mr.member_name.data) {
+                            Some(target) => target,
+                            None => continue,
+                        };
                         let native = Rc::new(self.members[i].typ.as_memory(others)?.memory.as_native().unwrap().clone());
-                        *f.memory.borrow_mut() = MemoryType::Native(NativeType::ArrayDimensionReference(
-                                ArrayDimensionReference {
-                                    origin: native,
-                                    size: f.clone(),
-                                    array: structure.borrow().fields[index].clone()
-                                }
-                            )).non_array_memory();
+                        *f.memory.borrow_mut() = MemoryType::Native(reference_native(c, native, f.clone(), target)).non_array_memory();
                     },
-                    StructMemberConstant::Size(mr) => {
-                        let index = self.get_member_index_by_name(&mr.member_name.data).unwrap();
+                    StructMemberConstant::Checksum(checksum) => {
+                        let first = self.get_member_index_by_name(&checksum.first.data).unwrap();
+                        let last = self.get_member_index_by_name(&checksum.last.data).unwrap();
                         let native = Rc::new(self.members[i].typ.as_memory(others)?.memory.as_native().unwrap().clone());
-                        *f.memory.borrow_mut() = MemoryType::Native(NativeType::StructMemberSize(
-                                StructMemberSizeReference {
+                        *f.memory.borrow_mut() = MemoryType::Native(NativeType::Checksum(
+                                ChecksumReference {
                                     native: native,
+                                    algorithm: checksum.algorithm.data,
                                     origin: f.clone(),
-                                    member: structure.borrow().fields[index].clone()
+                                    first: structure.borrow().fields[first].clone(),
+                                    last: structure.borrow().fields[last].clone()
                                 }
                             )).non_array_memory();
                     }
                 } 
             }
         }
+        for f in &structure.borrow().fields {
+            f.memory.borrow_mut().big_endian = self.member_endian(&self.members[f.index]);
+        }
+        // references leaving embedded structs, holders keep endian of their struct
+        for r in self.resolved_references().iter().filter(|r| r.holder_path.len() > 1) {
+            let holder = structure.borrow().member_at_path(&r.holder_path.join(".")).unwrap();
+            let target = structure.borrow().member_at_path(&r.target_path.join(".")).unwrap();
+            let native = Rc::new(r.holder.typ.as_memory(others)?.memory.into_native());
+            let reference = reference_native(r.holder.constant.as_ref().unwrap(), native, holder.clone(), target);
+            holder.memory.borrow_mut().memory = MemoryType::Native(reference);
+        }
         for f in &structure.borrow().fields {
             if let Some(nm) = f.memory.borrow().memory.as_native() {
                 if let NativeType::ViewKeyReference(vrk) = nm {
@@ -97,11 +193,11 @@ impl AsMemory for Struct {
     }
 }
 
-impl AsMemory for Enum {
+impl AsMemory for DataView<Enum> {
     fn as_memory(&self, others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
         let mut new_enum = EnumMemory {
             name: self.name.clone(),
-            underlaying_type: self.underlaying_int.as_memory(others)?.memory.into_native(),
+            underlaying_type: DataView::new(self.underlaying_int.clone(), self.code_view.clone()).as_memory(others)?.memory.into_native(),
             constants: Vec::new(),
         };
         for constant in &self.constants {
@@ -114,7 +210,7 @@ impl AsMemory for Enum {
     }
 }
 
-impl AsMemory for Int {
+impl AsMemory for DataView<Int> {
     fn as_memory(&self, _others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
         if self.signed {
             match self.bytes {
@@ -122,7 +218,7 @@ impl AsMemory for Int {
                 16 => Ok(MemoryType::Native(NativeType::I16).non_array_memory()),
                 32 => Ok(MemoryType::Native(NativeType::I32).non_array_memory()),
                 64 => Ok(MemoryType::Native(NativeType::I64).non_array_memory()),
-                _ => Err(InterpretError::UnknownIntSize(self.bytes)),
+                _ => Err(InterpretError::UnknownIntSize(self.code_view.clone(), self.bytes)),
             }
         } else {
             match self.bytes {
@@ -131,12 +227,37 @@ impl AsMemory for Int {
                 24 => Ok(MemoryType::Native(NativeType::U24).non_array_memory()),
                 32 => Ok(MemoryType::Native(NativeType::U32).non_array_memory()),
                 64 => Ok(MemoryType::Native(NativeType::U64).non_array_memory()),
-                _ => Err(InterpretError::UnknownIntSize(self.bytes)),
+                _ => Err(InterpretError::UnknownIntSize(self.code_view.clone(), self.bytes)),
             }
         }
     }
 }
 
+impl AsMemory for DataView<Float> {
+    fn as_memory(&self, _others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
+        match self.bits {
+            32 => Ok(MemoryType::Native(NativeType::F32).non_array_memory()),
+            64 => Ok(MemoryType::Native(NativeType::F64).non_array_memory()),
+            _ => Err(InterpretError::UnknownFloatSize(self.code_view.clone(), self.bits)),
+        }
+    }
+}
+
+impl AsMemory for DataView<Bool> {
+    fn as_memory(&self, _others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
+        match self.bits {
+            8 => Ok(MemoryType::Native(NativeType::Bool).non_array_memory()),
+            _ => Err(InterpretError::UnknownBoolSize(self.code_view.clone(), self.bits)),
+        }
+    }
+}
+
+impl AsMemory for StringType {
+    fn as_memory(&self, _others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
+        Ok(MemoryType::Native(NativeType::Char).non_array_memory())
+    }
+}
+
 impl AsMemory for View {
     fn as_memory(&self, others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
         let new_view = ViewMemory {
@@ -183,6 +304,9 @@ impl AsMemory for TypeVariant {
             TypeVariant::View(v) => v.borrow().as_memory(others),
             TypeVariant::Enum(e) => e.as_memory(others),
             TypeVariant::Int(i) => i.as_memory(others),
+            TypeVariant::Float(f) => f.as_memory(others),
+            TypeVariant::Bool(b) => b.as_memory(others),
+            TypeVariant::String(s) => s.as_memory(others),
             TypeVariant::Unknown(unknown) => Err(InterpretError::UnknownType(unknown.clone())),
         }
     }
@@ -190,9 +314,72 @@ impl AsMemory for TypeVariant {
 
 impl AsMemory for Type {
     fn as_memory(&self, others: &Vec<MemoryDeclaration>) -> Result<Memory, InterpretError> {
+        let memory = self.typ.as_memory(others)?.memory;
+        let mut array_size = self.array_size.clone();
+        if let ArraySize::Until(terminator) = &mut array_size {
+            terminator.sentinel = terminator_sentinel(terminator, &memory)?;
+        }
         Ok(Memory {
-            memory: self.typ.as_memory(others)?.memory,
-            array_size: self.array_size.clone()
+            memory,
+            array_size,
+            big_endian: None,
         })
     }
 }
+
+// Struct item is compared by its member, so the member must lie at fixed offset.
+fn terminator_sentinel(terminator: &Terminator, item: &MemoryType) -> Result<usize, InterpretError> {
+    let member = match &terminator.member {
+        Some(member) => member,
+        None => return sentinel_value(terminator, item),
+    };
+    let structure = item
+        .as_struct()
+        .ok_or_else(|| InterpretError::TerminatorTypeMismatch(member.code_view.clone()))?;
+    if structure.borrow().exact_size().is_none() {
+        return Err(InterpretError::TerminatorItemNotSized(member.code_view.clone()));
+    }
+    let (compared, _) = terminator
+        .compared_member(item)
+        .ok_or_else(|| InterpretError::UnknownStructMemberReference(member.code_view.clone()))?;
+    let memory = compared.memory.borrow();
+    if !memory.array_size.is_no() {
+        return Err(InterpretError::TerminatorTypeMismatch(member.code_view.clone()));
+    }
+    sentinel_value(terminator, &memory.memory)
+}
+
+// Sentinel is compared with integral, bool, character or enum value it must fit into.
+fn sentinel_value(terminator: &Terminator, compared: &MemoryType) -> Result<usize, InterpretError> {
+    let mismatch = || InterpretError::TerminatorTypeMismatch(terminator.value.code_view());
+    let native = match compared {
+        MemoryType::Native(native) => native,
+        MemoryType::Enum(e) => &e.underlaying_type,
+        _ => return Err(mismatch()),
+    };
+    let max_value = match native {
+        NativeType::Bool => 1,
+        NativeType::U8 | NativeType::U16 | NativeType::U24 | NativeType::U32 | NativeType::U64
+        | NativeType::I8 | NativeType::I16 | NativeType::I32 | NativeType::I64
+        | NativeType::Char => usize::MAX >> (64 - 8 * native.size() as u32),
+        _ => return Err(mismatch()),
+    };
+    let value = match (&terminator.value, compared) {
+        (TerminatorValue::Usize(value), _) => value.data,
+        (TerminatorValue::EnumMember(enum_name, member), MemoryType::Enum(e)) => {
+            if enum_name.as_ref().is_some_and(|name| name.data != e.name) {
+                return Err(mismatch());
+            }
+            e.constants
+                .iter()
+                .find(|c| c.name == member.data)
+                .map(|c| c.value)
+                .ok_or_else(|| InterpretError::UnknownEnumMember(member.code_view.clone()))?
+        },
+        _ => return Err(mismatch()),
+    };
+    if value > max_value {
+        return Err(mismatch());
+    }
+    Ok(value)
+}
@@ -4,13 +4,13 @@ impl ToString for InterpretError {
     fn to_string(&self) -> String {
         match self {
             InterpretError::TypeContainsItself(t) => {
-                format!("Type '{}' contains itself", t.data.name.data)
+                format!("Type '{}' contains itself", t.data)
             }
             InterpretError::UnknownType(t) => format!("Unknown type '{}'", t.code_view.view()),
             InterpretError::CyclicalReference(t) => {
-                format!("Cyclical reference: {}", t.join(" -> "))
+                format!("Cyclical reference: {}", t.iter().map(|t| t.data.as_str()).collect::<Vec<&str>>().join(" -> "))
             }
-            InterpretError::StructMemberNotUnique => format!("Struct member not unique"),
+            InterpretError::StructMemberNotUnique(name, ..) => format!("Struct member '{}' is not unique", name),
             InterpretError::MemberReferenceDoesntPointToView(c) => {
                 format!("Member reference '{}' doesn't point to view", c.view())
             },
@@ -26,9 +26,89 @@ impl ToString for InterpretError {
             InterpretError::ViewReferenceTypeTooSmall(c) => {
                 format!("View reference integral type '{}' is too small", c.view())
             }
-            InterpretError::UnknownIntSize(t) => format!("Unknown int size '{}'", t),
-            InterpretError::EnumAlreadyExists(t) => {
-                format!("Enum '{}' already exists", t.data.name)
+            InterpretError::DimensionTypeTooSmall(c) => {
+                format!("Dimension integral type '{}' is too small for array bound", c.view())
+            }
+            InterpretError::UnknownIntSize(_, t) => format!("Unknown int size '{}'", t),
+            InterpretError::UnknownFloatSize(_, t) => format!("Unknown float size '{}'", t),
+            InterpretError::UnknownBoolSize(_, t) => format!("Unknown bool size '{}'", t),
+            InterpretError::BitFieldArray(c) => {
+                format!("Bit field member '{}' cannot be array", c.view())
+            }
+            InterpretError::BitFieldConstant(c) => {
+                format!("Bit field member '{}' cannot have constant", c.view())
+            }
+            InterpretError::BitFieldGroupNotWholeBytes(c) => {
+                format!("Bit field group ending with member '{}' does not fill whole bytes", c.view())
+            }
+            InterpretError::BitFieldGroupTooBig(c) => {
+                format!("Bit field group ending with member '{}' does not fit into 1, 2, 3, 4 or 8 bytes", c.view())
+            }
+            InterpretError::BitFieldGroupMixedEndian(c) => {
+                format!("Bit field group ending with member '{}' mixes big and little endian members", c.view())
+            }
+            InterpretError::EndianOnCompoundMember(_) => {
+                "Endian can be set only on members of native or enum type".into()
+            }
+            InterpretError::StringWithoutLength(c) => {
+                format!("String '{}' must be declared as [string, size] or [string]", c.view())
+            }
+            InterpretError::StringWithoutDimension(c) => {
+                format!("Dynamic string '{}' must be preceded by its dimension member", c.view())
+            }
+            InterpretError::ChecksumTypeMismatch(c) => {
+                format!("Checksum member '{}' type does not match its algorithm", c.view())
+            }
+            InterpretError::ChecksumInvalidRange(c) => {
+                format!("Checksum range starting with '{}' must be ordered and must not cover checksum member", c.view())
+            }
+            InterpretError::ChecksumRangeSplitsBitFieldGroup(c) => {
+                format!("Checksum range boundary '{}' splits bit field group", c.view())
+            }
+            InterpretError::ConditionFlagMustPrecede(c) => {
+                format!("Presence flag '{}' must precede optional member", c.view())
+            }
+            InterpretError::ConditionFlagNotUnsigned(c) => {
+                format!("Presence flag '{}' must be unsigned integral or bool member without constant", c.view())
+            }
+            InterpretError::ConditionBitOutOfRange(c) => {
+                format!("Presence bit '{}' is out of range of flag member", c.view())
+            }
+            InterpretError::OptionalMemberConstant(c) => {
+                format!("Optional member '{}' cannot be bit field or have constant", c.view())
+            }
+            InterpretError::OptionalMemberReferenced(c) => {
+                format!("Optional member '{}' cannot be referenced by key, dimension or size", c.view())
+            }
+            InterpretError::RestArrayNotLast(c) => {
+                format!("Array '{}' running to end of message must be last member of its struct", c.view())
+            }
+            InterpretError::RestArrayNested(c) => {
+                format!("'{}' runs to end of message, so it cannot be nested in another type", c.view())
+            }
+            InterpretError::RestArrayDimension(c) => {
+                format!("Array '{}' running to end of message cannot have dimension", c.view())
+            }
+            InterpretError::TerminatedArrayDimension(c) => {
+                format!("Array '{}' ended by terminator cannot have dimension", c.view())
+            }
+            InterpretError::TerminatorTypeMismatch(c) => {
+                format!("Terminator '{}' does not match integral, bool, character or enum value it is compared with", c.view())
+            }
+            InterpretError::TerminatorItemNotSized(c) => {
+                format!("Struct compared by terminator member '{}' must have exact size", c.view())
+            }
+            InterpretError::TerminatedArrayInView(c) => {
+                format!("Terminated array of '{}' cannot be view item", c.view())
+            }
+            InterpretError::MemberPathNotThroughStruct(c) => {
+                format!("Member reference path '{}' must lead through struct members which are neither arrays nor optional", c.view())
+            }
+            InterpretError::OuterReferenceEmbedding(c) => {
+                format!("Member '{}' embeds struct referring to members outside of it, so it cannot be array or optional", c.view())
+            }
+            InterpretError::ArrayDimensionConflict(c) => {
+                format!("Dimension '{}' conflicts with other member dimensioning the same array", c.view())
             }
             InterpretError::EnumConstantNotUnique(t) => {
                 format!("Enum constant '{}' not unique", t.view())
@@ -40,27 +120,107 @@ impl ToString for InterpretError {
                 format!("Enum constant value '{}' not fitting", t.view())
             }
             InterpretError::UnknownEnumMember(t) => {
-                format!("Unknown enum member '{}' in '{}'", t.view(), t.pos())
+                format!("Unknown enum member '{}'", t.view())
             }
             InterpretError::UnknownEnum(t) => {
-                format!("Unknown enum '{}' in '{}'", t.view(), t.pos())
+                format!("Unknown enum '{}'", t.view())
             }
-            InterpretError::ViewAlreadyExists(t) => format!("View '{}' already exists", t.view()),
             InterpretError::ViewItemNotUniqueWithinView(t) => {
                 format!("View item '{}' not unique within view", t.view())
             }
             InterpretError::ViewReferenceKeyIsTooBig(c) => format!("View reference key '{}' is too big (max is 4 bytes)", c.view()),
-            InterpretError::ViewEmpty(t) => format!("View '{}' is empty", t),
+            InterpretError::ViewEmpty(t, _) => format!("View '{}' is empty", t),
             InterpretError::VievConstantsMustBeAllEnumsOrAllIntsOrAllUndefined => {
                 format!("View constants must be all enums or all ints or all undefined")
             },
-            InterpretError::EndianNotSet => "Endian not set".into(),
-            InterpretError::EndianOverrided(origin, overrided) => {
-                format!("Endian cannot be override, originally defined here {} overided here {}", origin.pos(), overrided.pos())
+            InterpretError::TypeNameClash(name, ..) => {
+                format!("Type '{}' is defined multiple times", name)
             }
-            InterpretError::GenericError(text) => text.clone(),
-            InterpretError::GenericWithPosError(cv, text) => format!("{} in {}", text, cv.pos()),
+            InterpretError::ModuleNameClash(name, ..) => {
+                format!("Module '{}' is imported from multiple files", name)
+            }
+            InterpretError::RequiredVersionNotMet(_, version) => {
+                format!("Required version {} is newer than compiler version {}", version, env!("CARGO_PKG_VERSION"))
+            }
+            InterpretError::EndianNotSet => "Endian not set".into(),
+            InterpretError::EndianOverrided(..) => "Endian cannot be overridden".into(),
+            InterpretError::BitOrderOverrided(..) => "Bit order cannot be overridden".into(),
+            InterpretError::GenericWithPosError(_, text) => text.clone(),
             InterpretError::CannotAsignUsizeCstToNonUnsignedMemory(value) => format!("Cannot asign {} to non unsigned memory", value),
         }
     }
 }
+
+impl ToDiagnostic for InterpretError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::new(self.to_string());
+        match self {
+            InterpretError::TypeContainsItself(t) => diagnostic.with_label(&t.code_view, "contains itself"),
+            InterpretError::UnknownType(t) => diagnostic.with_label(&t.code_view, "not declared"),
+            InterpretError::TypeNameClash(_, origin, clashing) => diagnostic
+                .with_label(clashing, "defined again here")
+                .with_label(origin, "first defined here"),
+            InterpretError::StructMemberNotUnique(_, origin, clashing) => diagnostic
+                .with_label(clashing, "declared again here")
+                .with_label(origin, "first declared here"),
+            InterpretError::CyclicalReference(types) => types
+                .iter()
+                .fold(diagnostic, |diagnostic, t| diagnostic.with_label(&t.code_view, "part of the cycle")),
+            InterpretError::ModuleNameClash(_, origin, clashing) => diagnostic
+                .with_label(clashing, "other file of the same module name")
+                .with_label(origin, "first file of the module"),
+            InterpretError::EndianOverrided(origin, overrided)
+            | InterpretError::BitOrderOverrided(origin, overrided) => diagnostic
+                .with_label(overrided, "overridden here")
+                .with_label(origin, "originally defined here"),
+            InterpretError::UnknownStructMemberReference(c)
+            | InterpretError::MemberReferenceDoesntPointToView(c)
+            | InterpretError::MemberReferenceDoesntPointToArray(c)
+            | InterpretError::StructMemberConstantCanBeApliedOnlyForInt(c)
+            | InterpretError::ViewReferenceTypeTooSmall(c)
+            | InterpretError::DimensionTypeTooSmall(c)
+            | InterpretError::BitFieldArray(c)
+            | InterpretError::BitFieldConstant(c)
+            | InterpretError::BitFieldGroupNotWholeBytes(c)
+            | InterpretError::BitFieldGroupTooBig(c)
+            | InterpretError::BitFieldGroupMixedEndian(c)
+            | InterpretError::StringWithoutLength(c)
+            | InterpretError::StringWithoutDimension(c)
+            | InterpretError::ChecksumTypeMismatch(c)
+            | InterpretError::ChecksumInvalidRange(c)
+            | InterpretError::ChecksumRangeSplitsBitFieldGroup(c)
+            | InterpretError::ConditionFlagMustPrecede(c)
+            | InterpretError::ConditionFlagNotUnsigned(c)
+            | InterpretError::ConditionBitOutOfRange(c)
+            | InterpretError::OptionalMemberConstant(c)
+            | InterpretError::OptionalMemberReferenced(c)
+            | InterpretError::RestArrayNotLast(c)
+            | InterpretError::RestArrayNested(c)
+            | InterpretError::RestArrayDimension(c)
+            | InterpretError::TerminatedArrayDimension(c)
+            | InterpretError::TerminatorTypeMismatch(c)
+            | InterpretError::TerminatorItemNotSized(c)
+            | InterpretError::TerminatedArrayInView(c)
+            | InterpretError::MemberPathNotThroughStruct(c)
+            | InterpretError::OuterReferenceEmbedding(c)
+            | InterpretError::ArrayDimensionConflict(c)
+            | InterpretError::EnumConstantNotUnique(c)
+            | InterpretError::EnumConstantValueNotUnique(c)
+            | InterpretError::EnumConstantValueNotFitting(c)
+            | InterpretError::UnknownEnumMember(c)
+            | InterpretError::UnknownEnum(c)
+            | InterpretError::ViewItemNotUniqueWithinView(c)
+            | InterpretError::ViewReferenceKeyIsTooBig(c)
+            | InterpretError::UnknownIntSize(c, _)
+            | InterpretError::UnknownFloatSize(c, _)
+            | InterpretError::UnknownBoolSize(c, _)
+            | InterpretError::GenericWithPosError(c, _) => diagnostic.with_label(c, ""),
+            InterpretError::RequiredVersionNotMet(c, _) => diagnostic.with_label(c, "required here"),
+            InterpretError::EndianOnCompoundMember(c) => diagnostic.with_label(c, "struct and view members keep endian of their declaration"),
+            InterpretError::ViewEmpty(_, c) => diagnostic.with_label(c, "declared here"),
+            InterpretError::VievConstantsMustBeAllEnumsOrAllIntsOrAllUndefined
+            | InterpretError::EndianNotSet
+            | InterpretError::CannotAsignUsizeCstToNonUnsignedMemory(_) => diagnostic,
+        }
+    }
+}
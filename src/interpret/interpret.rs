@@ -1,53 +1,110 @@
 use super::*;
+use super::reference::link_outer_references;
 
 impl Interpreter {
     pub fn put_token(&mut self, token: parser::SyntaxToken) -> Result<(), InterpretError> {
+        // every parsed file is a module, even without declarations
+        self.module(&token.code_view());
         match token {
+            parser::SyntaxToken::Import(i) => {
+                let module = self.module(&i.code_view);
+                let imported = std::path::Path::new(&i.path)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                if !module.imports.contains(&imported) {
+                    module.imports.push(imported);
+                }
+            }
             parser::SyntaxToken::Struct(t) => self.types.put_struct(t)?,
             parser::SyntaxToken::View(t) => self.types.put_view(t)?,
             parser::SyntaxToken::Enum(t) => self.types.put_enum(t)?,
-            parser::SyntaxToken::RequiredVersion(v) => {
-                self.required_version = Some([
-                    v.data.version[0].value.unwrap(),
-                    v.data.version[1].value.unwrap(),
-                    v.data.version[2].value.unwrap(),
-                ])
-            }
+            parser::SyntaxToken::RequiredVersion(v) => check_required_version(&v)?,
             parser::SyntaxToken::Endian(e) => {
-                if self.big_endian.is_some() {
-                     return Err(
-                        InterpretError::EndianOverrided(
-                            self.big_endian.as_ref().unwrap().code_view.clone(),
-                            e.code_view.clone()
+                // imported files may repeat the same endian
+                if let Some(origin) = &self.big_endian {
+                    if origin.data != e.big || origin.code_view.file == e.code_view.file {
+                        return Err(
+                            InterpretError::EndianOverrided(
+                                origin.code_view.clone(),
+                                e.code_view.clone()
+                            )
                         )
-                    )
+                    }
                 }
                 self.big_endian = Some(e.convert(|b| b.big));
             },
+            parser::SyntaxToken::BitOrder(o) => {
+                if let Some(origin) = &self.msb_first {
+                    if origin.data != o.msb_first || origin.code_view.file == o.code_view.file {
+                        return Err(
+                            InterpretError::BitOrderOverrided(
+                                origin.code_view.clone(),
+                                o.code_view.clone()
+                            )
+                        )
+                    }
+                }
+                self.msb_first = Some(o.convert(|o| o.msb_first));
+            },
         }
         Ok(())
     }
 
-    pub fn interpret(mut self, tokens: Vec<parser::SyntaxToken>) -> Result<Self, InterpretError> {
+    fn module(&mut self, code_view: &CodeView) -> &mut Module {
+        let name = code_view.module();
+        match self.modules.iter().position(|m| m.name == name) {
+            Some(i) => &mut self.modules[i],
+            None => {
+                self.modules.push(Module { name, imports: Vec::new() });
+                self.modules.last_mut().unwrap()
+            }
+        }
+    }
+
+    // Collects as many errors as possible, declarations are checked only when all types are resolved.
+    pub fn interpret(mut self, tokens: Vec<parser::SyntaxToken>) -> Result<Self, Vec<InterpretError>> {
+        let mut errors = check_module_names(&tokens);
         for te in tokens {
-            self.put_token(te)?;
+            if let Err(e) = self.put_token(te) {
+                errors.push(e);
+            }
+        }
+        let unresolved = self.types.resolve_unknown_types();
+        if !unresolved.is_empty() {
+            errors.extend(unresolved);
+            return Err(errors);
+        }
+        // bit fields are packed MSB first unless stated otherwise
+        if let Some(msb_first) = &self.msb_first {
+            self.types.set_lsb_first(!msb_first.data);
+        }
+        match self.types.resolve_types_order() {
+            Ok(order) => self.order = order,
+            Err(e) => {
+                errors.push(e);
+                return Err(errors);
+            }
+        }
+        errors.extend(self.types.check_types(&self.types));
+        if !errors.is_empty() {
+            return Err(errors);
         }
-        self.types = self.types.resolve_unknown_types()?;
-        self.order = self.types.resolve_types_order()?;
-        self.types.check_types(&self.types)?;
         Ok(self)
     }
 
     pub fn get_memory(&self) -> Result<Vec<MemoryDeclaration>, InterpretError> {
         let mut memory = Vec::new();
         for name in &self.order {
-            let t = self.types.get_type(name)?;
-            let m = t.unwrap().as_memory(&memory)?;
+            let t = self.types.get_type(name)?.unwrap();
+            let m = t.as_memory(&memory)?;
             memory.push(MemoryDeclaration {
                 name: name.clone(),
+                module: t.code_view().module(),
                 memory: m,
             });
         } 
+        link_outer_references(&memory);
         Ok(memory)
     }
 
@@ -58,3 +115,68 @@ impl Interpreter {
         }
     }
 }
+
+// Module is named by file stem only, so two files of the same stem would be merged into one module.
+fn check_module_names(tokens: &[parser::SyntaxToken]) -> Vec<InterpretError> {
+    let imports = tokens.iter().filter_map(|t| match t {
+        parser::SyntaxToken::Import(i) => {
+            let directory = std::path::Path::new(i.code_view.file.as_str()).parent().unwrap_or(std::path::Path::new(""));
+            Some((normalize_path(&directory.join(&i.path)), i.code_view.clone()))
+        },
+        _ => None,
+    }).collect::<Vec<_>>();
+    // files not imported by any other are pointed at by their first declaration
+    let mut files: Vec<(String, std::path::PathBuf, CodeView)> = Vec::new();
+    let mut errors = Vec::new();
+    for token in tokens {
+        let code_view = token.code_view();
+        let file = normalize_path(std::path::Path::new(code_view.file.as_str()));
+        if files.iter().any(|(_, f, _)| *f == file) {
+            continue;
+        }
+        let view = imports.iter()
+            .find(|(f, _)| *f == file)
+            .map_or(code_view.clone(), |(_, v)| v.clone());
+        let name = code_view.module();
+        if let Some((_, _, origin)) = files.iter().find(|(n, _, _)| *n == name) {
+            errors.push(InterpretError::ModuleNameClash(name.clone(), origin.clone(), view.clone()));
+        }
+        files.push((name, file, view));
+    }
+    errors
+}
+
+// Resolves '.' and '..' without touching file system, imported files are already known to exist.
+fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
+    let mut normalized = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                let can_pop = matches!(normalized.components().next_back(), Some(std::path::Component::Normal(_)));
+                if !can_pop || !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+// Schema fails only when it needs a newer compiler, versions are compared as major, minor, patch.
+fn check_required_version(required_version: &DataView<parser::RequiredVersion>) -> Result<(), InterpretError> {
+    let required = required_version.version.clone().map(|v| v.value.unwrap());
+    let compiler = [
+        env!("CARGO_PKG_VERSION_MAJOR"),
+        env!("CARGO_PKG_VERSION_MINOR"),
+        env!("CARGO_PKG_VERSION_PATCH"),
+    ].map(|v| v.parse::<u8>().unwrap());
+    if compiler >= required {
+        return Ok(());
+    }
+    Err(InterpretError::RequiredVersionNotMet(
+        required_version.code_view.clone(),
+        required.map(|v| v.to_string()).join("."),
+    ))
+}
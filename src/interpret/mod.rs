@@ -2,10 +2,13 @@ mod as_memory;
 mod enumeration;
 mod error;
 mod interpret;
+mod reference;
 mod structure;
 mod type_variant;
 mod types;
 mod view;
+#[cfg(test)]
+mod test;
 
 use super::*;
 use crate::parser::{self};
@@ -16,30 +19,60 @@ use std::vec::Vec;
 
 #[derive(Debug)]
 pub enum InterpretError {
-    TypeContainsItself(DataView<parser::Struct>),
+    TypeContainsItself(DataView<String>),
     UnknownType(DataView<String>),
-    CyclicalReference(Vec<String>),
-    StructMemberNotUnique,
+    CyclicalReference(Vec<DataView<String>>),
+    StructMemberNotUnique(String, CodeView, CodeView),
     UnknownStructMemberReference(CodeView),
     MemberReferenceDoesntPointToView(CodeView),
     MemberReferenceDoesntPointToArray(CodeView),
     StructMemberConstantCanBeApliedOnlyForInt(CodeView),
     ViewReferenceTypeTooSmall(CodeView),
-    UnknownIntSize(u8),
-    EnumAlreadyExists(DataView<parser::Enum>),
+    DimensionTypeTooSmall(CodeView),
+    UnknownIntSize(CodeView, u8),
+    UnknownFloatSize(CodeView, u8),
+    UnknownBoolSize(CodeView, u8),
+    BitFieldArray(CodeView),
+    BitFieldConstant(CodeView),
+    BitFieldGroupNotWholeBytes(CodeView),
+    BitFieldGroupTooBig(CodeView),
+    BitFieldGroupMixedEndian(CodeView),
+    EndianOnCompoundMember(CodeView),
+    StringWithoutLength(CodeView),
+    StringWithoutDimension(CodeView),
+    ChecksumTypeMismatch(CodeView),
+    ChecksumInvalidRange(CodeView),
+    ChecksumRangeSplitsBitFieldGroup(CodeView),
+    ConditionFlagMustPrecede(CodeView),
+    ConditionFlagNotUnsigned(CodeView),
+    ConditionBitOutOfRange(CodeView),
+    OptionalMemberConstant(CodeView),
+    OptionalMemberReferenced(CodeView),
+    RestArrayNotLast(CodeView),
+    RestArrayNested(CodeView),
+    RestArrayDimension(CodeView),
+    TerminatedArrayDimension(CodeView),
+    TerminatorTypeMismatch(CodeView),
+    TerminatorItemNotSized(CodeView),
+    TerminatedArrayInView(CodeView),
+    MemberPathNotThroughStruct(CodeView),
+    OuterReferenceEmbedding(CodeView),
+    ArrayDimensionConflict(CodeView),
     EnumConstantNotUnique(CodeView),
     EnumConstantValueNotUnique(CodeView),
     EnumConstantValueNotFitting(CodeView),
     UnknownEnumMember(CodeView),
     UnknownEnum(CodeView),
-    ViewAlreadyExists(CodeView),
     ViewItemNotUniqueWithinView(CodeView),
     ViewReferenceKeyIsTooBig(CodeView),
-    ViewEmpty(String),
+    ViewEmpty(String, CodeView),
     VievConstantsMustBeAllEnumsOrAllIntsOrAllUndefined,
+    TypeNameClash(String, CodeView, CodeView),
+    ModuleNameClash(String, CodeView, CodeView),
+    RequiredVersionNotMet(CodeView, String),
     EndianNotSet,
     EndianOverrided(CodeView, CodeView),
-    GenericError(String),
+    BitOrderOverrided(CodeView, CodeView),
     GenericWithPosError(CodeView, String),
     CannotAsignUsizeCstToNonUnsignedMemory(usize)
 }
@@ -50,6 +83,9 @@ pub enum TypeVariant {
     Enum(Rc<DataView<Enum>>),
     View(Rc<RefCell<DataView<View>>>),
     Int(DataView<Int>),
+    Float(DataView<Float>),
+    Bool(DataView<Bool>),
+    String(DataView<StringType>),
     Unknown(DataView<String>),
 }
 
@@ -77,6 +113,7 @@ pub enum StructMemberConstant {
     ArrayDimension(parser::MemberReference),
     Usize(usize),
     Size(parser::MemberReference),
+    Checksum(parser::Checksum),
 }
 
 #[derive(Clone)]
@@ -84,6 +121,8 @@ pub struct StructMember {
     name: DataView<String>,
     index: usize,
     typ: Type,
+    endian: Option<DataView<bool>>,
+    condition: Option<parser::MemberCondition>,
     constant: Option<StructMemberConstant>,
 }
 
@@ -91,6 +130,8 @@ pub struct StructMember {
 pub struct Struct {
     name: DataView<String>,
     members: Vec<StructMember>,
+    endian: Option<DataView<bool>>,
+    lsb_first: bool,
 }
 
 #[derive(Clone, Default)]
@@ -152,7 +193,8 @@ struct Interpreter {
     types: Types,
     order: Vec<String>,
     big_endian: Option<DataView<bool>>,
-    required_version: Option<[u8; 3]>,
+    msb_first: Option<DataView<bool>>,
+    modules: Vec<Module>,
 }
 
 pub trait AsMemory {
@@ -161,11 +203,12 @@ pub trait AsMemory {
 
 pub fn interpret(
     tokens: Vec<parser::SyntaxToken>,
-) -> Result<MemoryImage, InterpretError> {
+) -> Result<MemoryImage, Vec<InterpretError>> {
     let mut interpreter = Interpreter::default();
     interpreter = interpreter.interpret(tokens)?;
     Ok(MemoryImage {
-        big_endian: interpreter.big_endian()?,
-        memory_decl: interpreter.get_memory()?,
+        big_endian: interpreter.big_endian().map_err(|e| vec![e])?,
+        memory_decl: interpreter.get_memory().map_err(|e| vec![e])?,
+        modules: interpreter.modules,
     })
 }
@@ -0,0 +1,317 @@
+use super::*;
+
+// Reference crossing struct boundary, resolved in struct embedding both its holder and target.
+// Paths are member names starting at that struct.
+#[derive(Clone)]
+pub struct ResolvedReference {
+    pub holder_path: Vec<String>,
+    pub holder: StructMember,
+    pub holder_endian: Option<bool>,
+    pub target_path: Vec<String>,
+    pub target_struct: String,
+}
+
+impl StructMemberConstant {
+    pub fn member_reference(&self) -> Option<&parser::MemberReference> {
+        match self {
+            StructMemberConstant::ViewReferenceKey(mr)
+            | StructMemberConstant::ArrayDimension(mr)
+            | StructMemberConstant::Size(mr) => Some(mr),
+            StructMemberConstant::Usize(_) | StructMemberConstant::Checksum(_) => None,
+        }
+    }
+}
+
+impl StructMember {
+    pub fn member_reference(&self) -> Option<&parser::MemberReference> {
+        self.constant.as_ref().and_then(|c| c.member_reference())
+    }
+}
+
+fn member_path(mr: &parser::MemberReference) -> Vec<String> {
+    mr.member_name.data.split('.').map(String::from).collect()
+}
+
+impl Struct {
+    // Member at the end of reference path with name of struct declaring it, path is walked
+    // through nested struct members. None when the first member is not declared here,
+    // so the reference leaves the struct.
+    pub fn reference_target(&self, mr: &parser::MemberReference) -> Result<Option<(String, StructMember)>, InterpretError> {
+        let path = member_path(mr);
+        let mut member = match self.get_member_index_by_name(&path[0]) {
+            Some(i) => self.members[i].clone(),
+            None => return Ok(None),
+        };
+        let mut declaring = self.name.data.clone();
+        for name in &path[1..] {
+            let nested = match &member.typ.typ {
+                TypeVariant::Struct(s) if member.typ.array_size.is_no() && member.condition.is_none() => s.clone(),
+                _ => return Err(InterpretError::MemberPathNotThroughStruct(mr.member_name.code_view.clone())),
+            };
+            let nested = nested.borrow();
+            member = nested
+                .get_member_index_by_name(name)
+                .map(|i| nested.members[i].clone())
+                .ok_or_else(|| InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()))?;
+            declaring = nested.name.data.clone();
+        }
+        Ok(Some((declaring, member)))
+    }
+
+    // References of this struct and structs embedded in it which are not resolved here,
+    // given by path to their holder.
+    pub fn outer_references(&self) -> Vec<(Vec<String>, StructMember, Option<bool>)> {
+        let mut outer = Vec::new();
+        for member in &self.members {
+            if let Some(mr) = member.member_reference() {
+                if self.get_member_index_by_name(&member_path(mr)[0]).is_none() {
+                    outer.push((vec![member.name.data.clone()], member.clone(), self.member_endian(member)));
+                }
+            }
+            if let TypeVariant::Struct(s) = &member.typ.typ {
+                for (path, holder, endian) in s.borrow().outer_references() {
+                    if self.get_member_index_by_name(&member_path(holder.member_reference().unwrap())[0]).is_none() {
+                        outer.push(([vec![member.name.data.clone()], path].concat(), holder, endian));
+                    }
+                }
+            }
+        }
+        outer
+    }
+
+    // References resolved here which cross struct boundary: own ones pointing into embedded
+    // structs and those of embedded structs pointing out of them.
+    pub fn resolved_references(&self) -> Vec<ResolvedReference> {
+        let mut resolved = Vec::new();
+        let mut resolve = |holder_path: Vec<String>, holder: StructMember, holder_endian: Option<bool>| {
+            let mr = holder.member_reference().unwrap();
+            if let Ok(Some((target_struct, _))) = self.reference_target(mr) {
+                resolved.push(ResolvedReference {
+                    holder_path,
+                    target_path: member_path(mr),
+                    holder,
+                    holder_endian,
+                    target_struct,
+                });
+            }
+        };
+        for member in &self.members {
+            if member.member_reference().is_some_and(|mr| mr.member_name.data.contains('.')) {
+                resolve(vec![member.name.data.clone()], member.clone(), self.member_endian(member));
+            }
+            if let TypeVariant::Struct(s) = &member.typ.typ {
+                for (path, holder, endian) in s.borrow().outer_references() {
+                    resolve([vec![member.name.data.clone()], path].concat(), holder, endian);
+                }
+            }
+        }
+        resolved
+    }
+
+    // Struct embedding one with outer references must resolve them or pass them further out,
+    // which is possible only for single instance of it.
+    pub fn check_outer_references(&self, types: &Types) -> Result<(), InterpretError> {
+        for member in &self.members {
+            let s = match &member.typ.typ {
+                TypeVariant::Struct(s) => s,
+                _ => continue,
+            };
+            let outer = s.borrow().outer_references();
+            if outer.is_empty() {
+                continue
+            }
+            if !member.typ.array_size.is_no() || member.condition.is_some() {
+                return Err(InterpretError::OuterReferenceEmbedding(member.name.code_view.clone()));
+            }
+            for (_, holder, _) in outer {
+                let mr = holder.member_reference().unwrap();
+                if let Some((_, target)) = self.reference_target(mr)? {
+                    check_reference_target(&holder, &target, types)?;
+                }
+            }
+        }
+        for r in self.resolved_references() {
+            if r.holder.constant.as_ref().is_some_and(|c| c.is_array_dimension()) {
+                check_outer_dimension(&r, types)?;
+            }
+        }
+        if !types.is_embedded(&self.name.data) {
+            if let Some((_, holder, _)) = self.outer_references().first() {
+                let mr = holder.member_reference().unwrap();
+                return Err(InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Member holding key, dimension or size is checked against member it refers to wherever that is declared.
+pub fn check_reference_target(holder: &StructMember, target: &StructMember, types: &Types) -> Result<(), InterpretError> {
+    match holder.constant.as_ref().unwrap() {
+        StructMemberConstant::ViewReferenceKey(mr) => {
+            let view = match &target.typ.typ {
+                TypeVariant::View(view) => view.clone(),
+                _ => return Err(InterpretError::MemberReferenceDoesntPointToView(mr.member_name.code_view.clone())),
+            };
+            // find max value of view constant
+            let mut max_value = 0;
+            for t in &view.borrow().types {
+                if let Some(c) = &t.constant {
+                    match c {
+                        parser::ViewConstantValue::Usize(value) =>
+                            max_value = max_value.max(value.data),
+                        parser::ViewConstantValue::EnumMemberRef(e) =>
+                            max_value = max_value.max(
+                                types.get_enum_member_value(&e.enum_name, &e.enum_member)?),
+                    }
+                }
+            }
+            if holder.typ.typ.as_int().unwrap().max_value() < max_value {
+                return Err(InterpretError::ViewReferenceTypeTooSmall(
+                    holder.name.code_view.clone(),
+                ));
+            }
+        },
+        StructMemberConstant::ArrayDimension(mr) => {
+            if target.typ.array_size.is_no() {
+                return Err(InterpretError::MemberReferenceDoesntPointToArray(mr.member_name.code_view.clone()))
+            }
+            if target.typ.array_size.is_rest() {
+                return Err(InterpretError::RestArrayDimension(mr.member_name.code_view.clone()))
+            }
+            if target.typ.array_size.is_until() {
+                return Err(InterpretError::TerminatedArrayDimension(mr.member_name.code_view.clone()))
+            }
+            // every length up to bound must be representable
+            if let ArraySize::Bounded(bound) = target.typ.array_size {
+                if holder.typ.typ.as_int().unwrap().max_value() < bound as usize {
+                    return Err(InterpretError::DimensionTypeTooSmall(holder.name.code_view.clone()));
+                }
+            }
+        },
+        _ => {},
+    }
+    if target.condition.is_some() {
+        return Err(InterpretError::OptionalMemberReferenced(target.name.code_view.clone()));
+    }
+    Ok(())
+}
+
+// Array dimensioned from outside of its struct is dimensioned only from there, by members of one type,
+// since its deserializer keeps type of dimension member.
+fn check_outer_dimension(reference: &ResolvedReference, types: &Types) -> Result<(), InterpretError> {
+    let target = reference.target_path.last().unwrap();
+    let conflict = || InterpretError::ArrayDimensionConflict(reference.holder.name.code_view.clone());
+    let declaring = types.get_type(&reference.target_struct)?.unwrap().as_struct().unwrap().clone();
+    let local = declaring.borrow().members.iter().any(|m| match &m.constant {
+        Some(StructMemberConstant::ArrayDimension(mr)) => mr.member_name.data == *target,
+        _ => false,
+    });
+    if local {
+        return Err(conflict());
+    }
+    for name in &types.order {
+        let s = match types.types.get(name) {
+            Some(TypeVariant::Struct(s)) => s,
+            _ => continue,
+        };
+        for other in s.borrow().resolved_references() {
+            let same_target = other.target_struct == reference.target_struct
+                && other.target_path.last() == Some(target)
+                && other.holder.constant.as_ref().is_some_and(|c| c.is_array_dimension());
+            if same_target && (other.holder.typ.typ.as_int().map(|i| &i.data) != reference.holder.typ.typ.as_int().map(|i| &i.data)
+                || other.holder_endian != reference.holder_endian)
+            {
+                return Err(conflict());
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Types {
+    pub fn is_embedded(&self, name: &str) -> bool {
+        self.types.values().any(|t| match t {
+            TypeVariant::Struct(s) => s.borrow().members.iter().any(|m| match &m.typ.typ {
+                TypeVariant::Struct(nested) => nested.borrow().name.data == name,
+                _ => false,
+            }),
+            _ => false,
+        })
+    }
+
+    // Member of struct is dimensioned from struct embedding it by member resolved before it.
+    pub fn has_outer_dimension(&self, structure: &str, member: &str) -> bool {
+        self.order.iter().any(|name| match self.types.get(name) {
+            Some(TypeVariant::Struct(s)) => {
+                let s = s.borrow();
+                s.resolved_references().iter().any(|r| {
+                    r.target_struct == structure
+                        && r.target_path.last().is_some_and(|t| t == member)
+                        && r.holder.constant.as_ref().is_some_and(|c| c.is_array_dimension())
+                        && s.get_member_index_by_name(&r.holder_path[0]) < s.get_member_index_by_name(&r.target_path[0])
+                })
+            },
+            _ => false,
+        })
+    }
+}
+
+// Declarations are built before structs embedding them, so their members referring out of them
+// or referred to from outside take these references once all declarations are built.
+pub fn link_outer_references(declarations: &[MemoryDeclaration]) {
+    let mut structures = Vec::new();
+    for declaration in declarations {
+        collect_structures(&declaration.memory.memory, &mut structures);
+    }
+    let mut holders = HashMap::new();
+    let mut referring: HashMap<(String, String), Vec<Rc<StructMemberMemory>>> = HashMap::new();
+    for s in &structures {
+        for f in &s.borrow().fields {
+            let target = match f.get_referenced_member() {
+                Some(target) => target,
+                None => continue,
+            };
+            if Rc::ptr_eq(&target.structure, &f.structure) {
+                continue
+            }
+            holders.insert(member_key(f), f.memory.borrow().memory.as_native().unwrap().clone());
+            let references = referring.entry(member_key(&target)).or_default();
+            if !references.iter().any(|r| member_key(r) == member_key(f)) {
+                references.push(f.clone());
+            }
+        }
+    }
+    for s in &structures {
+        for f in &s.borrow().fields {
+            let unresolved = f.memory.borrow().memory.as_native().is_some_and(|n| n.is_unknown());
+            if let (true, Some(native)) = (unresolved, holders.get(&member_key(f))) {
+                f.memory.borrow_mut().memory = MemoryType::Native(native.clone());
+            }
+            if let Some(references) = referring.get(&member_key(f)) {
+                *f.outer_references.borrow_mut() = references.clone();
+            }
+        }
+    }
+}
+
+fn member_key(member: &StructMemberMemory) -> (String, String) {
+    (member.structure.borrow().name.clone(), member.name.clone())
+}
+
+fn collect_structures(memory: &MemoryType, structures: &mut Vec<Rc<RefCell<StructMemory>>>) {
+    match memory {
+        MemoryType::Struct(s) => {
+            structures.push(s.clone());
+            for f in &s.borrow().fields {
+                collect_structures(&f.memory.borrow().memory, structures);
+            }
+        },
+        MemoryType::View(v) => {
+            for t in &v.types {
+                collect_structures(&t.memory, structures);
+            }
+        },
+        MemoryType::Native(_) | MemoryType::Enum(_) => {},
+    }
+}
@@ -1,23 +1,134 @@
 use core::panic;
 
 use super::*;
+use super::reference::check_reference_target;
 
 impl Struct {
     pub fn check_type(&self, types: &Types) -> Result<(), InterpretError> {
         self.check_unique_member_names()?;
+        self.check_bit_fields()?;
+        self.check_endians()?;
+        self.check_strings(types)?;
+        self.check_rest_arrays()?;
         self.check_references(types)?;
+        self.check_conditions()?;
+        self.check_outer_references(types)?;
         Ok(())
     }
-    fn check_unique_member_names(&self) -> Result<(), InterpretError> {
+    fn check_bit_fields(&self) -> Result<(), InterpretError> {
         for member in &self.members {
-            if self
-                .members
+            if member.bit_field_width().is_some() {
+                if !member.typ.array_size.is_no() {
+                    return Err(InterpretError::BitFieldArray(member.name.code_view.clone()));
+                }
+                if member.constant.is_some() {
+                    return Err(InterpretError::BitFieldConstant(member.name.code_view.clone()));
+                }
+            }
+        }
+        for (first, last) in self.bit_field_groups() {
+            let bits = self.members[first..=last]
                 .iter()
-                .filter(|m| m.name.data == member.name.data)
-                .count()
-                != 1
-            {
-                return Err(InterpretError::StructMemberNotUnique);
+                .map(|m| m.bit_field_width().unwrap() as usize)
+                .sum::<usize>();
+            if bits % 8 != 0 {
+                return Err(InterpretError::BitFieldGroupNotWholeBytes(self.members[last].name.code_view.clone()));
+            }
+            if NativeType::unsigned_from_size(bits / 8).is_none() {
+                return Err(InterpretError::BitFieldGroupTooBig(self.members[last].name.code_view.clone()));
+            }
+        }
+        Ok(())
+    }
+    // Struct and view members are serialized by their own declaration, bit field group shares one container.
+    fn check_endians(&self) -> Result<(), InterpretError> {
+        for member in &self.members {
+            if let Some(endian) = &member.endian {
+                if member.typ.typ.is_struct() || member.typ.typ.is_view() {
+                    return Err(InterpretError::EndianOnCompoundMember(endian.code_view.clone()));
+                }
+            }
+        }
+        for (first, last) in self.bit_field_groups() {
+            let endian = self.member_endian(&self.members[first]);
+            if self.members[first..=last].iter().any(|m| self.member_endian(m) != endian) {
+                return Err(InterpretError::BitFieldGroupMixedEndian(self.members[last].name.code_view.clone()));
+            }
+        }
+        Ok(())
+    }
+    // Member endian overrides struct endian, none means endian of schema.
+    pub fn member_endian(&self, member: &StructMember) -> Option<bool> {
+        member.endian.as_ref().or(self.endian.as_ref()).map(|e| e.data)
+    }
+    // Length of dynamic string is known only from dimension member preceding it, which may be
+    // member of struct embedding this one.
+    fn check_strings(&self, types: &Types) -> Result<(), InterpretError> {
+        for (i, member) in self.members.iter().enumerate() {
+            if !member.typ.typ.is_string() {
+                continue
+            }
+            match member.typ.array_size {
+                ArraySize::No => return Err(InterpretError::StringWithoutLength(member.name.code_view.clone())),
+                ArraySize::Dyn | ArraySize::Bounded(_) => {
+                    let referenced = self.members[..i].iter().any(|m| match &m.constant {
+                        Some(StructMemberConstant::ArrayDimension(mr)) => mr.member_name.data == member.name.data,
+                        _ => false,
+                    });
+                    if !referenced && !types.has_outer_dimension(&self.name.data, &member.name.data) {
+                        return Err(InterpretError::StringWithoutDimension(member.name.code_view.clone()));
+                    }
+                },
+                ArraySize::Exact(_) | ArraySize::Rest | ArraySize::Until(_) => {},
+            }
+        }
+        Ok(())
+    }
+    // Array running to end of message takes all bytes after its start, so it
+    // can only end struct which is not part of another type.
+    fn check_rest_arrays(&self) -> Result<(), InterpretError> {
+        for member in &self.members {
+            if member.typ.array_size.is_rest() && member.index + 1 != self.members.len() {
+                return Err(InterpretError::RestArrayNotLast(member.name.code_view.clone()));
+            }
+            if let TypeVariant::Struct(s) = &member.typ.typ {
+                if s.borrow().ends_with_rest_array() {
+                    return Err(InterpretError::RestArrayNested(member.name.code_view.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+    pub fn ends_with_rest_array(&self) -> bool {
+        self.members.last().is_some_and(|m| m.typ.array_size.is_rest())
+    }
+    // Consecutive bit field members share one container, groups are returned as (first, last) member index.
+    pub fn bit_field_groups(&self) -> Vec<(usize, usize)> {
+        let mut groups = Vec::new();
+        let mut first = None;
+        for (i, member) in self.members.iter().enumerate() {
+            match (member.bit_field_width(), first) {
+                (Some(_), None) => first = Some(i),
+                (None, Some(f)) => {
+                    groups.push((f, i - 1));
+                    first = None;
+                },
+                _ => {}
+            }
+        }
+        if let Some(f) = first {
+            groups.push((f, self.members.len() - 1));
+        }
+        groups
+    }
+    fn check_unique_member_names(&self) -> Result<(), InterpretError> {
+        for (i, member) in self.members.iter().enumerate() {
+            if let Some(origin) = self.members[..i].iter().find(|m| m.name.data == member.name.data) {
+                return Err(InterpretError::StructMemberNotUnique(
+                    member.name.data.clone(),
+                    origin.name.code_view.clone(),
+                    member.name.code_view.clone(),
+                ));
             }
         }
         Ok(())
@@ -32,56 +143,89 @@ impl Struct {
                 }
                 match c {
                     StructMemberConstant::Usize(_value) => continue,
-                    StructMemberConstant::ViewReferenceKey(mr) => {
-                        if let Some(t) = types.get_type(&mr.member_name.data)? {
-                            if let Some(i) = self.get_member_index_by_name(&mr.member_name.data) {
-                                if !self.members[i].typ.typ.is_view() {
-                                    return Err(InterpretError::MemberReferenceDoesntPointToView(mr.member_name.code_view.clone()))
-                                }
-                            } else {
-                                return Err(InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()));
-                            }
-                            if let Some(view) = t.as_view() {
-                                // find max value of view constant
-                                let mut max_value = 0;
-                                for t in &view.borrow().types {
-                                    if let Some(c) = &t.constant {
-                                        match c {
-                                            parser::ViewConstantValue::Usize(value) => 
-                                                max_value = max_value.max(value.data),
-                                            parser::ViewConstantValue::EnumMemberRef(e) => 
-                                                max_value = max_value.max(
-                                                    types.get_enum_member_value(&e.enum_name, &e.enum_member)?),
-                                        }
-                                    }
-                                }
-                                if member.typ.typ.as_int().unwrap().max_value() < max_value {
-                                    return Err(InterpretError::ViewReferenceTypeTooSmall(
-                                        member.name.code_view.clone(),
-                                    ));
-                                }
-                            } else {
-                                return Err(InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()));
-                            }
-                        }
-                    },
-                    StructMemberConstant::ArrayDimension(mr) => {
-                        if let Some(i) = self.get_member_index_by_name(&mr.member_name.data) {
-                            if self.members[i].typ.array_size.is_no() {
-                                return Err(InterpretError::MemberReferenceDoesntPointToArray(mr.member_name.code_view.clone()))
-                            }
-                        } else {
-                            return Err(InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()));
+                    StructMemberConstant::ViewReferenceKey(mr)
+                    | StructMemberConstant::ArrayDimension(mr)
+                    | StructMemberConstant::Size(mr) => {
+                        // reference leaving the struct is checked where the struct is embedded
+                        if let Some((_, target)) = self.reference_target(mr)? {
+                            check_reference_target(member, &target, types)?;
                         }
                     },
-                    StructMemberConstant::Size(mr) => {
-                        if self.get_member_index_by_name(&mr.member_name.data).is_none() {
-                            return Err(InterpretError::UnknownStructMemberReference(mr.member_name.code_view.clone()));
-                        }
+                    StructMemberConstant::Checksum(checksum) => self.check_checksum(member, checksum)?,
+                }
+            }
+        }
+        Ok(())
+    }
+    // Checksum covers consecutive whole members other than itself, value has width of algorithm.
+    fn check_checksum(&self, member: &StructMember, checksum: &parser::Checksum) -> Result<(), InterpretError> {
+        let int = member.typ.typ.as_int().unwrap();
+        let fits = match checksum.algorithm.bits() {
+            Some(bits) => int.bytes == bits,
+            None => !int.is_bit_field(),
+        };
+        if int.signed || !member.typ.array_size.is_no() || !fits {
+            return Err(InterpretError::ChecksumTypeMismatch(member.name.code_view.clone()));
+        }
+        let first = self.get_member_index_by_name(&checksum.first.data)
+            .ok_or(InterpretError::UnknownStructMemberReference(checksum.first.code_view.clone()))?;
+        let last = self.get_member_index_by_name(&checksum.last.data)
+            .ok_or(InterpretError::UnknownStructMemberReference(checksum.last.code_view.clone()))?;
+        if first > last || (first..=last).contains(&member.index) {
+            return Err(InterpretError::ChecksumInvalidRange(checksum.first.code_view.clone()));
+        }
+        // checksums covering each other cannot be computed
+        for other in &self.members[first..=last] {
+            if let Some(StructMemberConstant::Checksum(other_checksum)) = &other.constant {
+                let other_first = self.get_member_index_by_name(&other_checksum.first.data);
+                let other_last = self.get_member_index_by_name(&other_checksum.last.data);
+                if let (Some(other_first), Some(other_last)) = (other_first, other_last) {
+                    if (other_first..=other_last).contains(&member.index) {
+                        return Err(InterpretError::ChecksumInvalidRange(checksum.first.code_view.clone()));
                     }
                 }
             }
         }
+        for (group_first, group_last) in self.bit_field_groups() {
+            if group_first < first && first <= group_last {
+                return Err(InterpretError::ChecksumRangeSplitsBitFieldGroup(checksum.first.code_view.clone()));
+            }
+            if group_first <= last && last < group_last {
+                return Err(InterpretError::ChecksumRangeSplitsBitFieldGroup(checksum.last.code_view.clone()));
+            }
+        }
+        Ok(())
+    }
+    // Presence of optional member is decided by preceding unsigned or bool flag member.
+    fn check_conditions(&self) -> Result<(), InterpretError> {
+        for member in &self.members {
+            let condition = match &member.condition {
+                Some(condition) => condition,
+                None => continue,
+            };
+            if member.bit_field_width().is_some() || member.constant.is_some() {
+                return Err(InterpretError::OptionalMemberConstant(member.name.code_view.clone()));
+            }
+            let flag = self.get_member_index_by_name(&condition.member_name.data)
+                .ok_or(InterpretError::UnknownStructMemberReference(condition.member_name.code_view.clone()))?;
+            if flag >= member.index {
+                return Err(InterpretError::ConditionFlagMustPrecede(condition.member_name.code_view.clone()));
+            }
+            let flag = &self.members[flag];
+            let width = match &flag.typ.typ {
+                TypeVariant::Int(int) if !int.signed => int.bytes,
+                TypeVariant::Bool(_) if condition.bit.is_none() => 1,
+                _ => 0,
+            };
+            if width == 0 || !flag.typ.array_size.is_no() || flag.constant.is_some() || flag.condition.is_some() {
+                return Err(InterpretError::ConditionFlagNotUnsigned(condition.member_name.code_view.clone()));
+            }
+            if let Some(bit) = &condition.bit {
+                if bit.data >= width {
+                    return Err(InterpretError::ConditionBitOutOfRange(bit.code_view.clone()));
+                }
+            }
+        }
         Ok(())
     }
     pub fn resolve_members_with_unknown_types(
@@ -112,6 +256,9 @@ impl Struct {
                 TypeVariant::Enum(_) => true,
                 TypeVariant::View(t) => known_types.contains(&t.borrow().name),
                 TypeVariant::Int(_) => true,
+                TypeVariant::Float(_) => true,
+                TypeVariant::Bool(_) => true,
+                TypeVariant::String(_) => true,
                 TypeVariant::Unknown(_) => panic!("unexpected unknown type"),
             })
     }
@@ -119,3 +266,13 @@ impl Struct {
         self.members.iter().position(|m| m.name.data == name)
     }
 }
+
+impl StructMember {
+    pub fn bit_field_width(&self) -> Option<u8> {
+        match &self.typ.typ {
+            TypeVariant::Int(int) if int.is_bit_field() => Some(int.bytes),
+            TypeVariant::Bool(boolean) if boolean.bits == 1 => Some(1),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+mod tests;
+
+use super::*;
+
+// Interprets sources given as (file name, content), imported files first.
+fn interpret_sources(sources: &[(&str, &str)]) -> Result<MemoryImage, Vec<InterpretError>> {
+    let mut tokens = Vec::new();
+    for (file, content) in sources {
+        let (parsed, errors) = parser::parse_view(CodeView::from_file(file, String::from(*content)));
+        assert!(errors.is_empty(), "parse failed");
+        tokens.extend(parsed);
+    }
+    interpret(tokens)
+}
+
+fn diagnostic(sources: &[(&str, &str)]) -> String {
+    match interpret_sources(sources) {
+        Ok(_) => panic!("interpret succeeded"),
+        Err(errors) => errors
+            .iter()
+            .map(|e| e.to_diagnostic().to_string())
+            .collect::<Vec<String>>()
+            .join("\n\n"),
+    }
+}
+
+fn check(source: &str, expected: &str) {
+    assert_eq!(diagnostic(&[("test.abf", source)]), expected);
+}
@@ -0,0 +1,794 @@
+use super::*;
+
+#[test]
+fn type_contains_itself() {
+    check(
+        "endian big\nstruct A {\n    a: A\n}",
+        r#"error: Type 'A' contains itself
+ --> test.abf:2:8
+  |
+2 | struct A {
+  |        ^ contains itself"#
+    );
+}
+
+#[test]
+fn unknown_type() {
+    check(
+        "endian big\nstruct A {\n    b: B\n}",
+        r#"error: Unknown type 'B'
+ --> test.abf:3:8
+  |
+3 |     b: B
+  |        ^ not declared"#
+    );
+}
+
+#[test]
+fn cyclical_reference() {
+    check(
+        "endian big\nstruct A {\n    b: B\n}\nstruct B {\n    a: A\n}",
+        r#"error: Cyclical reference: A -> B
+ --> test.abf:2:1
+  |
+2 | struct A {
+  | ^^^^^^^^^^ part of the cycle
+ ::: test.abf:5:1
+  |
+5 | struct B {
+  | ---------- part of the cycle"#
+    );
+}
+
+#[test]
+fn struct_member_not_unique() {
+    check(
+        "endian big\nstruct A {\n    x: u8,\n    x: u8\n}",
+        r#"error: Struct member 'x' is not unique
+ --> test.abf:4:5
+  |
+4 |     x: u8
+  |     ^ declared again here
+ ::: test.abf:3:5
+  |
+3 |     x: u8,
+  |     - first declared here"#
+    );
+}
+
+#[test]
+fn unknown_struct_member_reference() {
+    check(
+        "endian big\nstruct A {\n    len: u8 = data.dimension\n}",
+        r#"error: Unknown struct member reference 'data'
+ --> test.abf:3:15
+  |
+3 |     len: u8 = data.dimension
+  |               ^^^^"#
+    );
+}
+
+#[test]
+fn member_reference_doesnt_point_to_view() {
+    check(
+        "endian big\nstruct B {}\nstruct A {\n    k: u8 = B.key,\n    B: u8\n}",
+        r#"error: Member reference 'B' doesn't point to view
+ --> test.abf:4:13
+  |
+4 |     k: u8 = B.key,
+  |             ^"#
+    );
+}
+
+#[test]
+fn member_reference_doesnt_point_to_array() {
+    check(
+        "endian big\nstruct A {\n    len: u8 = x.dimension,\n    x: u8\n}",
+        r#"error: Member reference 'x' doesn't point to array
+ --> test.abf:3:15
+  |
+3 |     len: u8 = x.dimension,
+  |               ^"#
+    );
+}
+
+#[test]
+fn unknown_nested_member_reference() {
+    check(
+        "endian big\nstruct B {\n    x: u8\n}\nstruct A {\n    len: u8 = b.y.dimension,\n    b: B\n}",
+        r#"error: Unknown struct member reference 'b.y'
+ --> test.abf:6:15
+  |
+6 |     len: u8 = b.y.dimension,
+  |               ^^^"#
+    );
+}
+
+#[test]
+fn member_path_not_through_struct() {
+    check(
+        "endian big\nstruct B {\n    x: [u8]\n}\nstruct A {\n    len: u8 = b.x.dimension,\n    b: [B, 2]\n}",
+        r#"error: Member reference path 'b.x' must lead through struct members which are neither arrays nor optional
+ --> test.abf:6:15
+  |
+6 |     len: u8 = b.x.dimension,
+  |               ^^^"#
+    );
+}
+
+#[test]
+fn outer_reference_embedding() {
+    check(
+        "endian big\nstruct B {\n    len: u8 = data.dimension\n}\nstruct A {\n    b: [B, 2],\n    data: [u8]\n}",
+        r#"error: Member 'b' embeds struct referring to members outside of it, so it cannot be array or optional
+ --> test.abf:6:5
+  |
+6 |     b: [B, 2],
+  |     ^"#
+    );
+}
+
+#[test]
+fn array_dimension_conflict() {
+    check(
+        "endian big\nstruct B {\n    n: u8 = items.dimension,\n    items: [u8]\n}\nstruct A {\n    m: u8 = b.items.dimension,\n    b: B\n}",
+        r#"error: Dimension 'm' conflicts with other member dimensioning the same array
+ --> test.abf:7:5
+  |
+7 |     m: u8 = b.items.dimension,
+  |     ^"#
+    );
+}
+
+#[test]
+fn struct_member_constant_can_be_aplied_only_for_int() {
+    check(
+        "endian big\nstruct A {\n    x: f32 = 1\n}",
+        r#"error: Struct member constant 'x' can be aplied only for integral type
+ --> test.abf:3:5
+  |
+3 |     x: f32 = 1
+  |     ^"#
+    );
+}
+
+#[test]
+fn view_reference_type_too_small() {
+    check(
+        "endian big\nview V {\n    u8 = 300,\n    u16 = 2\n}\nstruct A {\n    k: u8 = V.key,\n    V: V\n}",
+        r#"error: View reference integral type 'k' is too small
+ --> test.abf:7:5
+  |
+7 |     k: u8 = V.key,
+  |     ^"#
+    );
+}
+
+#[test]
+fn unknown_int_size() {
+    check(
+        "endian big\nview V {\n    u3,\n    u8\n}",
+        r#"error: Unknown int size '3'
+ --> test.abf:3:5
+  |
+3 |     u3,
+  |     ^^"#
+    );
+}
+
+#[test]
+fn unknown_float_size() {
+    check(
+        "endian big\nstruct A {\n    x: f16\n}",
+        r#"error: Unknown float size '16'
+ --> test.abf:3:8
+  |
+3 |     x: f16
+  |        ^^^"#
+    );
+}
+
+#[test]
+fn unknown_bool_size() {
+    check(
+        "endian big\nview V {\n    bool3,\n    u8\n}",
+        r#"error: Unknown bool size '3'
+ --> test.abf:3:5
+  |
+3 |     bool3,
+  |     ^^^^^"#
+    );
+}
+
+#[test]
+fn bit_field_array() {
+    check(
+        "endian big\nstruct A {\n    x: [u4, 2]\n}",
+        r#"error: Bit field member 'x' cannot be array
+ --> test.abf:3:5
+  |
+3 |     x: [u4, 2]
+  |     ^"#
+    );
+}
+
+#[test]
+fn bit_field_constant() {
+    check(
+        "endian big\nstruct A {\n    x: u3 = 1,\n    y: u5\n}",
+        r#"error: Bit field member 'x' cannot have constant
+ --> test.abf:3:5
+  |
+3 |     x: u3 = 1,
+  |     ^"#
+    );
+}
+
+#[test]
+fn bit_field_group_not_whole_bytes() {
+    check(
+        "endian big\nstruct A {\n    x: u3\n}",
+        r#"error: Bit field group ending with member 'x' does not fill whole bytes
+ --> test.abf:3:5
+  |
+3 |     x: u3
+  |     ^"#
+    );
+}
+
+#[test]
+fn bit_field_group_too_big() {
+    check(
+        "endian big\nstruct A {\n    x: u40,\n    y: u8\n}",
+        r#"error: Bit field group ending with member 'x' does not fit into 1, 2, 3, 4 or 8 bytes
+ --> test.abf:3:5
+  |
+3 |     x: u40,
+  |     ^"#
+    );
+}
+
+#[test]
+fn bit_field_group_mixed_endian() {
+    check(
+        "endian big\nstruct A endian little {\n    x: u4,\n    y: u12 endian big\n}",
+        r#"error: Bit field group ending with member 'y' mixes big and little endian members
+ --> test.abf:4:5
+  |
+4 |     y: u12 endian big
+  |     ^"#
+    );
+}
+
+#[test]
+fn endian_on_compound_member() {
+    check(
+        "endian big\nstruct B {\n    x: u8\n}\nstruct A {\n    b: B endian little\n}",
+        r#"error: Endian can be set only on members of native or enum type
+ --> test.abf:6:10
+  |
+6 |     b: B endian little
+  |          ^^^^^^^^^^^^^ struct and view members keep endian of their declaration"#
+    );
+}
+
+#[test]
+fn string_without_length() {
+    check(
+        "endian big\nstruct A {\n    s: string\n}",
+        r#"error: String 's' must be declared as [string, size] or [string]
+ --> test.abf:3:5
+  |
+3 |     s: string
+  |     ^"#
+    );
+}
+
+#[test]
+fn string_without_dimension() {
+    check(
+        "endian big\nstruct A {\n    s: [string]\n}",
+        r#"error: Dynamic string 's' must be preceded by its dimension member
+ --> test.abf:3:5
+  |
+3 |     s: [string]
+  |     ^"#
+    );
+}
+
+#[test]
+fn dimension_type_too_small() {
+    check(
+        "endian big\nstruct A {\n    len: u8 = data.dimension,\n    data: [u8, ..300]\n}",
+        r#"error: Dimension integral type 'len' is too small for array bound
+ --> test.abf:3:5
+  |
+3 |     len: u8 = data.dimension,
+  |     ^^^"#
+    );
+}
+
+#[test]
+fn rest_array_not_last() {
+    check(
+        "endian big\nstruct A {\n    data: [u8, ..],\n    x: u8\n}",
+        r#"error: Array 'data' running to end of message must be last member of its struct
+ --> test.abf:3:5
+  |
+3 |     data: [u8, ..],
+  |     ^^^^"#
+    );
+}
+
+#[test]
+fn rest_array_nested() {
+    check(
+        "endian big\nstruct A {\n    x: u8,\n    data: [u8, ..]\n}\nstruct B {\n    a: A\n}",
+        r#"error: 'a' runs to end of message, so it cannot be nested in another type
+ --> test.abf:7:5
+  |
+7 |     a: A
+  |     ^"#
+    );
+}
+
+#[test]
+fn rest_array_dimension() {
+    check(
+        "endian big\nstruct A {\n    len: u8 = data.dimension,\n    data: [u8, ..]\n}",
+        r#"error: Array 'data' running to end of message cannot have dimension
+ --> test.abf:3:15
+  |
+3 |     len: u8 = data.dimension,
+  |               ^^^^"#
+    );
+}
+
+#[test]
+fn terminated_array_dimension() {
+    check(
+        "endian big\nstruct A {\n    len: u8 = data.dimension,\n    data: [u8, until 0]\n}",
+        r#"error: Array 'data' ended by terminator cannot have dimension
+ --> test.abf:3:15
+  |
+3 |     len: u8 = data.dimension,
+  |               ^^^^"#
+    );
+}
+
+#[test]
+fn terminator_not_fitting() {
+    check(
+        "endian big\nstruct A {\n    data: [u8, until 256]\n}",
+        r#"error: Terminator '256' does not match integral, bool, character or enum value it is compared with
+ --> test.abf:3:22
+  |
+3 |     data: [u8, until 256]
+  |                      ^^^"#
+    );
+}
+
+#[test]
+fn terminator_item_not_sized() {
+    check(
+        "endian big\nstruct B {\n    kind: u8,\n    data: [u8, until 0]\n}\nstruct A {\n    data: [B, until kind == 0]\n}",
+        r#"error: Struct compared by terminator member 'kind' must have exact size
+ --> test.abf:7:21
+  |
+7 |     data: [B, until kind == 0]
+  |                     ^^^^"#
+    );
+}
+
+#[test]
+fn terminated_array_in_view() {
+    check(
+        "endian big\nview V {\n    u8,\n    [u8, until 0]\n}",
+        r#"error: Terminated array of 'u8' cannot be view item
+ --> test.abf:4:6
+  |
+4 |     [u8, until 0]
+  |      ^^"#
+    );
+}
+
+#[test]
+fn terminator_unknown_member() {
+    check(
+        "endian big\nstruct B {\n    kind: u8\n}\nstruct A {\n    data: [B, until type == 0]\n}",
+        r#"error: Unknown struct member reference 'type'
+ --> test.abf:6:21
+  |
+6 |     data: [B, until type == 0]
+  |                     ^^^^"#
+    );
+}
+
+#[test]
+fn checksum_type_mismatch() {
+    check(
+        "endian big\nstruct A {\n    c: u8 = crc16_ccitt(a..a),\n    a: u8\n}",
+        r#"error: Checksum member 'c' type does not match its algorithm
+ --> test.abf:3:5
+  |
+3 |     c: u8 = crc16_ccitt(a..a),
+  |     ^"#
+    );
+}
+
+#[test]
+fn checksum_invalid_range() {
+    check(
+        "endian big\nstruct A {\n    c: u8 = crc8(c..a),\n    a: u8\n}",
+        r#"error: Checksum range starting with 'c' must be ordered and must not cover checksum member
+ --> test.abf:3:18
+  |
+3 |     c: u8 = crc8(c..a),
+  |                  ^"#
+    );
+}
+
+#[test]
+fn checksum_range_splits_bit_field_group() {
+    check(
+        "endian big\nstruct A {\n    c: u8 = crc8(b..d),\n    a: u4,\n    b: u4,\n    d: u8\n}",
+        r#"error: Checksum range boundary 'b' splits bit field group
+ --> test.abf:3:18
+  |
+3 |     c: u8 = crc8(b..d),
+  |                  ^"#
+    );
+}
+
+#[test]
+fn condition_flag_must_precede() {
+    check(
+        "endian big\nstruct A {\n    x: u8 if f,\n    f: u8\n}",
+        r#"error: Presence flag 'f' must precede optional member
+ --> test.abf:3:14
+  |
+3 |     x: u8 if f,
+  |              ^"#
+    );
+}
+
+#[test]
+fn condition_flag_not_unsigned() {
+    check(
+        "endian big\nstruct A {\n    f: i8,\n    x: u8 if f\n}",
+        r#"error: Presence flag 'f' must be unsigned integral or bool member without constant
+ --> test.abf:4:14
+  |
+4 |     x: u8 if f
+  |              ^"#
+    );
+}
+
+#[test]
+fn condition_bit_out_of_range() {
+    check(
+        "endian big\nstruct A {\n    f: u8,\n    x: u8 if f.bit8\n}",
+        r#"error: Presence bit '8' is out of range of flag member
+ --> test.abf:4:19
+  |
+4 |     x: u8 if f.bit8
+  |                   ^"#
+    );
+}
+
+#[test]
+fn optional_member_constant() {
+    check(
+        "endian big\nstruct A {\n    f: u8,\n    x: u8 if f = 1\n}",
+        r#"error: Optional member 'x' cannot be bit field or have constant
+ --> test.abf:4:5
+  |
+4 |     x: u8 if f = 1
+  |     ^"#
+    );
+}
+
+#[test]
+fn optional_member_referenced() {
+    check(
+        "endian big\nstruct B {}\nstruct A {\n    f: u8,\n    s: u8 = x.size,\n    x: B if f\n}",
+        r#"error: Optional member 'x' cannot be referenced by key, dimension or size
+ --> test.abf:6:5
+  |
+6 |     x: B if f
+  |     ^"#
+    );
+}
+
+#[test]
+fn enum_constant_not_unique() {
+    check(
+        "endian big\nenum E : u8 {\n    A = 1,\n    A = 2\n}",
+        r#"error: Enum constant 'A = 1' not unique
+ --> test.abf:3:5
+  |
+3 |     A = 1,
+  |     ^^^^^"#
+    );
+}
+
+#[test]
+fn enum_constant_value_not_unique() {
+    check(
+        "endian big\nenum E : u8 {\n    A = 1,\n    B = 1\n}",
+        r#"error: Enum constant value 'A = 1' not unique
+ --> test.abf:3:5
+  |
+3 |     A = 1,
+  |     ^^^^^"#
+    );
+}
+
+#[test]
+fn enum_constant_value_not_fitting() {
+    check(
+        "endian big\nenum E : u8 {\n    A = 300\n}",
+        r#"error: Enum constant value 'A = 300' not fitting
+ --> test.abf:3:5
+  |
+3 |     A = 300
+  |     ^^^^^^^"#
+    );
+}
+
+#[test]
+fn unknown_enum_member() {
+    check(
+        "endian big\nenum E : u8 {\n    A = 1\n}\nview V {\n    u8 = E::B,\n    u16 = E::A\n}",
+        r#"error: Unknown enum member 'B'
+ --> test.abf:6:13
+  |
+6 |     u8 = E::B,
+  |             ^"#
+    );
+}
+
+#[test]
+fn unknown_enum() {
+    check(
+        "endian big\nview V {\n    u8 = F::A,\n    u16 = F::B\n}",
+        r#"error: Unknown enum 'F'
+ --> test.abf:3:10
+  |
+3 |     u8 = F::A,
+  |          ^"#
+    );
+}
+
+#[test]
+fn view_item_not_unique_within_view() {
+    check(
+        "endian big\nview V {\n    u8,\n    u8\n}",
+        r#"error: View item 'u8' not unique within view
+ --> test.abf:3:5
+  |
+3 |     u8,
+  |     ^^"#
+    );
+}
+
+#[test]
+fn view_reference_key_is_too_big() {
+    check(
+        "endian big\nview V {\n    u8 = 4294967296,\n    u16 = 1\n}\nstruct A {\n    k: u64 = V.key,\n    V: V\n}",
+        r#"error: View reference key 'V' is too big (max is 4 bytes)
+ --> test.abf:7:14
+  |
+7 |     k: u64 = V.key,
+  |              ^"#
+    );
+}
+
+#[test]
+fn view_empty() {
+    check(
+        "endian big\nview V {}",
+        r#"error: View 'V' is empty
+ --> test.abf:2:1
+  |
+2 | view V {}
+  | ^^^^^^^^^ declared here"#
+    );
+}
+
+#[test]
+fn viev_constants_must_be_all_enums_or_all_ints_or_all_undefined() {
+    check(
+        "endian big\nview V {\n    u8 = 1,\n    u16\n}",
+        r#"error: View constants must be all enums or all ints or all undefined"#
+    );
+}
+
+#[test]
+fn endian_not_set() {
+    check(
+        "struct A {\n    x: u8\n}",
+        r#"error: Endian not set"#
+    );
+}
+
+#[test]
+fn endian_overrided() {
+    check(
+        "endian big\nendian little",
+        r#"error: Endian cannot be overridden
+ --> test.abf:2:1
+  |
+2 | endian little
+  | ^^^^^^^^^^^^^ overridden here
+ ::: test.abf:1:1
+  |
+1 | endian big
+  | ---------- originally defined here"#
+    );
+}
+
+#[test]
+fn bit_order_overrided() {
+    check(
+        "endian big\nbit_order msb\nbit_order lsb",
+        r#"error: Bit order cannot be overridden
+ --> test.abf:3:1
+  |
+3 | bit_order lsb
+  | ^^^^^^^^^^^^^ overridden here
+ ::: test.abf:2:1
+  |
+2 | bit_order msb
+  | ------------- originally defined here"#
+    );
+}
+
+#[test]
+fn generic_error() {
+    check(
+        "endian big\nstruct A {\n    x: u8 = 300\n}",
+        r#"error: Cannot convert 300 to u8
+ --> test.abf:3:5
+  |
+3 |     x: u8 = 300
+  |     ^"#
+    );
+}
+
+#[test]
+fn cannot_asign_usize_cst_to_non_unsigned_memory() {
+    // constants of non integral members are refused before memory is built
+    assert_eq!(
+        InterpretError::CannotAsignUsizeCstToNonUnsignedMemory(5).to_diagnostic().to_string(),
+        "error: Cannot asign 5 to non unsigned memory"
+    );
+}
+
+#[test]
+fn generic_with_pos_error() {
+    let mut code_view = CodeView::from_file("test.abf", "endian big\nstruct A {}".into());
+    code_view.from = 18;
+    code_view.to = 19;
+    assert_eq!(
+        InterpretError::GenericWithPosError(code_view, "Something failed".into()).to_diagnostic().to_string(),
+        r#"error: Something failed
+ --> test.abf:2:8
+  |
+2 | struct A {}
+  |        ^"#
+    );
+}
+
+#[test]
+fn type_name_clash() {
+    let diagnostic = diagnostic(&[
+        ("common.abf", "endian big\nstruct A {\n    x: u8\n}"),
+        ("test.abf", "import \"common.abf\"\nendian big\n\nstruct A {\n    y: u16\n}"),
+    ]);
+    assert_eq!(
+        diagnostic,
+        r#"error: Type 'A' is defined multiple times
+ --> test.abf:4:1
+  |
+4 | struct A {
+  | ^^^^^^^^^^ defined again here
+ ::: common.abf:2:1
+  |
+2 | struct A {
+  | ---------- first defined here"#
+    );
+}
+
+#[test]
+fn imported_endian() {
+    let diagnostic = diagnostic(&[
+        ("common.abf", "endian little"),
+        ("test.abf", "import \"common.abf\"\nendian big"),
+    ]);
+    assert_eq!(
+        diagnostic,
+        r#"error: Endian cannot be overridden
+ --> test.abf:2:1
+  |
+2 | endian big
+  | ^^^^^^^^^^ overridden here
+ ::: common.abf:1:1
+  |
+1 | endian little
+  | ------------- originally defined here"#
+    );
+}
+
+#[test]
+fn module_name_clash() {
+    let diagnostic = diagnostic(&[
+        ("a/common.abf", "endian big\nstruct X {\n    x: u8\n}"),
+        ("b/common.abf", "endian big\nstruct Y {\n    y: u8\n}"),
+        ("test.abf", "import \"a/common.abf\"\nimport \"b/common.abf\"\nendian big"),
+    ]);
+    assert_eq!(
+        diagnostic,
+        r#"error: Module 'common' is imported from multiple files
+ --> test.abf:2:1
+  |
+2 | import "b/common.abf"
+  | ^^^^^^^^^^^^^^^^^^^^^ other file of the same module name
+ ::: test.abf:1:1
+  |
+1 | import "a/common.abf"
+  | --------------------- first file of the module"#
+    );
+}
+
+#[test]
+fn module_imported_by_relative_paths() {
+    // file imported repeatedly is parsed once, under path of its first import
+    let memory = interpret_sources(&[
+        ("sub/../common.abf", "endian big\nstruct X {\n    x: u8\n}"),
+        ("sub/other.abf", "import \"../common.abf\"\nendian big"),
+        ("test.abf", "import \"sub/other.abf\"\nimport \"common.abf\"\nendian big"),
+    ]);
+    assert!(memory.is_ok());
+}
+
+#[test]
+fn all_errors_reported() {
+    check(
+        "endian big\nstruct A {\n    x: u3\n}\nstruct B {\n    s: string\n}",
+        r#"error: Bit field group ending with member 'x' does not fill whole bytes
+ --> test.abf:3:5
+  |
+3 |     x: u3
+  |     ^
+
+error: String 's' must be declared as [string, size] or [string]
+ --> test.abf:6:5
+  |
+6 |     s: string
+  |     ^"#
+    );
+}
+
+#[test]
+fn required_version_not_met() {
+    check(
+        "required_version 1.0.0\nendian big",
+        &format!(r#"error: Required version 1.0.0 is newer than compiler version {}
+ --> test.abf:1:1
+  |
+1 | required_version 1.0.0
+  | ^^^^^^^^^^^^^^^^^^^^^^ required here"#, env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn required_version_met() {
+    let version = format!("required_version {}\nendian big", env!("CARGO_PKG_VERSION"));
+    assert!(interpret_sources(&[("test.abf", &version)]).is_ok());
+}
+
+#[test]
+fn required_version_older() {
+    assert!(interpret_sources(&[("test.abf", "required_version 0.0.5\nendian big")]).is_ok());
+}
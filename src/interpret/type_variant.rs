@@ -7,6 +7,18 @@ impl TypeVariant {
                 typ: TypeVariant::Int(int.clone()),
                 array_size: array_size
             },
+            parser::TypVariant::Float(float) => Type {
+                typ: TypeVariant::Float(float.clone()),
+                array_size: array_size
+            },
+            parser::TypVariant::Bool(boolean) => Type {
+                typ: TypeVariant::Bool(boolean.clone()),
+                array_size: array_size
+            },
+            parser::TypVariant::String(string) => Type {
+                typ: TypeVariant::String(string.clone()),
+                array_size: array_size
+            },
             parser::TypVariant::Unknown(unknown) => Type {
                 typ: TypeVariant::Unknown(unknown.clone()),
                 array_size: array_size
@@ -23,7 +35,7 @@ impl TypeVariant {
                 false
             }
         }) {
-            return Err(InterpretError::TypeContainsItself(s));
+            return Err(InterpretError::TypeContainsItself(s.data.name));
         }
         // create structure
         Ok(TypeVariant::Struct(Rc::new(RefCell::new(DataView::new(
@@ -38,6 +50,8 @@ impl TypeVariant {
                             name: member.name.clone(),
                             index: i,
                             typ: Self::from_parser_typ(&member.typ.typ, member.typ.array_size.clone()),
+                            endian: member.endian.clone().map(|e| e.convert(|e| e.big)),
+                            condition: member.condition.clone(),
                             constant: match &member.constant {
                                     parser::StructMemberConstant::No => None,
                                     parser::StructMemberConstant::Usize(value) => Some(StructMemberConstant::Usize(*value)),
@@ -50,10 +64,15 @@ impl TypeVariant {
                                     parser::StructMemberConstant::Size(mr) => {
                                         Some(StructMemberConstant::Size(mr.clone()))
                                     }
+                                    parser::StructMemberConstant::Checksum(c) => {
+                                        Some(StructMemberConstant::Checksum(c.clone()))
+                                    }
                             },
                         };
                     })
                     .collect(),
+                endian: s.endian.clone().map(|e| e.convert(|e| e.big)),
+                lsb_first: false,
             },
             s.code_view,
         )))))
@@ -106,6 +125,9 @@ impl TypeVariant {
             TypeVariant::View(view) => view.borrow().has_known_types(known_types),
             TypeVariant::Enum(_) => return true,
             TypeVariant::Int(_) => return true,
+            TypeVariant::Float(_) => return true,
+            TypeVariant::Bool(_) => return true,
+            TypeVariant::String(_) => return true,
             TypeVariant::Unknown(_) => panic!("unexpected unknoqn type"),
         }
     }
@@ -115,6 +137,9 @@ impl TypeVariant {
             TypeVariant::Enum(e) => e.check_type(),
             TypeVariant::View(v) => v.borrow_mut().check_type(types),
             TypeVariant::Int(_i) => Ok(()),
+            TypeVariant::Float(_f) => Ok(()),
+            TypeVariant::Bool(_b) => Ok(()),
+            TypeVariant::String(_s) => Ok(()),
             TypeVariant::Unknown(_unknown) => panic!("cannot check type for unknown"),
         }
     }
@@ -124,6 +149,9 @@ impl TypeVariant {
             TypeVariant::View(v) => v.borrow_mut().resolve_unknown_types(types),
             TypeVariant::Enum(_e) => Ok(()),
             TypeVariant::Int(_) => Ok(()),
+            TypeVariant::Float(_) => Ok(()),
+            TypeVariant::Bool(_) => Ok(()),
+            TypeVariant::String(_) => Ok(()),
             TypeVariant::Unknown(_unknown) => panic!("cannot resolve unknown types for unknown"),
         }
     }
@@ -133,6 +161,9 @@ impl TypeVariant {
             TypeVariant::View(v) => v.borrow().code_view.clone(),
             TypeVariant::Enum(e) => e.code_view.clone(),
             TypeVariant::Int(i) => i.code_view.clone(),
+            TypeVariant::Float(f) => f.code_view.clone(),
+            TypeVariant::Bool(b) => b.code_view.clone(),
+            TypeVariant::String(s) => s.code_view.clone(),
             TypeVariant::Unknown(_unknown) => panic!("cannot get code view for unknown"),
         }
     }
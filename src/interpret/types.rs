@@ -2,35 +2,52 @@ use super::*;
 
 impl Types {
     pub fn put_struct(&mut self, typ: DataView<parser::Struct>) -> Result<(), InterpretError> {
-        self.types.insert(typ.name.data.clone(), TypeVariant::from_struct(typ)?);
+        self.check_name_clash(&typ.name.data, &typ.code_view)?;
+        let name = typ.name.data.clone();
+        self.types.insert(name.clone(), TypeVariant::from_struct(typ)?);
+        self.order.push(name);
         Ok(())
     }
     pub fn put_enum(&mut self, typ: DataView<parser::Enum>) -> Result<(), InterpretError> {
-        if self.types.contains_key(typ.name.as_str()) {
-            return Err(InterpretError::EnumAlreadyExists(typ));
-        }
-        self.types.insert(typ.name.clone(), TypeVariant::from_enum(typ)?);
+        self.check_name_clash(&typ.name, &typ.code_view)?;
+        let name = typ.name.clone();
+        self.types.insert(name.clone(), TypeVariant::from_enum(typ)?);
+        self.order.push(name);
         Ok(())
     }
     pub fn put_view(&mut self, typ: DataView<parser::View>) -> Result<(), InterpretError> {
-        if self.types.contains_key(typ.name.as_str()) {
-            return Err(InterpretError::ViewAlreadyExists(typ.code_view.clone()));
-        }
-        self.types.insert(typ.name.clone(), TypeVariant::from_view(typ)?);
+        self.check_name_clash(&typ.name, &typ.code_view)?;
+        let name = typ.name.clone();
+        self.types.insert(name.clone(), TypeVariant::from_view(typ)?);
+        self.order.push(name);
         Ok(())
     }
-    pub fn resolve_unknown_types(self) -> Result<Self, InterpretError> {
+    fn check_name_clash(&self, name: &str, code_view: &CodeView) -> Result<(), InterpretError> {
+        match self.types.get(name) {
+            Some(t) => Err(InterpretError::TypeNameClash(name.into(), t.code_view(), code_view.clone())),
+            None => Ok(()),
+        }
+    }
+    // Types are visited in order of declaration, so errors are reported in that order too.
+    pub fn resolve_unknown_types(&self) -> Vec<InterpretError> {
+        self.order
+            .iter()
+            .filter_map(|name| self.types[name].resolve_unknown_types(self).err())
+            .collect()
+    }
+    pub fn set_lsb_first(&self, lsb_first: bool) {
         for (_name, t) in &self.types {
-            t.resolve_unknown_types(&self)?
+            if let TypeVariant::Struct(s) = t {
+                s.borrow_mut().data.lsb_first = lsb_first;
+            }
         }
-        Ok(self)
     }
     pub fn resolve_types_order(&self) -> Result<Vec<String>, InterpretError> {
         let mut independent = Vec::<String>::default();
         let mut dependent = Vec::<String>::default();
         // first make all independet types - enums, types that consist only from native types
-        for (k, typ) in &self.types {
-            if typ.has_known_types(&independent) {
+        for k in &self.order {
+            if self.types[k].has_known_types(&independent) {
                 independent.push(k.clone())
             } else {
                 dependent.push(k.clone())
@@ -49,16 +66,21 @@ impl Types {
                 }
             }
             if dep_len == dependent.len() {
-                return Err(InterpretError::CyclicalReference(dependent));
+                return Err(InterpretError::CyclicalReference(
+                    dependent
+                        .iter()
+                        .map(|name| DataView::new(name.clone(), self.types[name].code_view()))
+                        .collect()
+                ));
             }
             dep_len = dependent.len();
         }
         Ok(independent)
     }
-    pub fn check_types(&self, types: &Types) -> Result<(), InterpretError> {
-        for (_name, typ) in &self.types {
-            let _ = typ.check_type(types)?;
-        }
-        Ok(())
+    pub fn check_types(&self, types: &Types) -> Vec<InterpretError> {
+        self.order
+            .iter()
+            .filter_map(|name| self.types[name].check_type(types).err())
+            .collect()
     }
 }
@@ -15,7 +15,10 @@ impl View {
         }
         Ok(())
     }
+}
 
+// Empty view is reported at its declaration, which only data view of it knows.
+impl DataView<View> {
     pub fn check_type(&mut self, types: &Types) -> Result<(), InterpretError> {
         for view_posibility in &self.types {
             // find count of type in self.types
@@ -28,6 +31,13 @@ impl View {
                             && t.typ.typ.as_int().unwrap().bytes == i.bytes
                             && t.typ.typ.as_int().unwrap().signed == i.signed
                     }
+                    TypeVariant::Float(f) => {
+                        t.typ.typ.is_float() && t.typ.typ.as_float().unwrap().bits == f.bits
+                    }
+                    TypeVariant::Bool(b) => {
+                        t.typ.typ.is_bool() && t.typ.typ.as_bool().unwrap().bits == b.bits
+                    }
+                    TypeVariant::String(_) => t.typ.typ.is_string(),
                     TypeVariant::Struct(s) => {
                         t.typ.typ.is_struct() && t.typ.typ.as_struct().unwrap().borrow().name.data == s.borrow().name.data
                     }
@@ -44,9 +54,28 @@ impl View {
         }
         for t in &mut self.types {
             t.typ.typ.check_type(types)?;
+            let ends_with_rest_array = match &t.typ.typ {
+                TypeVariant::Struct(s) => s.borrow().ends_with_rest_array(),
+                _ => false,
+            };
+            if t.typ.array_size.is_rest() || ends_with_rest_array {
+                return Err(InterpretError::RestArrayNested(t.typ.typ.code_view()));
+            }
+            // view item is selected by key, not by length of its own
+            if t.typ.array_size.is_until() {
+                return Err(InterpretError::TerminatedArrayInView(t.typ.typ.code_view()));
+            }
+            // view has no member to carry length of dynamic string
+            if t.typ.typ.is_string() {
+                match t.typ.array_size {
+                    ArraySize::No => return Err(InterpretError::StringWithoutLength(t.typ.typ.code_view())),
+                    ArraySize::Dyn | ArraySize::Bounded(_) => return Err(InterpretError::StringWithoutDimension(t.typ.typ.code_view())),
+                    ArraySize::Exact(_) | ArraySize::Rest | ArraySize::Until(_) => {},
+                }
+            }
         }
         if self.types.len() == 0 {
-            return Err(InterpretError::ViewEmpty(self.name.clone()));
+            return Err(InterpretError::ViewEmpty(self.name.clone(), self.code_view.clone()));
         }
         if !self.types
             .iter()
@@ -69,6 +98,9 @@ impl View {
             }
         Ok(())
     }
+}
+
+impl View {
     pub fn has_known_types(&self, known_types: &Vec<String>) -> bool {
         self.types
             .iter()
@@ -77,6 +109,9 @@ impl View {
                 TypeVariant::Enum(_) => true,
                 TypeVariant::View(t) => known_types.contains(&t.borrow().name),
                 TypeVariant::Int(_) => true,
+                TypeVariant::Float(_) => true,
+                TypeVariant::Bool(_) => true,
+                TypeVariant::String(_) => true,
                 TypeVariant::Unknown(ref _u) => panic!("unexpected unknown type"),
             })
     }
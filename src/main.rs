@@ -3,6 +3,7 @@ mod data_view;
 mod generator;
 mod int;
 mod interpret;
+mod memory;
 mod memory_details;
 mod native_type;
 mod parser;
@@ -15,18 +16,39 @@ mod view_posibility_constant_memory;
 mod memory_type;
 mod enum_memory;
 mod array_size;
+mod bit_field;
+mod checksum_algorithm;
+mod checksum_reference;
+mod presence_condition;
+mod terminator;
+mod diagnostic;
 
 use clap::Parser;
-use interpret::InterpretError;
-use std::{fmt::Display, fs, process::exit, rc::Rc, cell::RefCell};
+use std::{fmt::Display, process::exit, rc::Rc, cell::RefCell};
 
 #[derive(Clone, Default, Debug)]
 pub struct CodeView {
     pub origin: Rc<String>,
+    pub file: Rc<String>,
     pub from: usize,
     pub to: usize,
 }
 
+// Report of an error rendered with source lines of its labels, the first label is primary.
+pub struct Diagnostic {
+    pub message: String,
+    pub labels: Vec<Label>,
+}
+
+pub struct Label {
+    pub code_view: CodeView,
+    pub text: String,
+}
+
+pub trait ToDiagnostic {
+    fn to_diagnostic(&self) -> Diagnostic;
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct DataView<TData: Default + Clone> {
     pub data: TData,
@@ -39,6 +61,28 @@ pub struct Int {
     pub bytes: u8,
 }
 
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Float {
+    pub bits: u8,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Bool {
+    pub bits: u8,
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct StringType {}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub enum ChecksumAlgorithm {
+    #[default]
+    Crc8,
+    Crc16Ccitt,
+    Crc32,
+    Sum,
+}
+
 #[derive(Debug, Clone)]
 pub struct ViewMemberKeyReferenceMemory {
     pub code_view: CodeView,
@@ -53,6 +97,9 @@ pub enum StructMemberConstantMemory {
 #[derive(Debug, Clone)]
 pub enum Language {
     Cpp,
+    Rust,
+    Python,
+    C,
     Unknown
 }
 
@@ -60,6 +107,9 @@ impl Display for Language {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Cpp => write!(f, "Cpp"),
+            Self::Rust => write!(f, "Rust"),
+            Self::Python => write!(f, "Python"),
+            Self::C => write!(f, "C"),
             Self::Unknown => panic!("unknown language")
         }
     }
@@ -70,7 +120,10 @@ pub struct StructMemberMemory {
     pub name: String,
     pub index: usize,
     pub memory: RefCell<Memory>,
-    pub structure: Rc<RefCell<StructMemory>>
+    pub condition: Option<PresenceCondition>,
+    pub structure: Rc<RefCell<StructMemory>>,
+    // Members of other structs which refer to it, either embedding its struct or embedded along with it.
+    pub outer_references: RefCell<Vec<Rc<StructMemberMemory>>>
 }
 
 #[derive(Debug)]
@@ -138,6 +191,38 @@ pub struct StructMemberSizeReference {
     member: Rc<StructMemberMemory>
 }
 
+#[derive(Debug, Clone)]
+pub struct ChecksumReference {
+    native: Rc<NativeType>,
+    algorithm: ChecksumAlgorithm,
+    origin: Rc<StructMemberMemory>,
+    first: Rc<StructMemberMemory>,
+    last: Rc<StructMemberMemory>
+}
+
+// Reference between members of different structs, both given by path from struct embedding them.
+#[derive(Debug, Clone)]
+pub struct NestedReference {
+    holder: Vec<Rc<StructMemberMemory>>,
+    target: Vec<Rc<StructMemberMemory>>
+}
+
+#[derive(Debug, Clone)]
+pub struct PresenceCondition {
+    flag: Rc<StructMemberMemory>,
+    bit: Option<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BitField {
+    native: Rc<NativeType>,
+    container: Rc<NativeType>,
+    bits: u8,
+    shift: u8,
+    opens_group: bool,
+    closes_group: bool,
+}
+
 #[derive(Debug, Clone, variation::Variation)]
 pub enum NativeType {
     Bool,
@@ -155,10 +240,15 @@ pub enum NativeType {
     I16,
     I32,
     I64,
+    F32,
+    F64,
+    Char,
     Unknown,
     ViewKeyReference(ViewKeyReference),
     ArrayDimensionReference(ArrayDimensionReference),
     StructMemberSize(StructMemberSizeReference),
+    Checksum(ChecksumReference),
+    BitField(BitField),
 }
 
 trait ExactSize {
@@ -179,6 +269,7 @@ trait IsDirectlyDeserializable {
 
 pub struct MemoryDeclaration {
     pub name: String,
+    pub module: String,
     pub memory: Memory,
 }
 
@@ -197,13 +288,37 @@ pub trait MemoryDetails {
 pub enum ArraySize {
     No,
     Dyn,
-    Exact(u32)
+    Exact(u32),
+    // dynamic array of at most given items
+    Bounded(u32),
+    // dynamic array taking all bytes left in message
+    Rest,
+    // dynamic array ended by item holding sentinel value
+    Until(Terminator)
+}
+
+// Sentinel is compared with item, or with given member of struct item.
+#[derive(Clone, Debug, Default)]
+pub struct Terminator {
+    member: Option<DataView<String>>,
+    value: TerminatorValue,
+    // value of sentinel, resolved by interpreter
+    sentinel: usize,
+}
+
+#[derive(Clone, Debug)]
+pub enum TerminatorValue {
+    Usize(DataView<usize>),
+    // enum may be omitted, it is known from compared value
+    EnumMember(Option<DataView<String>>, DataView<String>),
 }
 
 #[derive(Debug)]
 pub struct Memory {
     memory: MemoryType,
-    array_size: ArraySize
+    array_size: ArraySize,
+    // Overrides endian of schema for native and enum memory.
+    big_endian: Option<bool>,
 }
 
 #[derive(Debug, variation::Variation)]
@@ -214,22 +329,52 @@ pub enum MemoryType {
     Enum(Rc<EnumMemory>)
 }
 
+// Every parsed file is a module, generated separately and including modules it imports.
+#[derive(Default, Clone)]
+pub struct Module {
+    pub name: String,
+    pub imports: Vec<String>,
+}
+
 pub struct MemoryImage {
     big_endian: bool,
-    memory_decl: Vec<MemoryDeclaration>
+    memory_decl: Vec<MemoryDeclaration>,
+    modules: Vec<Module>,
+}
+
+// Declarations parsed despite syntax errors are interpreted too, to report all errors at once.
+fn interpet_memory(protofile: &str) -> Result<MemoryImage, Vec<Diagnostic>> {
+    let (tokens, parse_errors) = parser::parse_file(protofile);
+    let mut diagnostics = parse_errors
+        .iter()
+        .map(|e| e.to_diagnostic())
+        .collect::<Vec<Diagnostic>>();
+    if tokens.is_empty() && !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    match interpret::interpret(tokens) {
+        Ok(memory_image) if diagnostics.is_empty() => Ok(memory_image),
+        Ok(_) => Err(diagnostics),
+        Err(errors) => {
+            diagnostics.extend(errors.iter().map(|e| e.to_diagnostic()));
+            Err(diagnostics)
+        }
+    }
 }
 
-fn interpet_memory(content: String) -> Result<MemoryImage, InterpretError> {
-    let tokens = parser::parse(content)
-        .or_else(|e| -> Result<Vec<parser::SyntaxToken>, String> {
-            log::error!("parse error: {}", e.to_string());
-            Err(e.to_string())
-        })
-        .unwrap();
-    interpret::interpret(tokens)
+fn render_diagnostics(diagnostics: &[Diagnostic]) -> String {
+    let mut reports = diagnostics
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>();
+    reports.push(match diagnostics.len() {
+        1 => "error: aborting due to previous error".into(),
+        n => format!("error: aborting due to {} previous errors", n),
+    });
+    reports.join("\n\n")
 }
 
-fn generate_cpp(memory_image: MemoryImage, args: &Args) {
+fn generate_code(memory_image: MemoryImage, args: &Args) {
     if let Err(e) = generator::generate(memory_image, args) {
         log::error!("generator error: {}", e.to_string());
         exit(1)
@@ -240,6 +385,9 @@ impl From<String> for Language {
     fn from(value: String) -> Self {
         match value.as_str() {
             "cpp" => Language::Cpp,
+            "rust" => Language::Rust,
+            "python" => Language::Python,
+            "c" => Language::C,
             _ => {
                 log::error!("Unknown language '{value}'");
                 exit(1);
@@ -255,17 +403,21 @@ pub struct Args {
     #[arg(short, long)]
     protofile: String,
 
-    // Target language (only cpp supported for now).
+    // Target language (cpp, rust, python or c).
     #[arg(short, long)]
     language: Language,
 
-    // target machine endianess (big or little)
+    // Target machine endianess (big or little), C++ detects it at compile time when omitted.
     #[arg(short, long)]
-    endian: String,
+    endian: Option<String>,
 
     // Output directory where library will be generated.
     #[arg(short, long)]
     output_dir: String,
+
+    // C++ reports errors by returned values instead of exceptions, e.g. for builds with -fno-exceptions.
+    #[arg(long)]
+    no_exceptions: bool,
 }
 
 fn cpp_ptr_size() -> usize {
@@ -282,19 +434,15 @@ fn main() {
     log::info!("Protofile: {}", &args.protofile);
     let language = Language::from(args.language.clone());
     log::info!("Language: {}", args.language);
-    let content = fs::read_to_string(args.protofile.as_str())
-        .or_else(|_| -> Result<String, String> {
-            log::error!("Unable to read {}", &args.protofile);
+    let memory_image = match interpet_memory(&args.protofile) {
+        Ok(memory_image) => memory_image,
+        Err(diagnostics) => {
+            eprintln!("{}", render_diagnostics(&diagnostics));
             exit(1)
-        })
-        .unwrap();
-    let memory_image = interpet_memory(content);
-    if memory_image.is_err() {
-        log::error!("interpreting failed: {}", memory_image.err().unwrap().to_string());
-        return
-    }
+        }
+    };
     match language {
-        Language::Cpp => generate_cpp(memory_image.unwrap(), &args),
+        Language::Cpp | Language::Rust | Language::Python | Language::C => generate_code(memory_image, &args),
         _ => panic!("unexpected langage")
     }
 }
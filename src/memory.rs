@@ -0,0 +1,8 @@
+use super::*;
+
+impl Memory {
+    // Strings are arrays of characters, fixed or referenced by dimension.
+    pub fn is_string(&self) -> bool {
+        !self.array_size.is_no() && matches!(self.memory, MemoryType::Native(NativeType::Char))
+    }
+}
@@ -68,15 +68,28 @@ impl MemoryDetails for MemoryDeclaration {
 
 impl MemoryDetails for Memory {
     fn exact_size(&self) -> Option<usize> {
-        self.memory.exact_size()
+        match self.array_size {
+            ArraySize::No => self.memory.exact_size(),
+            // terminated array is sized by its items, terminator included
+            ArraySize::Dyn | ArraySize::Bounded(_) | ArraySize::Rest | ArraySize::Until(_) => None,
+            ArraySize::Exact(s) => self.memory.exact_size().map(|bytes| bytes * s as usize),
+        }
     }
 
     fn max_size(&self) -> Option<usize> {
-        self.memory.max_size()
+        match self.array_size {
+            ArraySize::No => self.memory.max_size(),
+            ArraySize::Dyn | ArraySize::Rest | ArraySize::Until(_) => None,
+            ArraySize::Exact(s) | ArraySize::Bounded(s) => self.memory.max_size().map(|bytes| bytes * s as usize),
+        }
     }
 
     fn buffer_size(&self) -> Option<usize> {
-        self.memory.buffer_size()
+        match self.array_size {
+            ArraySize::No => self.memory.buffer_size(),
+            ArraySize::Dyn | ArraySize::Rest | ArraySize::Until(_) => None,
+            ArraySize::Exact(s) | ArraySize::Bounded(s) => self.memory.buffer_size().map(|bytes| bytes * s as usize),
+        }
     }
 
     fn submembers(&self) -> usize {
@@ -153,10 +166,15 @@ impl MemoryDetails for NativeType {
             Self::I16 => Some(2),
             Self::I32 => Some(4),
             Self::I64 => Some(8),
+            Self::F32 => Some(4),
+            Self::F64 => Some(8),
+            Self::Char => Some(1),
             Self::Unknown => None,
             Self::ViewKeyReference(mr) => mr.native_key.exact_size(),
             Self::ArrayDimensionReference(mr) => mr.origin.exact_size(),
             Self::StructMemberSize(m) => m.native.exact_size(),
+            Self::Checksum(c) => c.native.exact_size(),
+            Self::BitField(bf) => Some(bf.bytes()),
         }
     }
 
@@ -181,7 +199,7 @@ impl MemoryDetails for StructMemory {
     fn exact_size(&self) -> Option<usize> {
         self.fields.iter().fold(Some(0), |sum, m| {
             if let Some(size1) = sum {
-                if let Some(size2) = m.memory.borrow().exact_size() {
+                if let Some(size2) = m.exact_size() {
                     return Some(size1 + size2);
                 }
             }
@@ -317,6 +335,10 @@ impl MemoryDetails for EnumMemory {
 
 impl MemoryDetails for StructMemberMemory {
     fn exact_size(&self) -> Option<usize> {
+        // absent optional member takes no bytes
+        if self.condition.is_some() {
+            return None
+        }
         self.memory.borrow().exact_size()
     }
 
@@ -4,7 +4,8 @@ impl MemoryType {
     pub fn non_array_memory(self) -> Memory {
         Memory {
             memory: self,
-            array_size: ArraySize::No
+            array_size: ArraySize::No,
+            big_endian: None,
         }
     }
 
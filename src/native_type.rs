@@ -27,6 +27,17 @@ impl NativeType {
         }
     }
 
+    pub fn unsigned_from_size(size: usize) -> Option<NativeType> {
+        match size {
+            1 => Some(Self::U8),
+            2 => Some(Self::U16),
+            3 => Some(Self::U24),
+            4 => Some(Self::U32),
+            8 => Some(Self::U64),
+            _ => None,
+        }
+    }
+
     pub fn size(&self) -> u8 {
         match self {
             Self::Bool => 1,
@@ -34,6 +45,9 @@ impl NativeType {
             Self::I16 => 2,
             Self::I32 => 4,
             Self::I64 => 8,
+            Self::F32 => 4,
+            Self::F64 => 8,
+            Self::Char => 1,
             Self::U8 => 1,
             Self::U16 => 2,
             Self::U24 => 3,
@@ -48,6 +62,8 @@ impl NativeType {
             Self::ViewKeyReference(mr) => mr.key.memory.borrow().memory.as_native().unwrap().size(),
             Self::ArrayDimensionReference(mr) => mr.size.memory.borrow().memory.as_native().unwrap().size(),
             Self::StructMemberSize(m) => m.origin.memory.borrow().memory.as_native().unwrap().size(),
+            Self::Checksum(c) => c.native.size(),
+            Self::BitField(bf) => bf.bytes() as u8,
         }
     }
 
@@ -3,28 +3,47 @@ use super::*;
 impl ToString for ParseError {
     fn to_string(&self) -> String {
         match self {
-            ParseError::NotEnoughChars(code_view) => {
-                format!("Not enough chars at {}", code_view.pos())
-            }
-            ParseError::ParseValueFailed(code_view) => {
-                format!("Parse value failed at {}", code_view.pos())
-            }
-            ParseError::NotInt(code_view) => format!("Not int at {}", code_view.pos()),
-            ParseError::NotWord(code_view) => format!("Not word at {}", code_view.pos()),
-            ParseError::OrFailed(code_view, message) => {
-                format!("{message}. At {}", code_view.pos())
-            }
-            ParseError::NotToken(token, code_view) => {
-                format!("Not token '{}' at {}", token, code_view.pos())
-            }
-            ParseError::NotStr(code_view) => format!("Not str at {}", code_view.pos()),
-            ParseError::NotAType(code_view) => format!("Not a type at {}", code_view.pos()),
-            ParseError::RetrieveDataFailed(code_view) => {
-                format!("Retrieve data failed at {}", code_view.pos())
-            }
-            ParseError::UnknownSyntaxToken(code_view) => {
-                format!("Unknown syntax token at {}", code_view.pos())
-            }
+            ParseError::NotEnoughChars(_) => "Not enough chars".into(),
+            ParseError::ParseValueFailed(_) => "Parse value failed".into(),
+            ParseError::NotInt(_) => "Not int".into(),
+            ParseError::NotFloat(_) => "Not float".into(),
+            ParseError::NotBool(_) => "Not bool".into(),
+            ParseError::NotString(_) => "Not string".into(),
+            ParseError::NotChecksumAlgorithm(_) => "Not checksum algorithm".into(),
+            ParseError::NotWord(_) => "Not word".into(),
+            ParseError::OrFailed(_, message) => message.clone(),
+            ParseError::NotToken(token, _) => format!("Not token '{}'", token),
+            ParseError::NotStr(_) => "Not str".into(),
+            ParseError::NotAType(_) => "Not a type".into(),
+            ParseError::RetrieveDataFailed(_) => "Retrieve data failed".into(),
+            ParseError::UnknownSyntaxToken(_) => "Unknown syntax token".into(),
+            ParseError::CannotReadFile(path) => format!("Cannot read file '{}'", path),
+            ParseError::CannotReadImport(path, _) => format!("Cannot read imported file '{}'", path),
+            ParseError::ImportCycle(files) => format!("Import cycle: {}", files.join(" -> ")),
+        }
+    }
+}
+
+impl ToDiagnostic for ParseError {
+    fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::new(self.to_string());
+        match self {
+            ParseError::NotEnoughChars(code_view)
+            | ParseError::ParseValueFailed(code_view)
+            | ParseError::NotInt(code_view)
+            | ParseError::NotFloat(code_view)
+            | ParseError::NotBool(code_view)
+            | ParseError::NotString(code_view)
+            | ParseError::NotChecksumAlgorithm(code_view)
+            | ParseError::NotWord(code_view)
+            | ParseError::OrFailed(code_view, _)
+            | ParseError::NotToken(_, code_view)
+            | ParseError::NotStr(code_view)
+            | ParseError::NotAType(code_view)
+            | ParseError::RetrieveDataFailed(code_view)
+            | ParseError::UnknownSyntaxToken(code_view) => diagnostic.with_label(code_view, ""),
+            ParseError::CannotReadImport(_, code_view) => diagnostic.with_label(code_view, "imported here"),
+            ParseError::CannotReadFile(_) | ParseError::ImportCycle(_) => diagnostic,
         }
     }
 }
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use super::*;
+
+impl Importer {
+    // Path of import is relative to importing file, file imported repeatedly is parsed once.
+    pub fn load(&mut self, path: &Path, import: Option<&DataView<Import>>) -> Result<(), ParseError> {
+        let display = path.to_string_lossy().to_string();
+        let read_error = || match import {
+            Some(i) => ParseError::CannotReadImport(i.path.clone(), i.code_view.clone()),
+            None => ParseError::CannotReadFile(display.clone()),
+        };
+        let canonical = path.canonicalize().map_err(|_| read_error())?;
+        if let Some(i) = self.loading.iter().position(|p| *p == canonical) {
+            let mut cycle = self.loading[i..]
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect::<Vec<String>>();
+            cycle.push(canonical.to_string_lossy().to_string());
+            return Err(ParseError::ImportCycle(cycle));
+        }
+        if self.loaded.contains(&canonical) {
+            return Ok(());
+        }
+        let content = std::fs::read_to_string(path).map_err(|_| read_error())?;
+        let (tokens, errors) = parse_view(CodeView::from_file(&display, content));
+        self.errors.extend(errors);
+        self.loading.push(canonical.clone());
+        let directory = path.parent().unwrap_or(Path::new(""));
+        for token in &tokens {
+            if let SyntaxToken::Import(i) = token {
+                if let Err(e) = self.load(&directory.join(&i.path), Some(i)) {
+                    self.errors.push(e);
+                }
+            }
+        }
+        self.loading.pop();
+        self.loaded.push(canonical);
+        self.tokens.extend(tokens);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Importer reads file system, so files are written to a fresh directory per test.
+    fn write_files(test: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!("abufferc_{}_{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        for (file, content) in files {
+            std::fs::write(directory.join(file), content).unwrap();
+        }
+        directory.canonicalize().unwrap()
+    }
+
+    fn diagnostic(errors: &[ParseError]) -> String {
+        errors
+            .iter()
+            .map(|e| e.to_diagnostic().to_string())
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    #[test]
+    fn import_cycle() {
+        let directory = write_files("import_cycle", &[
+            ("a.abf", "import \"b.abf\"\nendian big"),
+            ("b.abf", "import \"a.abf\"\nendian big"),
+        ]);
+        let (_, errors) = parse_file(&directory.join("a.abf").to_string_lossy());
+        let a = directory.join("a.abf").to_string_lossy().to_string();
+        let b = directory.join("b.abf").to_string_lossy().to_string();
+        assert_eq!(diagnostic(&errors), format!("error: Import cycle: {} -> {} -> {}", a, b, a));
+    }
+
+    #[test]
+    fn missing_import() {
+        let directory = write_files("missing_import", &[
+            ("test.abf", "endian big\nimport \"missing.abf\""),
+        ]);
+        let test = directory.join("test.abf").to_string_lossy().to_string();
+        let (_, errors) = parse_file(&test);
+        assert_eq!(
+            diagnostic(&errors),
+            format!(r#"error: Cannot read imported file 'missing.abf'
+ --> {}:2:1
+  |
+2 | import "missing.abf"
+  | ^^^^^^^^^^^^^^^^^^^^ imported here"#, test)
+        );
+    }
+}
@@ -5,6 +5,8 @@ mod parse;
 mod parser_data;
 mod view_type_posibility;
 mod default;
+mod importer;
+mod syntax_token;
 
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -16,6 +18,10 @@ pub enum ParseError {
     NotEnoughChars(CodeView),
     ParseValueFailed(CodeView),
     NotInt(CodeView),
+    NotFloat(CodeView),
+    NotBool(CodeView),
+    NotString(CodeView),
+    NotChecksumAlgorithm(CodeView),
     NotWord(CodeView),
     OrFailed(CodeView, String),
     NotToken(String, CodeView),
@@ -23,6 +29,9 @@ pub enum ParseError {
     NotAType(CodeView),
     RetrieveDataFailed(CodeView),
     UnknownSyntaxToken(CodeView),
+    CannotReadFile(String),
+    CannotReadImport(String, CodeView),
+    ImportCycle(Vec<String>),
 }
 
 pub trait Parser {
@@ -73,14 +82,22 @@ struct Str {
     string: Option<String>,
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct Endian {
     pub big: bool,
 }
 
+#[derive(Default, Clone)]
+pub struct BitOrder {
+    pub msb_first: bool,
+}
+
 #[derive(Debug, Clone, variation::Variation)]
 pub enum TypVariant {
     Int(DataView<Int>),
+    Float(DataView<Float>),
+    Bool(DataView<Bool>),
+    String(DataView<StringType>),
     Unknown(DataView<String>),
     UnknownType,
 }
@@ -97,6 +114,19 @@ pub struct MemberReference {
     property: String,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct Checksum {
+    pub algorithm: DataView<ChecksumAlgorithm>,
+    pub first: DataView<String>,
+    pub last: DataView<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemberCondition {
+    pub member_name: DataView<String>,
+    pub bit: Option<DataView<u8>>,
+}
+
 #[derive(variation::Variation, Debug, Clone)]
 pub enum StructMemberConstant {
     No,
@@ -104,18 +134,22 @@ pub enum StructMemberConstant {
     ArrayDimension(MemberReference),
     Usize(usize),
     Size(MemberReference),
+    Checksum(Checksum),
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct StructMember {
     pub name: DataView<String>,
     pub typ: Typ,
+    pub endian: Option<DataView<Endian>>,
+    pub condition: Option<MemberCondition>,
     pub constant: StructMemberConstant,
 }
 
 #[derive(Default, Clone, Debug)]
 pub struct Struct {
     pub name: DataView<String>,
+    pub endian: Option<DataView<Endian>>,
     pub members: Vec<StructMember>,
 }
 
@@ -167,23 +201,49 @@ pub struct Enum {
     pub constants: Vec<DataView<EnumConstant>>,
 }
 
+#[derive(Default, Clone)]
+pub struct Import {
+    pub path: String,
+}
+
+#[derive(Default)]
+struct Importer {
+    loading: Vec<std::path::PathBuf>,
+    loaded: Vec<std::path::PathBuf>,
+    tokens: Vec<SyntaxToken>,
+    errors: Vec<ParseError>,
+}
+
 #[derive(Default, Clone)]
 pub struct RequiredVersion {
     pub version: [Value<u8>; 3],
 }
 
 pub enum SyntaxToken {
+    Import(DataView<Import>),
     RequiredVersion(DataView<RequiredVersion>),
     Endian(DataView<Endian>),
+    BitOrder(DataView<BitOrder>),
     Struct(DataView<Struct>),
     View(DataView<View>),
     Enum(DataView<Enum>),
 }
 
-pub fn parse(text: String) -> Result<Vec<SyntaxToken>, ParseError> {
+// Tokens of imported files precede tokens of file importing them.
+pub fn parse_file(path: &str) -> (Vec<SyntaxToken>, Vec<ParseError>) {
+    let mut importer = Importer::default();
+    if let Err(e) = importer.load(std::path::Path::new(path), None) {
+        importer.errors.push(e);
+    }
+    (importer.tokens, importer.errors)
+}
+
+// Parsing continues after an error with the next declaration, tokens parsed so far are kept.
+pub fn parse_view(view: CodeView) -> (Vec<SyntaxToken>, Vec<ParseError>) {
     let mut tokens = Vec::default();
-    let mut res = CodeView::from(text);
-    loop {
+    let mut errors = Vec::default();
+    let mut res = view;
+    while !res.rest().is_empty() {
         let mut token = Option::<SyntaxToken>::default();
         match token.parse(&res) {
             Ok(view) => {
@@ -191,14 +251,36 @@ pub fn parse(text: String) -> Result<Vec<SyntaxToken>, ParseError> {
                     tokens.push(t);
                 }
                 res = view;
-                if res.rest().len() == 0 {
-                    return Ok(tokens);
-                }
             }
             Err(err) => match err {
-                Some(err) => return Err(err),
+                Some(err) => {
+                    errors.push(err);
+                    match next_declaration(&res) {
+                        Some(view) => res = view,
+                        None => break,
+                    }
+                }
                 None => panic!("unexpected error"),
             },
         }
     }
+    (tokens, errors)
+}
+
+// Declarations are recognized by keyword starting a line.
+fn next_declaration(text: &CodeView) -> Option<CodeView> {
+    let rest = text.rest();
+    let mut line_start = rest.find('\n')? + 1;
+    loop {
+        let line = &rest[line_start..];
+        let trimmed = line.trim_start_matches([' ', '\t']);
+        let is_declaration = ["struct", "view", "enum"].iter().any(|keyword| {
+            trimmed.starts_with(keyword)
+                && trimmed[keyword.len()..].starts_with(|c: char| c.is_whitespace())
+        });
+        if is_declaration {
+            return Some(text.offset(line_start + line.len() - trimmed.len()));
+        }
+        line_start += line.find('\n')? + 1;
+    }
 }
@@ -132,7 +132,8 @@ impl<TParser: Parser> Parser for Optional<TParser> {
             self.parsed = true;
             return Ok(res);
         }
-        Ok(text.clone())
+        // nothing consumed
+        Ok(text.offset(0))
     }
 }
 
@@ -209,6 +210,24 @@ impl Parser for Str {
     }
 }
 
+impl Parser for Import {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut path = Str {
+            beg_end: '"',
+            esc: '\\',
+            string: None,
+        };
+        let res = Sequence::new(&mut [
+            &mut Token::new("import", false),
+            &mut WhiteChars::default(),
+            &mut path,
+        ])
+        .parse(text)?;
+        self.path = path.string.unwrap_or_default();
+        Ok(res)
+    }
+}
+
 impl Parser for RequiredVersion {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
         let mut major = Value::<u8>::default();
@@ -289,6 +308,24 @@ impl Parser for Endian {
     }
 }
 
+impl Parser for BitOrder {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut msb = Token::new("msb", true);
+        let mut lsb = Token::new("lsb", true);
+        let result = Sequence::new(&mut [
+            &mut Token::new("bit_order", false),
+            &mut WhiteChars::default(),
+            &mut Or::new(
+                &mut [&mut msb, &mut lsb],
+                "Expect 'msb' or 'lsb' keyword.",
+            ),
+        ])
+        .parse(text)?;
+        self.msb_first = msb.found;
+        Ok(result)
+    }
+}
+
 impl Default for TypVariant {
     fn default() -> Self {
         Self::UnknownType
@@ -336,6 +373,47 @@ impl Parser for Int {
     }
 }
 
+impl Parser for Float {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        if text.rest().starts_with('f') && text.rest().len() > 1 {
+            let mut u8 = Value::<u8>::default();
+            if let Ok(res) = u8.parse(&text.offset(1)) {
+                self.bits = u8.value.unwrap();
+                let count = 1 + res.view().len();
+                return Ok(text.offset(count));
+            }
+        }
+        Err(Some(ParseError::NotFloat(text.offset(0))))
+    }
+}
+
+impl Parser for Bool {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        if text.rest().starts_with("bool") {
+            let mut u8 = Value::<u8>::default();
+            let (bits, count) = match u8.parse(&text.offset(4)) {
+                Ok(res) => (u8.value.unwrap(), 4 + res.view().len()),
+                Err(_) => (8, 4),
+            };
+            // plain 'bool' takes whole byte, otherwise it is prefix of user defined type
+            if !text.rest()[count..].starts_with(is_a::is_word_mid) {
+                self.bits = bits;
+                return Ok(text.offset(count));
+            }
+        }
+        Err(Some(ParseError::NotBool(text.offset(0))))
+    }
+}
+
+impl Parser for StringType {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        if text.rest().starts_with("string") && !text.rest()[6..].starts_with(is_a::is_word_mid) {
+            return Ok(text.offset(6));
+        }
+        Err(Some(ParseError::NotString(text.offset(0))))
+    }
+}
+
 impl Parser for ViewConstantValue {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
         let mut unsigned_value = DataView::<Value<usize>>::default();
@@ -396,6 +474,21 @@ impl Parser for TypVariant {
             *self = TypVariant::Int(int);
             return Ok(res);
         }
+        let mut float = DataView::<Float>::default();
+        if let Ok(res) = float.parse(text) {
+            *self = TypVariant::Float(float);
+            return Ok(res);
+        }
+        let mut boolean = DataView::<Bool>::default();
+        if let Ok(res) = boolean.parse(text) {
+            *self = TypVariant::Bool(boolean);
+            return Ok(res);
+        }
+        let mut string = DataView::<StringType>::default();
+        if let Ok(res) = string.parse(text) {
+            *self = TypVariant::String(string);
+            return Ok(res);
+        }
         let mut word = DataView::<String>::default();
         if let Ok(res) = word.parse(text) {
             *self = TypVariant::Unknown(DataView::new(word.data, word.code_view));
@@ -408,7 +501,10 @@ impl Parser for TypVariant {
 impl Parser for Typ {
     fn parse<'b>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
         self.array_size = ArraySize::No;
+        let mut bound = Optional::new(Token::new("..", false));
         let mut size = Optional::new(Value::<u32>::default());
+        let mut until = Token::new("until", false);
+        let mut terminator = Terminator::default();
         let res = Sequence::new(&mut [
             &mut Token::new("[", true),
             &mut WhiteChars::default(),
@@ -421,6 +517,19 @@ impl Parser for Typ {
                         &mut Token::new(",", false)
                     ], "epected ';' or ','"),
                     &mut WhiteChars::default(),
+                    &mut until,
+                    &mut WhiteChars::default(),
+                    &mut terminator,
+                    &mut WhiteChars::default()
+                ]),
+                &mut Sequence::new(&mut [
+                    &mut Or::new(&mut [
+                        &mut Token::new(";", false),
+                        &mut Token::new(",", false)
+                    ], "epected ';' or ','"),
+                    &mut WhiteChars::default(),
+                    &mut bound,
+                    &mut WhiteChars::default(),
                     &mut size,
                     &mut WhiteChars::default()
                 ]),
@@ -429,7 +538,13 @@ impl Parser for Typ {
             &mut Token::new("]", true),
         ]).parse(text);
         if res.is_ok() {
-            if size.parsed {
+            if until.found {
+                self.array_size = ArraySize::Until(terminator);
+            } else if bound.parsed && !size.parsed {
+                self.array_size = ArraySize::Rest;
+            } else if bound.parsed {
+                self.array_size = ArraySize::Bounded(size.parser.value.unwrap());
+            } else if size.parsed {
                 self.array_size = ArraySize::Exact(size.parser.value.unwrap());
             } else {
                 self.array_size = ArraySize::Dyn;
@@ -440,15 +555,118 @@ impl Parser for Typ {
     }
 }
 
+// Sentinel is given as value, optionally compared with member of struct item,
+// e.g. `0` or `kind == Kind::End`.
+impl Parser for Terminator {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut member = DataView::<String>::default();
+        let mut compared = false;
+        let mut count = 0;
+        {
+            let mut white_before = WhiteChars::default();
+            let mut equals_token = Token::new("==", false);
+            let mut white_after = WhiteChars::default();
+            let mut sequence: [&mut dyn Parser; 4] = [&mut member, &mut white_before, &mut equals_token, &mut white_after];
+            let mut equals = Optional::new(Sequence::new(&mut sequence));
+            if let Ok(res) = equals.parse(text) {
+                compared = equals.parsed;
+                count = res.view().len();
+            }
+        }
+        self.member = if compared { Some(member) } else { None };
+        let mut value = DataView::<Value<usize>>::default();
+        let mut enum_member_ref = DataView::<EnumMemberRef>::default();
+        let mut constant = DataView::<String>::default();
+        let mut or_posibilities: [&mut dyn Parser; 3] = [&mut value, &mut enum_member_ref, &mut constant];
+        let mut or = Or::new(&mut or_posibilities, "Expect unsigned value or enum member");
+        let res = or.parse(&text.offset(count))?;
+        self.value = match or.index {
+            0 => TerminatorValue::Usize(DataView::new(value.data.value.unwrap(), value.code_view.clone())),
+            1 => TerminatorValue::EnumMember(
+                Some(enum_member_ref.data.enum_name.clone()),
+                enum_member_ref.data.enum_member.clone()),
+            _ => TerminatorValue::EnumMember(None, constant),
+        };
+        Ok(text.offset(count + res.view().len()))
+    }
+}
+
+// Member of nested struct is named by dotted path, property follows the last dot.
 impl<'b> Parser for MemberReference {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
-        Sequence::new(&mut [
-            &mut self.member_name,
+        let mut path = Vec::new();
+        let mut count = 0;
+        loop {
+            let mut segment = String::default();
+            count += segment.parse(&text.offset(count))?.view().len();
+            path.push(segment);
+            let end = count;
+            count += Sequence::new(&mut [
+                &mut WhiteChars::default(),
+                &mut Token::new(".", true),
+                &mut WhiteChars::default(),
+            ]).parse(&text.offset(count))?.view().len();
+            let rest = &text.rest()[count..];
+            if rest.starts_with(self.property.as_str()) && !rest[self.property.len()..].starts_with(is_a::is_word_mid) {
+                self.member_name = DataView::new(path.join("."), text.offset(end));
+                return Ok(text.offset(count + self.property.len()));
+            }
+        }
+    }
+}
+
+impl Parser for ChecksumAlgorithm {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut name = String::default();
+        let res = name.parse(text)?;
+        if let Some(algorithm) = ChecksumAlgorithm::from_name(&name) {
+            *self = algorithm;
+            return Ok(res);
+        }
+        Err(Some(ParseError::NotChecksumAlgorithm(text.offset(0))))
+    }
+}
+
+impl Parser for Checksum {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut last = DataView::<String>::default();
+        let res = Sequence::new(&mut [
+            &mut self.algorithm,
             &mut WhiteChars::default(),
-            &mut Token::new(".", true),
+            &mut Token::new("(", true),
             &mut WhiteChars::default(),
-            &mut Token::new(&self.property, true)
-        ]).parse(text)
+            &mut self.first,
+            &mut WhiteChars::default(),
+            &mut Some(Sequence::new(&mut [
+                &mut Token::new("..", true),
+                &mut WhiteChars::default(),
+                &mut last,
+                &mut WhiteChars::default(),
+            ])),
+            &mut Token::new(")", true),
+        ]).parse(text)?;
+        // single member range
+        self.last = if last.data.is_empty() { self.first.clone() } else { last };
+        Ok(res)
+    }
+}
+
+impl Parser for MemberCondition {
+    fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut bit = DataView::<u8>::default();
+        let res = Sequence::new(&mut [
+            &mut self.member_name,
+            &mut Some(Sequence::new(&mut [
+                &mut WhiteChars::default(),
+                &mut Token::new(".", true),
+                &mut WhiteChars::default(),
+                &mut Token::new("bit", true),
+                &mut bit,
+            ])),
+        ]).parse(text)?;
+        // whole member value when bit is not given
+        self.bit = if bit.code_view.view().is_empty() { None } else { Some(bit) };
+        Ok(res)
     }
 }
 
@@ -458,10 +676,11 @@ impl<'b> Parser for StructMemberConstant {
         let mut view_reference = MemberReference::new("key");
         let mut array_dimension = MemberReference::new("dimension");
         let mut size = MemberReference::new("size");
-        let mut or_posibilities: [&mut dyn Parser; 4] = [&mut view_reference, &mut array_dimension, &mut value, &mut size];
+        let mut checksum = Checksum::default();
+        let mut or_posibilities: [&mut dyn Parser; 5] = [&mut view_reference, &mut array_dimension, &mut value, &mut size, &mut checksum];
         let mut or = Or::new(
             &mut or_posibilities,
-            "View reference or size of struct member",
+            "View reference, size or checksum of struct member",
         );
         let res: CodeView = or.parse(text)?;
         match or.index {
@@ -469,6 +688,7 @@ impl<'b> Parser for StructMemberConstant {
             1 => *self = StructMemberConstant::ArrayDimension(array_dimension),
             2 => *self = StructMemberConstant::Usize(value.value.unwrap()),
             3 => *self = StructMemberConstant::Size(size),
+            4 => *self = StructMemberConstant::Checksum(checksum),
             _ => panic!("Unexpected index"),
         }
         Ok(res)
@@ -492,13 +712,23 @@ impl<TData: Parser> Parser for Option<TData> {
 impl<'b> Parser for StructMember {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
         self.constant = StructMemberConstant::No;
-        Sequence::new(&mut [
+        let mut condition = Some(MemberCondition::default());
+        let mut endian = Some(DataView::<Endian>::default());
+        let res = Sequence::new(&mut [
                 &mut self.name,
                 &mut WhiteChars::default(),
                 &mut Token::new(":", true),
                 &mut WhiteChars::default(),
                 &mut self.typ,
                 &mut WhiteChars::default(),
+                &mut endian,
+                &mut WhiteChars::default(),
+                &mut Some(Sequence::new(&mut [
+                    &mut Token::new("if", true),
+                    &mut WhiteChars::new(1),
+                    &mut condition,
+                    &mut WhiteChars::default(),
+                ])),
                 &mut Some(Sequence::new(&mut [
                     &mut Token::new("=", true),
                     &mut WhiteChars::default(),
@@ -506,19 +736,25 @@ impl<'b> Parser for StructMember {
                 ])),
             ],
         )
-        .parse(text)
+        .parse(text)?;
+        self.endian = endian;
+        self.condition = condition.filter(|c| !c.member_name.data.is_empty());
+        Ok(res)
     }
 }
 
 impl<'b> Parser for Struct {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
         let mut struct_keyword = Token::new("struct", false);
+        let mut endian = Some(DataView::<Endian>::default());
         let res = Sequence {
             parsers: &mut [
                 &mut struct_keyword,
                 &mut WhiteChars::default(),
                 &mut self.name,
                 &mut WhiteChars::default(),
+                &mut endian,
+                &mut WhiteChars::default(),
                 &mut Token::new("{", true),
                 &mut WhiteChars::default(),
                 &mut self.members,
@@ -527,6 +763,7 @@ impl<'b> Parser for Struct {
             ],
         }
         .parse(text)?;
+        self.endian = endian;
         Ok(res)
     }
 }
@@ -569,6 +806,19 @@ impl<TData: Parser + Default + Clone> Parser for DataView<TData> {
 
 impl Parser for Option<SyntaxToken> {
     fn parse<'a>(&mut self, text: &CodeView) -> Result<CodeView, Option<ParseError>> {
+        let mut parser = DataView::<Import>::default();
+        match parser.parse(text) {
+            Ok(res) => {
+                *self = Some(SyntaxToken::Import(parser));
+                return Ok(res);
+            }
+            Err(e) => {
+                if e.is_some() {
+                    return Err(e);
+                }
+            }
+        }
+
         let mut parser = DataView::<RequiredVersion>::default();
         match parser.parse(text) {
             Ok(res) => {
@@ -595,6 +845,19 @@ impl Parser for Option<SyntaxToken> {
             }
         }
 
+        let mut parser = DataView::<BitOrder>::default();
+        match parser.parse(text) {
+            Ok(res) => {
+                *self = Some(SyntaxToken::BitOrder(parser));
+                return Ok(res);
+            }
+            Err(e) => {
+                if e.is_some() {
+                    return Err(e);
+                }
+            }
+        }
+
         let mut parser = DataView::<Struct>::default();
         match parser.parse(text) {
             Ok(res) => {
@@ -723,6 +986,44 @@ mod test {
         assert_eq!(*parser.array_size.as_exact().unwrap(), 3);
     }
 
+    #[test]
+    fn bounded_array() {
+        let mut parser = Typ::default();
+        let res = parser.parse(&CodeView::from("[u8, ..64]"));
+        assert!(res.is_ok());
+        assert_eq!(*parser.array_size.as_bounded().unwrap(), 64);
+        let res = parser.parse(&CodeView::from("[u8; .. 64]"));
+        assert!(res.is_ok());
+        assert_eq!(*parser.array_size.as_bounded().unwrap(), 64);
+    }
+
+    #[test]
+    fn terminated_array() {
+        let mut parser = Typ::default();
+        let res = parser.parse(&CodeView::from("[u8, until 0]"));
+        assert!(res.is_ok());
+        let terminator = parser.array_size.as_until().unwrap();
+        assert!(terminator.member.is_none());
+        assert!(matches!(&terminator.value, TerminatorValue::Usize(v) if v.data == 0));
+        let res = parser.parse(&CodeView::from("[Entry, until kind == End]"));
+        assert!(res.is_ok());
+        let terminator = parser.array_size.as_until().unwrap();
+        assert_eq!(terminator.member.as_ref().unwrap().data, "kind");
+        assert!(matches!(&terminator.value, TerminatorValue::EnumMember(None, m) if m.data == "End"));
+        let res = parser.parse(&CodeView::from("[Entry; until kind == Kind::End]"));
+        assert!(res.is_ok());
+        let terminator = parser.array_size.as_until().unwrap();
+        assert!(matches!(&terminator.value, TerminatorValue::EnumMember(Some(e), m) if e.data == "Kind" && m.data == "End"));
+    }
+
+    #[test]
+    fn rest_array() {
+        let mut parser = Typ::default();
+        let res = parser.parse(&CodeView::from("[u8, ..]"));
+        assert!(res.is_ok());
+        assert!(parser.array_size.is_rest());
+    }
+
     #[test]
     fn u8() {
         let mut u8_parser = Value::<u8>::default();
@@ -859,6 +1160,14 @@ mod test {
         assert_eq!(parser.version[2].value.unwrap(), 12);
     }
 
+    #[test]
+    fn import() {
+        let mut parser = Import::default();
+        let res = parser.parse(&CodeView::from("import \"common.abf\""));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.path, "common.abf");
+    }
+
     #[test]
     fn endian() {
         let mut parser = Endian::default();
@@ -870,6 +1179,45 @@ mod test {
         assert_eq!(parser.big, false);
     }
 
+    #[test]
+    fn bit_order() {
+        let mut parser = BitOrder::default();
+        let res = parser.parse(&CodeView::from("bit_order   msb"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.msb_first, true);
+        let res = parser.parse(&CodeView::from("bit_order   lsb"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.msb_first, false);
+    }
+
+    #[test]
+    fn bool_type() {
+        let mut parser = Bool::default();
+        let res = parser.parse(&CodeView::from("bool"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.bits, 8);
+        let res = parser.parse(&CodeView::from("bool1"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.bits, 1);
+        let res = parser.parse(&CodeView::from("boolean"));
+        assert_eq!(res.is_ok(), false);
+    }
+
+    #[test]
+    fn string_type() {
+        let mut parser = Typ::default();
+        let res = parser.parse(&CodeView::from("[string, 16]"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.typ.is_string(), true);
+        assert_eq!(*parser.array_size.as_exact().unwrap(), 16);
+        let res = parser.parse(&CodeView::from("[string]"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.array_size.is_dyn(), true);
+        let res = parser.parse(&CodeView::from("strings"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.typ.is_unknown(), true);
+    }
+
     #[test]
     fn member() {
         let mut parser: StructMember = Default::default();
@@ -955,6 +1303,94 @@ mod test {
         assert_eq!(parser.members[0].constant.is_view_member_key(), true);
     }
 
+    #[test]
+    fn parse_member_path_constant() {
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("len: u16 = body . items.dimension"));
+        assert!(res.is_ok());
+        let reference = parser.constant.as_array_dimension().unwrap();
+        assert_eq!(reference.member_name.data, "body.items");
+        assert_eq!(reference.member_name.code_view.view(), "body . items");
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("kind: u8 = keys.key"));
+        assert!(res.is_ok());
+        assert_eq!(parser.constant.as_view_member_key().unwrap().member_name.data, "keys");
+    }
+
+    #[test]
+    fn parse_checksum_constant() {
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("crc: u16 = crc16_ccitt(header .. payload)"));
+        assert_eq!(res.is_ok(), true);
+        let checksum = parser.constant.as_checksum().unwrap();
+        assert_eq!(checksum.algorithm.data, ChecksumAlgorithm::Crc16Ccitt);
+        assert_eq!(checksum.first.data, "header");
+        assert_eq!(checksum.last.data, "payload");
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("crc: u8 = sum(payload)"));
+        assert_eq!(res.is_ok(), true);
+        let checksum = parser.constant.as_checksum().unwrap();
+        assert_eq!(checksum.algorithm.data, ChecksumAlgorithm::Sum);
+        assert_eq!(checksum.first.data, "payload");
+        assert_eq!(checksum.last.data, "payload");
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("crc: u8 = md5(payload)"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.constant.is_no(), true);
+    }
+
+    #[test]
+    fn parse_member_condition() {
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("ext: Ext if flags.bit3"));
+        assert_eq!(res.is_ok(), true);
+        let condition = parser.condition.unwrap();
+        assert_eq!(condition.member_name.data, "flags");
+        assert_eq!(condition.bit.unwrap().data, 3);
+        assert_eq!(parser.constant.is_no(), true);
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("ext: [u8, 4] if has_ext"));
+        assert_eq!(res.is_ok(), true);
+        let condition = parser.condition.unwrap();
+        assert_eq!(condition.member_name.data, "has_ext");
+        assert_eq!(condition.bit.is_none(), true);
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("ext: u8"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.condition.is_none(), true);
+    }
+
+    #[test]
+    fn parse_endian_annotation() {
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("length: u16 endian little if flags.bit0"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.endian.unwrap().data.big, false);
+        assert_eq!(parser.condition.unwrap().member_name.data, "flags");
+
+        let mut parser = StructMember::default();
+        let res = parser.parse(&CodeView::from("length: u16"));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.endian.is_none(), true);
+
+        let mut parser = Struct::default();
+        let res = parser.parse(&CodeView::from(
+            "struct Descriptor endian big {
+            length: u16 endian little,
+            kind: u16
+        }",
+        ));
+        assert_eq!(res.is_ok(), true);
+        assert_eq!(parser.endian.unwrap().data.big, true);
+        assert_eq!(parser.members[0].endian.as_ref().unwrap().data.big, false);
+        assert_eq!(parser.members[1].endian.is_none(), true);
+    }
+
     #[test]
     fn parse_enum() {
         let mut parser = Enum::default();
@@ -1053,4 +1489,27 @@ mod test {
         assert_eq!(parser.types[1].constant.as_ref().unwrap().as_enum_member_ref().unwrap().enum_name.data, "AnEnum");
         assert_eq!(parser.types[1].constant.as_ref().unwrap().as_enum_member_ref().unwrap().enum_member.data, "U16");
     }
+
+    #[test]
+    fn recover_from_errors() {
+        let (tokens, errors) = parse_view(CodeView::from(
+            "endian big
+struct A {
+    x: u8 =
+}
+struct B {
+    y: u8
+}
+view C {
+    u8,
+}
+enum D : u8 {
+    E = 1
+}",
+        ));
+        assert_eq!(errors.len(), 2);
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[1].code_view().line(), 5);
+        assert_eq!(tokens[2].code_view().line(), 11);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use super::*;
+
+impl SyntaxToken {
+    pub fn code_view(&self) -> CodeView {
+        match self {
+            SyntaxToken::Import(i) => i.code_view.clone(),
+            SyntaxToken::RequiredVersion(v) => v.code_view.clone(),
+            SyntaxToken::Endian(e) => e.code_view.clone(),
+            SyntaxToken::BitOrder(o) => o.code_view.clone(),
+            SyntaxToken::Struct(s) => s.code_view.clone(),
+            SyntaxToken::View(v) => v.code_view.clone(),
+            SyntaxToken::Enum(e) => e.code_view.clone(),
+        }
+    }
+}
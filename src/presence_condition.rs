@@ -0,0 +1,11 @@
+use super::*;
+
+impl PresenceCondition {
+    // Expression testing flag value, same syntax in all generated languages.
+    pub fn test(&self, value: &str) -> String {
+        match self.bit {
+            Some(bit) => format!("(({} >> {}) & 1) != 0", value, bit),
+            None => format!("{} != 0", value),
+        }
+    }
+}
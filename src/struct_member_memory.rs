@@ -5,7 +5,7 @@ impl StructMemberMemory {
         for m in &self.structure.borrow().fields {
             if let Some(nt) = m.memory.borrow().memory.as_native() {
                 if let Some(adr) = nt.as_array_dimension_reference() {
-                    if adr.array.name == self.name {
+                    if std::ptr::eq(adr.array.as_ref(), self) {
                         return Some(m.clone())
                     }
                 }
@@ -18,7 +18,7 @@ impl StructMemberMemory {
         for m in &self.structure.borrow().fields {
             if let Some(nt) = m.memory.borrow().memory.as_native() {
                 if let Some(vkr) = nt.as_view_key_reference() {
-                    if vkr.key.name == self.name {
+                    if std::ptr::eq(vkr.key.as_ref(), self) && Rc::ptr_eq(&vkr.view.structure, &self.structure) {
                         return Some(vkr.clone())
                     }
                 }
@@ -31,7 +31,7 @@ impl StructMemberMemory {
         for m in &self.structure.borrow().fields {
             if let Some(nt) = m.memory.borrow().memory.as_native() {
                 if let Some(sms) = nt.as_struct_member_size() {
-                    if sms.member.name == self.name {
+                    if std::ptr::eq(sms.member.as_ref(), self) {
                         return Some(sms.clone())
                     }
                 }
@@ -39,4 +39,30 @@ impl StructMemberMemory {
         }
         None
     }
+
+    // Dimension given by member of struct embedding this member's struct.
+    pub fn get_outer_dimension(&self) -> Option<Rc<StructMemberMemory>> {
+        self.outer_references
+            .borrow()
+            .iter()
+            .find(|r| r.memory.borrow().memory.as_native().is_some_and(|n| n.is_array_dimension_reference()))
+            .cloned()
+    }
+
+    pub fn has_outer_key(&self) -> bool {
+        self.outer_references
+            .borrow()
+            .iter()
+            .any(|r| r.memory.borrow().memory.as_native().is_some_and(|n| n.is_view_key_reference()))
+    }
+
+    // Member referring to another one, None for the rest.
+    pub fn get_referenced_member(&self) -> Option<Rc<StructMemberMemory>> {
+        match self.memory.borrow().memory.as_native()? {
+            NativeType::ViewKeyReference(vkr) => Some(vkr.view.clone()),
+            NativeType::ArrayDimensionReference(adr) => Some(adr.array.clone()),
+            NativeType::StructMemberSize(sms) => Some(sms.member.clone()),
+            _ => None,
+        }
+    }
 }
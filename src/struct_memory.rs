@@ -33,7 +33,8 @@ impl StructMemory {
         }
         for (i, _f) in  self.fields.iter().enumerate() {
             if  self.is_view_reference(i) && 
-                self.get_referenced_view_index(i) == member_index 
+                self.get_referenced_view_index(i) == member_index &&
+                self.fields[i].get_view_key_reference().is_some()
             {
                 return Some(i);
             }
@@ -41,6 +42,75 @@ impl StructMemory {
         None
     }
 
+    // Member named by dotted path through nested struct members.
+    pub fn member_at_path(&self, path: &str) -> Option<Rc<StructMemberMemory>> {
+        let (name, rest) = match path.split_once('.') {
+            Some((name, rest)) => (name, Some(rest)),
+            None => (path, None),
+        };
+        let member = self.fields.iter().find(|f| f.name == name)?.clone();
+        let rest = match rest {
+            Some(rest) => rest,
+            None => return Some(member),
+        };
+        let nested = member.memory.borrow().memory.as_struct()?.clone();
+        let found = nested.borrow().member_at_path(rest);
+        found
+    }
+
+    // Members of this struct and of structs nested in it as single members, each given by path from here.
+    pub fn member_paths(&self) -> std::vec::Vec<std::vec::Vec<Rc<StructMemberMemory>>> {
+        let mut paths = std::vec::Vec::new();
+        for f in &self.fields {
+            paths.push(vec![f.clone()]);
+            let memory = f.memory.borrow();
+            if let (MemoryType::Struct(s), true, None) = (&memory.memory, memory.array_size.is_no(), &f.condition) {
+                for path in s.borrow().member_paths() {
+                    paths.push([vec![f.clone()], path].concat());
+                }
+            }
+        }
+        paths
+    }
+
+    // References this struct connects: its own ones pointing into nested structs and those of
+    // nested structs pointing out of them. Ones within single nested member are its own.
+    pub fn get_nested_references(&self) -> std::vec::Vec<NestedReference> {
+        let paths = self.member_paths();
+        let mut nested = std::vec::Vec::new();
+        for holder in &paths {
+            let target = match holder.last().unwrap().get_referenced_member() {
+                Some(target) => target,
+                None => continue,
+            };
+            let target = match paths.iter().find(|p| Rc::ptr_eq(p.last().unwrap(), &target)) {
+                Some(target) => target,
+                None => continue,
+            };
+            let local = holder.len() == 1 && target.len() == 1;
+            let within_nested = holder.len() > 1 && target.len() > 1 && Rc::ptr_eq(&holder[0], &target[0]);
+            if !local && !within_nested {
+                nested.push(NestedReference { holder: holder.clone(), target: target.clone() });
+            }
+        }
+        nested
+    }
+
+    // Member is accessed by struct embedding this one, when it or member nested in it refers out of
+    // this struct or is referred to from outside of it.
+    pub fn is_wired_from_outside(&self, member_index: usize) -> bool {
+        let paths = self.member_paths();
+        let in_tree = |sm: &Rc<StructMemberMemory>| paths.iter().any(|p| Rc::ptr_eq(p.last().unwrap(), sm));
+        let referred_here = |sm: &Rc<StructMemberMemory>| paths
+            .iter()
+            .any(|p| p.last().unwrap().get_referenced_member().is_some_and(|t| Rc::ptr_eq(&t, sm)));
+        paths.iter().filter(|p| Rc::ptr_eq(&p[0], &self.fields[member_index])).any(|p| {
+            let sm = p.last().unwrap();
+            (!sm.outer_references.borrow().is_empty() && !referred_here(sm))
+                || sm.get_referenced_member().is_some_and(|t| !in_tree(&t))
+        })
+    }
+
     pub fn is_view(&self, member_index: usize) -> bool {
         if self.fields[member_index].memory.borrow().memory.is_view() {
             return true
@@ -48,6 +118,23 @@ impl StructMemory {
         false
     }
 
+    // Checksum covered by another checksum comes first, so its value is final when covering one is computed.
+    pub fn get_checksums(&self) -> std::vec::Vec<ChecksumReference> {
+        let mut pending: std::vec::Vec<ChecksumReference> = self.fields
+            .iter()
+            .filter_map(|f| f.memory.borrow().memory.as_native().and_then(|n| n.as_checksum().cloned()))
+            .collect();
+        let mut out = std::vec::Vec::default();
+        while !pending.is_empty() {
+            let next = pending
+                .iter()
+                .position(|c| !pending.iter().any(|other| c.covers(other)))
+                .expect("cyclic checksums");
+            out.push(pending.remove(next));
+        }
+        out
+    }
+
     pub fn get_groups(&self) -> std::vec::Vec<(usize, usize)> {
         if self.fields.is_empty() {
             return Default::default()
@@ -55,7 +142,7 @@ impl StructMemory {
         let mut out = std::vec::Vec::default();
         let mut i0 = 0;
         for i in 0..self.fields.len() {
-            if self.fields[i].memory.exact_size().is_none() {
+            if self.fields[i].exact_size().is_none() {
                 out.push((i0, i));
                 i0 = i + 1;
             }
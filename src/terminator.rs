@@ -0,0 +1,47 @@
+use super::*;
+
+impl Default for TerminatorValue {
+    fn default() -> Self {
+        Self::Usize(DataView::default())
+    }
+}
+
+impl PartialEq for TerminatorValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Usize(a), Self::Usize(b)) => a.data == b.data,
+            (Self::EnumMember(enum_a, a), Self::EnumMember(enum_b, b)) =>
+                enum_a.as_ref().map(|e| &e.data) == enum_b.as_ref().map(|e| &e.data) && a.data == b.data,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for Terminator {
+    fn eq(&self, other: &Self) -> bool {
+        self.member.as_ref().map(|m| &m.data) == other.member.as_ref().map(|m| &m.data) && self.value == other.value
+    }
+}
+
+impl TerminatorValue {
+    pub fn code_view(&self) -> CodeView {
+        match self {
+            Self::Usize(value) => value.code_view.clone(),
+            Self::EnumMember(_, member) => member.code_view.clone(),
+        }
+    }
+}
+
+impl Terminator {
+    // Struct item is compared by its member placed at fixed offset.
+    pub fn compared_member(&self, item: &MemoryType) -> Option<(Rc<StructMemberMemory>, usize)> {
+        let name = &self.member.as_ref()?.data;
+        let structure = item.as_struct()?.borrow();
+        let index = structure.fields.iter().position(|f| &f.name == name)?;
+        let offset = structure.fields[..index]
+            .iter()
+            .map(|f| f.exact_size())
+            .sum::<Option<usize>>()?;
+        Some((structure.fields[index].clone(), offset))
+    }
+}